@@ -0,0 +1,820 @@
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    ops::{Add, Bound, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    continuous::{partial_cmp_bounds, BoundSide},
+    ContinuousRange, Discrete, Range,
+};
+
+/// A numeric index type supporting interval arithmetic
+///
+/// Results that can't be represented by the type (integer overflow, `NaN`) are reported as [`None`] and the
+/// corresponding bound of the resulting range becomes unbounded. Results below [`Numeric::lower_limit`] are
+/// clamped instead, so that subtracting unsigned integers saturates at zero.
+pub trait Numeric: PartialOrd + Clone {
+    /// The additive identity
+    fn zero() -> Self;
+
+    /// Add `other` to `self`
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Subtract `other` from `self`
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    /// Multiply `self` by `other`
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    /// Divide `self` by `other`, never called with a zero `other`
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+
+    /// Raise `self` to the power `exp`
+    fn checked_pow(&self, exp: u32) -> Option<Self>;
+
+    /// Round a computed value that becomes the start of a range
+    ///
+    /// The default implementation doesn't round, it can be overridden to round toward negative infinity and
+    /// guarantee that the result contains the exact value.
+    #[must_use]
+    fn round_down(self) -> Self {
+        self
+    }
+
+    /// Round a computed value that becomes the end of a range
+    ///
+    /// The default implementation doesn't round, it can be overridden to round toward positive infinity and
+    /// guarantee that the result contains the exact value.
+    #[must_use]
+    fn round_up(self) -> Self {
+        self
+    }
+
+    /// The lowest representable value when results below it should be clamped to it, like zero for unsigned
+    /// integers
+    ///
+    /// The default implementation returns [`None`], results overflowing below the type become unbounded.
+    #[must_use]
+    fn lower_limit() -> Option<Self> {
+        None
+    }
+
+    /// Normalize the bounds of a range before computing with them
+    ///
+    /// Discrete types use it to convert exclusive bounds to inclusive ones, so that `(1..3)` is handled as
+    /// `[2..2]`.
+    #[must_use]
+    fn normalize(range: ContinuousRange<Self>) -> ContinuousRange<Self> {
+        range
+    }
+}
+
+/// A numeric index type representing real numbers
+pub trait Real: Numeric {
+    /// The square root of a positive value
+    #[must_use]
+    fn sqrt(&self) -> Self;
+}
+
+/// Convert all the exclusive bounds of a range of a discrete type to inclusive ones
+pub(crate) fn normalize_discrete<Idx: Discrete>(
+    range: &ContinuousRange<Idx>,
+) -> ContinuousRange<Idx> {
+    let (start, end) = match range.range_bounds() {
+        Some(bounds) if !range.is_empty() => bounds,
+        _ => return ContinuousRange::Empty,
+    };
+
+    let start = match start {
+        Bound::Included(start) => Some(start.clone()),
+        Bound::Excluded(start) => match start.successor() {
+            Some(start) => Some(start),
+            None => return ContinuousRange::Empty,
+        },
+        Bound::Unbounded => None,
+    };
+    let end = match end {
+        Bound::Included(end) => Some(end.clone()),
+        Bound::Excluded(end) => match end.predecessor() {
+            Some(end) => Some(end),
+            None => return ContinuousRange::Empty,
+        },
+        Bound::Unbounded => None,
+    };
+
+    let start = start.as_ref().map_or(Bound::Unbounded, Bound::Included);
+    let end = end.as_ref().map_or(Bound::Unbounded, Bound::Included);
+    ContinuousRange::from_bounds((start, end))
+}
+
+macro_rules! impl_numeric_integer {
+    ($($t:ty => $limit:expr),*) => {
+        $(
+            impl Numeric for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *other)
+                }
+
+                fn checked_div(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_div(*self, *other)
+                }
+
+                fn checked_pow(&self, exp: u32) -> Option<Self> {
+                    <$t>::checked_pow(*self, exp)
+                }
+
+                fn lower_limit() -> Option<Self> {
+                    $limit
+                }
+
+                fn normalize(range: ContinuousRange<Self>) -> ContinuousRange<Self> {
+                    normalize_discrete(&range)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_integer!(
+    i8 => None,
+    i16 => None,
+    i32 => None,
+    i64 => None,
+    i128 => None,
+    isize => None,
+    u8 => Some(0),
+    u16 => Some(0),
+    u32 => Some(0),
+    u64 => Some(0),
+    u128 => Some(0),
+    usize => Some(0)
+);
+
+macro_rules! impl_numeric_float {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    let result = self + other;
+                    (!result.is_nan()).then(|| result)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    let result = self - other;
+                    (!result.is_nan()).then(|| result)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    let result = self * other;
+                    (!result.is_nan()).then(|| result)
+                }
+
+                fn checked_div(&self, other: &Self) -> Option<Self> {
+                    let result = self / other;
+                    (!result.is_nan()).then(|| result)
+                }
+
+                fn checked_pow(&self, exp: u32) -> Option<Self> {
+                    let result = match i32::try_from(exp) {
+                        Ok(exp) => self.powi(exp),
+                        // `x^exp = (x^(exp / 2))^2 * x^(exp % 2)`, exact for odd exponents unlike a float exponent
+                        Err(_) => {
+                            let half = self.powi(i32::try_from(exp / 2).ok()?);
+                            if exp % 2 == 0 {
+                                half * half
+                            } else {
+                                half * half * self
+                            }
+                        }
+                    };
+                    (!result.is_nan()).then(|| result)
+                }
+            }
+
+            impl Real for $t {
+                fn sqrt(&self) -> Self {
+                    <$t>::sqrt(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_float!(f32, f64);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+impl Sign {
+    fn mul(self, other: Sign) -> Sign {
+        match (self, other) {
+            (Sign::Zero, _) | (_, Sign::Zero) => Sign::Zero,
+            (Sign::Positive, Sign::Positive) | (Sign::Negative, Sign::Negative) => Sign::Positive,
+            _ => Sign::Negative,
+        }
+    }
+}
+
+/// A value on the extended number line
+#[derive(Clone, Debug)]
+enum Value<Idx> {
+    NegInf,
+    Finite(Idx),
+    PosInf,
+}
+
+impl<Idx: Numeric> Value<Idx> {
+    fn infinite(sign: Sign) -> Self {
+        match sign {
+            Sign::Negative => Value::NegInf,
+            Sign::Zero | Sign::Positive => Value::PosInf,
+        }
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::NegInf, Value::NegInf) | (Value::PosInf, Value::PosInf) => Ordering::Equal,
+            (Value::NegInf, _) | (_, Value::PosInf) => Ordering::Less,
+            (_, Value::NegInf) | (Value::PosInf, _) => Ordering::Greater,
+            (Value::Finite(a), Value::Finite(b)) => a
+                .partial_cmp(b)
+                .expect("PartialOrd contract isn't correctly implemented"),
+        }
+    }
+}
+
+/// One end of an interval
+#[derive(Clone, Debug)]
+struct Endpoint<Idx> {
+    value: Value<Idx>,
+    closed: bool,
+}
+
+impl<Idx: Numeric> Endpoint<Idx> {
+    fn open(value: Value<Idx>) -> Self {
+        Endpoint {
+            value,
+            closed: false,
+        }
+    }
+
+    fn is_closed_zero(&self) -> bool {
+        self.closed && self.sign_at(BoundSide::Start) == Sign::Zero
+    }
+
+    /// The sign of the values near this endpoint when it's at the `side` of an interval, an excluded zero takes
+    /// the sign of the values of the interval.
+    fn sign_at(&self, side: BoundSide) -> Sign {
+        match &self.value {
+            Value::NegInf => Sign::Negative,
+            Value::PosInf => Sign::Positive,
+            Value::Finite(value) => match value.partial_cmp(&Idx::zero()) {
+                Some(Ordering::Less) => Sign::Negative,
+                Some(Ordering::Greater) => Sign::Positive,
+                _ if self.closed => Sign::Zero,
+                _ => match side {
+                    BoundSide::Start => Sign::Positive,
+                    BoundSide::End => Sign::Negative,
+                },
+            },
+        }
+    }
+}
+
+/// Select the endpoint including the lowest values
+fn lowest<Idx: Numeric>(candidates: Vec<Endpoint<Idx>>) -> Endpoint<Idx> {
+    candidates
+        .into_iter()
+        .reduce(|a, b| match a.value.cmp(&b.value) {
+            Ordering::Less => a,
+            Ordering::Greater => b,
+            Ordering::Equal => Endpoint {
+                closed: a.closed || b.closed,
+                ..a
+            },
+        })
+        .expect("There is always at least one candidate")
+}
+
+/// Select the endpoint including the highest values
+fn highest<Idx: Numeric>(candidates: Vec<Endpoint<Idx>>) -> Endpoint<Idx> {
+    candidates
+        .into_iter()
+        .reduce(|a, b| match a.value.cmp(&b.value) {
+            Ordering::Greater => a,
+            Ordering::Less => b,
+            Ordering::Equal => Endpoint {
+                closed: a.closed || b.closed,
+                ..a
+            },
+        })
+        .expect("There is always at least one candidate")
+}
+
+/// Add the candidates for a computed value, rounded in both directions
+fn push_computed<Idx: Numeric>(
+    candidates: &mut Vec<Endpoint<Idx>>,
+    value: Option<Idx>,
+    sign: Sign,
+    closed: bool,
+) {
+    match value {
+        Some(value) => {
+            candidates.push(Endpoint {
+                value: Value::Finite(value.clone().round_down()),
+                closed,
+            });
+            candidates.push(Endpoint {
+                value: Value::Finite(value.round_up()),
+                closed,
+            });
+        }
+        None => candidates.push(Endpoint::open(Value::infinite(sign))),
+    }
+}
+
+/// A non-empty range seen as an interval of the extended number line
+#[derive(Clone, Debug)]
+struct Interval<Idx> {
+    lower: Endpoint<Idx>,
+    upper: Endpoint<Idx>,
+}
+
+impl<Idx: Numeric> Interval<Idx> {
+    fn from_range(range: ContinuousRange<Idx>) -> Option<Self> {
+        let range = Idx::normalize(range);
+        if range.is_empty() {
+            return None;
+        }
+
+        let (start, end) = range.range_bounds()?;
+        let lower = match start {
+            Bound::Included(x) => Endpoint {
+                value: Value::Finite(x.clone()),
+                closed: true,
+            },
+            Bound::Excluded(x) => Endpoint::open(Value::Finite(x.clone())),
+            Bound::Unbounded => Endpoint::open(Value::NegInf),
+        };
+        let upper = match end {
+            Bound::Included(x) => Endpoint {
+                value: Value::Finite(x.clone()),
+                closed: true,
+            },
+            Bound::Excluded(x) => Endpoint::open(Value::Finite(x.clone())),
+            Bound::Unbounded => Endpoint::open(Value::PosInf),
+        };
+
+        Some(Interval { lower, upper })
+    }
+
+    fn into_range(self) -> ContinuousRange<Idx> {
+        fn to_bound<Idx>(endpoint: &Endpoint<Idx>) -> Bound<&Idx> {
+            match &endpoint.value {
+                Value::Finite(x) if endpoint.closed => Bound::Included(x),
+                Value::Finite(x) => Bound::Excluded(x),
+                Value::NegInf | Value::PosInf => Bound::Unbounded,
+            }
+        }
+
+        let range = ContinuousRange::from_bounds((to_bound(&self.lower), to_bound(&self.upper)));
+        Idx::normalize(range)
+    }
+
+    fn sign(&self) -> Sign {
+        let lower = self.lower.sign_at(BoundSide::Start);
+        let upper = self.upper.sign_at(BoundSide::End);
+        match (lower, upper) {
+            (Sign::Positive, _) | (Sign::Zero, Sign::Positive) => Sign::Positive,
+            (_, Sign::Negative) | (Sign::Negative, Sign::Zero) => Sign::Negative,
+            _ => Sign::Zero,
+        }
+    }
+
+    fn contains_zero(&self) -> bool {
+        self.lower.sign_at(BoundSide::Start) != Sign::Positive
+            && self.upper.sign_at(BoundSide::End) != Sign::Negative
+    }
+
+    /// Apply a monotonically increasing function to both ends
+    fn map_monotonic(self, f: impl Fn(&Idx) -> Option<Idx>) -> Self {
+        let map =
+            |endpoint: Endpoint<Idx>, overflow: Value<Idx>, round: fn(Idx) -> Idx| match &endpoint
+                .value
+            {
+                Value::Finite(x) => Endpoint {
+                    value: f(x).map(round).map_or(overflow, Value::Finite),
+                    ..endpoint
+                },
+                _ => endpoint,
+            };
+
+        Interval {
+            lower: map(self.lower, Value::NegInf, Idx::round_down),
+            upper: map(self.upper, Value::PosInf, Idx::round_up),
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let sum =
+            |a: &Endpoint<Idx>, b: &Endpoint<Idx>, overflow: Value<Idx>, round: fn(Idx) -> Idx| {
+                let value = match (&a.value, &b.value) {
+                    (Value::Finite(a), Value::Finite(b)) => {
+                        a.checked_add(b).map(round).map_or(overflow, Value::Finite)
+                    }
+                    _ => overflow,
+                };
+                Endpoint {
+                    value,
+                    closed: a.closed && b.closed,
+                }
+            };
+
+        Interval {
+            lower: sum(&self.lower, &other.lower, Value::NegInf, Idx::round_down),
+            upper: sum(&self.upper, &other.upper, Value::PosInf, Idx::round_up),
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        let limit = Idx::lower_limit();
+
+        let lower = match (&self.lower.value, &other.upper.value) {
+            (Value::Finite(a), Value::Finite(b)) => a.checked_sub(b).map(Idx::round_down),
+            _ => None,
+        };
+        let lower = match (lower, &limit) {
+            (Some(value), _) => Endpoint {
+                value: Value::Finite(value),
+                closed: self.lower.closed && other.upper.closed,
+            },
+            // Values below the limit aren't representable, the result starts at the limit
+            (None, Some(limit)) => Endpoint {
+                value: Value::Finite(limit.clone()),
+                closed: true,
+            },
+            (None, None) => Endpoint::open(Value::NegInf),
+        };
+
+        let upper = match (&self.upper.value, &other.lower.value) {
+            (Value::Finite(a), Value::Finite(b)) => match (a.checked_sub(b), &limit) {
+                (Some(value), _) => Endpoint {
+                    value: Value::Finite(value.round_up()),
+                    closed: self.upper.closed && other.lower.closed,
+                },
+                // All the values are below the limit, the result is empty
+                (None, Some(limit)) => Endpoint::open(Value::Finite(limit.clone())),
+                (None, None) => Endpoint::open(Value::PosInf),
+            },
+            _ => Endpoint::open(Value::PosInf),
+        };
+
+        Interval { lower, upper }
+    }
+
+    fn neg(&self) -> Self {
+        let zero = Endpoint {
+            value: Value::Finite(Idx::zero()),
+            closed: true,
+        };
+        Interval {
+            lower: zero.clone(),
+            upper: zero,
+        }
+        .sub(self)
+    }
+
+    fn corners(&self, other: &Self) -> [(Endpoint<Idx>, BoundSide, Endpoint<Idx>, BoundSide); 4] {
+        [
+            (
+                self.lower.clone(),
+                BoundSide::Start,
+                other.lower.clone(),
+                BoundSide::Start,
+            ),
+            (
+                self.lower.clone(),
+                BoundSide::Start,
+                other.upper.clone(),
+                BoundSide::End,
+            ),
+            (
+                self.upper.clone(),
+                BoundSide::End,
+                other.lower.clone(),
+                BoundSide::Start,
+            ),
+            (
+                self.upper.clone(),
+                BoundSide::End,
+                other.upper.clone(),
+                BoundSide::End,
+            ),
+        ]
+    }
+
+    fn from_candidates(candidates: Vec<Endpoint<Idx>>) -> Self {
+        Interval {
+            lower: lowest(candidates.clone()),
+            upper: highest(candidates),
+        }
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut candidates = Vec::new();
+        for (a, a_side, b, b_side) in self.corners(other) {
+            let sign = a.sign_at(a_side).mul(b.sign_at(b_side));
+            match (&a.value, &b.value) {
+                _ if a.is_closed_zero() || b.is_closed_zero() => candidates.push(Endpoint {
+                    value: Value::Finite(Idx::zero()),
+                    closed: true,
+                }),
+                (Value::Finite(x), Value::Finite(y)) => {
+                    push_computed(
+                        &mut candidates,
+                        x.checked_mul(y),
+                        sign,
+                        a.closed && b.closed,
+                    );
+                }
+                (Value::Finite(x), _) | (_, Value::Finite(x)) if *x == Idx::zero() => {
+                    // Infinity multiplied by an excluded zero can be any value of that sign
+                    candidates.push(Endpoint::open(Value::Finite(Idx::zero())));
+                    candidates.push(Endpoint::open(Value::infinite(sign)));
+                }
+                _ => candidates.push(Endpoint::open(Value::infinite(sign))),
+            }
+        }
+
+        Self::from_candidates(candidates)
+    }
+
+    /// Divide by an interval that doesn't contain zero
+    fn div_nonzero(&self, other: &Self) -> Self {
+        let divisor_sign = other.sign();
+        let mut candidates = Vec::new();
+        for (a, a_side, b, _) in self.corners(other) {
+            let sign = a.sign_at(a_side).mul(divisor_sign);
+            let b_is_zero = matches!(&b.value, Value::Finite(y) if *y == Idx::zero());
+            match (&a.value, &b.value) {
+                _ if a.is_closed_zero() => candidates.push(Endpoint {
+                    value: Value::Finite(Idx::zero()),
+                    closed: true,
+                }),
+                (Value::Finite(x), Value::Finite(y)) if !b_is_zero => {
+                    push_computed(
+                        &mut candidates,
+                        x.checked_div(y),
+                        sign,
+                        a.closed && b.closed,
+                    );
+                }
+                (Value::Finite(_), Value::NegInf | Value::PosInf) => {
+                    candidates.push(Endpoint::open(Value::Finite(Idx::zero())));
+                }
+                (Value::NegInf | Value::PosInf, Value::Finite(_)) => {
+                    candidates.push(Endpoint::open(Value::infinite(sign)));
+                }
+                (Value::Finite(x), Value::Finite(_)) if *x != Idx::zero() => {
+                    candidates.push(Endpoint::open(Value::infinite(sign)));
+                }
+                _ => {
+                    // Both sides tend to zero or infinity, the result can be any value of that sign
+                    candidates.push(Endpoint::open(Value::Finite(Idx::zero())));
+                    candidates.push(Endpoint::open(Value::infinite(sign)));
+                }
+            }
+        }
+
+        Self::from_candidates(candidates)
+    }
+
+    fn abs(&self) -> Self {
+        match self.sign() {
+            Sign::Positive => self.clone(),
+            Sign::Negative => self.neg(),
+            Sign::Zero => {
+                let negated = self.neg();
+                Interval {
+                    lower: Endpoint {
+                        value: Value::Finite(Idx::zero()),
+                        closed: true,
+                    },
+                    upper: highest(vec![negated.upper, self.upper.clone()]),
+                }
+            }
+        }
+    }
+
+    fn pow(&self, exp: u32) -> Self {
+        let base = if exp % 2 == 0 {
+            self.abs()
+        } else {
+            self.clone()
+        };
+        base.map_monotonic(|x| x.checked_pow(exp))
+    }
+}
+
+fn binary_op<Idx: Numeric>(
+    a: ContinuousRange<Idx>,
+    b: ContinuousRange<Idx>,
+    op: impl FnOnce(&Interval<Idx>, &Interval<Idx>) -> Interval<Idx>,
+) -> ContinuousRange<Idx> {
+    match (Interval::from_range(a), Interval::from_range(b)) {
+        (Some(a), Some(b)) => op(&a, &b).into_range(),
+        _ => ContinuousRange::Empty,
+    }
+}
+
+impl<Idx: Numeric> ContinuousRange<Idx> {
+    /// Compute the absolute value of all the values of the range
+    #[must_use]
+    pub fn abs(&self) -> ContinuousRange<Idx> {
+        match Interval::from_range(self.clone()) {
+            Some(interval) => interval.abs().into_range(),
+            None => ContinuousRange::Empty,
+        }
+    }
+
+    /// Raise all the values of the range to the power `exp`
+    #[must_use]
+    pub fn pow(&self, exp: u32) -> ContinuousRange<Idx> {
+        match Interval::from_range(self.clone()) {
+            Some(_) if exp == 0 => Idx::zero()
+                .checked_pow(0)
+                .map_or(ContinuousRange::Empty, ContinuousRange::Single),
+            Some(interval) => interval.pow(exp).into_range(),
+            None => ContinuousRange::Empty,
+        }
+    }
+}
+
+impl<Idx: Real + std::fmt::Debug> ContinuousRange<Idx> {
+    /// Compute the square root of all the values of the range
+    ///
+    /// Negative values don't have a square root and are ignored.
+    #[must_use]
+    pub fn sqrt(&self) -> ContinuousRange<Idx> {
+        let positive = self.intersection(&ContinuousRange::From(Idx::zero()));
+        match Interval::from_range(positive) {
+            Some(interval) => interval.map_monotonic(|x| Some(x.sqrt())).into_range(),
+            None => ContinuousRange::Empty,
+        }
+    }
+}
+
+impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
+    /// The range of the minimum between a value of this range and a value of `other`
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn min(&self, other: &ContinuousRange<Idx>) -> ContinuousRange<Idx> {
+        self.select_bounds(other, Ordering::Less)
+    }
+
+    /// The range of the maximum between a value of this range and a value of `other`
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn max(&self, other: &ContinuousRange<Idx>) -> ContinuousRange<Idx> {
+        self.select_bounds(other, Ordering::Greater)
+    }
+
+    fn select_bounds(
+        &self,
+        other: &ContinuousRange<Idx>,
+        wanted: Ordering,
+    ) -> ContinuousRange<Idx> {
+        if self.is_empty() || other.is_empty() {
+            return ContinuousRange::Empty;
+        }
+
+        let (self_start, self_end) = self
+            .range_bounds()
+            .expect("Non-empty self should have bounds");
+        let (other_start, other_end) = other
+            .range_bounds()
+            .expect("Non-empty other should have bounds");
+
+        let select = |a, b, side| {
+            let ordering = partial_cmp_bounds(&a, side, &b, side)
+                .expect("PartialOrd contract isn't correctly implemented");
+            if ordering == wanted {
+                a
+            } else {
+                b
+            }
+        };
+
+        ContinuousRange::from_bounds((
+            select(self_start, other_start, BoundSide::Start),
+            select(self_end, other_end, BoundSide::End),
+        ))
+    }
+}
+
+impl<Idx: Numeric> Add for ContinuousRange<Idx> {
+    type Output = ContinuousRange<Idx>;
+
+    fn add(self, other: ContinuousRange<Idx>) -> ContinuousRange<Idx> {
+        binary_op(self, other, Interval::add)
+    }
+}
+
+impl<Idx: Numeric> Sub for ContinuousRange<Idx> {
+    type Output = ContinuousRange<Idx>;
+
+    fn sub(self, other: ContinuousRange<Idx>) -> ContinuousRange<Idx> {
+        binary_op(self, other, Interval::sub)
+    }
+}
+
+impl<Idx: Numeric> Mul for ContinuousRange<Idx> {
+    type Output = ContinuousRange<Idx>;
+
+    fn mul(self, other: ContinuousRange<Idx>) -> ContinuousRange<Idx> {
+        binary_op(self, other, Interval::mul)
+    }
+}
+
+impl<Idx: Numeric> Div for ContinuousRange<Idx> {
+    type Output = Range<Idx>;
+
+    /// Divide all the values of the range by all the values of `other`
+    ///
+    /// When `other` contains zero the result is the union of the division by its negative and positive parts,
+    /// possibly producing a composite range.
+    fn div(self, other: ContinuousRange<Idx>) -> Range<Idx> {
+        let (a, b) = match (
+            Interval::from_range(self),
+            Interval::from_range(other.clone()),
+        ) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Range::empty(),
+        };
+
+        if !b.contains_zero() {
+            return Range::Continuous(a.div_nonzero(&b).into_range());
+        }
+
+        if a.contains_zero() {
+            // 0 / 0 can be anything
+            return Range::full();
+        }
+
+        let parts = vec![
+            other.try_intersection(&ContinuousRange::ToExclusive(Idx::zero())),
+            other.try_intersection(&ContinuousRange::FromExclusive(Idx::zero())),
+        ];
+        let pieces: Vec<_> = parts
+            .into_iter()
+            .map(|part| part.expect("PartialOrd contract isn't correctly implemented"))
+            .filter_map(Interval::from_range)
+            .map(|b| a.div_nonzero(&b).into_range())
+            .collect();
+
+        Range::composite(
+            crate::coverage::covered(&pieces)
+                .expect("PartialOrd contract isn't correctly implemented")
+                .into_iter()
+                .map(Range::Continuous),
+        )
+    }
+}
+
+impl<Idx: Numeric> Neg for ContinuousRange<Idx> {
+    type Output = ContinuousRange<Idx>;
+
+    fn neg(self) -> ContinuousRange<Idx> {
+        match Interval::from_range(self) {
+            Some(interval) => interval.neg().into_range(),
+            None => ContinuousRange::Empty,
+        }
+    }
+}
@@ -0,0 +1,412 @@
+mod test_add_sub {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(
+            ContinuousRange::Empty + ContinuousRange::Inclusive(1.0, 2.0),
+            ContinuousRange::Empty
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) - ContinuousRange::Empty,
+            ContinuousRange::Empty
+        );
+    }
+
+    #[test]
+    pub fn bounded() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) + ContinuousRange::Inclusive(10.0, 20.0),
+            ContinuousRange::Inclusive(11.0, 22.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) - ContinuousRange::Inclusive(10.0, 20.0),
+            ContinuousRange::Inclusive(-19.0, -8.0)
+        );
+        assert_eq!(
+            ContinuousRange::Single(1.5) + ContinuousRange::Single(2.0),
+            ContinuousRange::Single(3.5)
+        );
+    }
+
+    #[test]
+    pub fn exclusive_bounds() {
+        assert_eq!(
+            ContinuousRange::EndExclusive(1.0, 2.0) + ContinuousRange::Inclusive(10.0, 20.0),
+            ContinuousRange::EndExclusive(11.0, 22.0)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(1.0, 2.0) - ContinuousRange::Inclusive(10.0, 20.0),
+            ContinuousRange::EndExclusive(-19.0, -8.0)
+        );
+        assert_eq!(
+            ContinuousRange::StartExclusive(1.0, 2.0) - ContinuousRange::StartExclusive(10.0, 20.0),
+            ContinuousRange::Exclusive(-19.0, -8.0)
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            ContinuousRange::From(1.0) + ContinuousRange::Inclusive(10.0, 20.0),
+            ContinuousRange::From(11.0)
+        );
+        assert_eq!(
+            ContinuousRange::From(1.0) - ContinuousRange::Inclusive(10.0, 20.0),
+            ContinuousRange::From(-19.0)
+        );
+        assert_eq!(
+            ContinuousRange::From(1.0) - ContinuousRange::From(10.0),
+            ContinuousRange::Full
+        );
+        assert_eq!(
+            ContinuousRange::ToExclusive(1.0) + ContinuousRange::To(10.0),
+            ContinuousRange::ToExclusive(11.0)
+        );
+    }
+
+    #[test]
+    pub fn integers_use_inclusive_bounds() {
+        assert_eq!(
+            ContinuousRange::Exclusive(1, 4) + ContinuousRange::Exclusive(1, 4),
+            ContinuousRange::Inclusive(4, 6)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(1, 5) - ContinuousRange::Single(1),
+            ContinuousRange::Inclusive(0, 3)
+        );
+    }
+
+    #[test]
+    pub fn integer_overflow_is_unbounded() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1i8, 100) + ContinuousRange::Inclusive(1, 100),
+            ContinuousRange::From(2)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-100i8, 1) - ContinuousRange::Inclusive(1, 100),
+            ContinuousRange::To(0)
+        );
+    }
+
+    #[test]
+    pub fn unsigned() {
+        assert_eq!(
+            ContinuousRange::Inclusive(5u32, 10) + ContinuousRange::Inclusive(1, 2),
+            ContinuousRange::Inclusive(6, 12)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(5u32, 10) - ContinuousRange::Inclusive(1, 2),
+            ContinuousRange::Inclusive(3, 9)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(5u32, 10) - ContinuousRange::Inclusive(7, 8),
+            ContinuousRange::Inclusive(0, 3)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(5u32, 10) - ContinuousRange::From(7),
+            ContinuousRange::Inclusive(0, 3)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(5u32, 10) - ContinuousRange::Inclusive(11, 20),
+            ContinuousRange::Empty
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(200u8, 250) + ContinuousRange::Inclusive(10, 10),
+            ContinuousRange::From(210)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(2u64, 3) * ContinuousRange::Inclusive(4, 5),
+            ContinuousRange::Inclusive(8, 15)
+        );
+        assert_eq!(
+            -ContinuousRange::Inclusive(0u32, 5),
+            ContinuousRange::Single(0)
+        );
+        assert_eq!(-ContinuousRange::Inclusive(1u32, 5), ContinuousRange::Empty);
+    }
+}
+
+mod test_mul {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn positive() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) * ContinuousRange::Inclusive(3.0, 4.0),
+            ContinuousRange::Inclusive(3.0, 8.0)
+        );
+    }
+
+    #[test]
+    pub fn mixed_signs() {
+        assert_eq!(
+            ContinuousRange::Inclusive(-1.0, 2.0) * ContinuousRange::Inclusive(3.0, 4.0),
+            ContinuousRange::Inclusive(-4.0, 8.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-1.0, 2.0) * ContinuousRange::Inclusive(-3.0, 4.0),
+            ContinuousRange::Inclusive(-6.0, 8.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-2, -1) * ContinuousRange::Inclusive(-4, -3),
+            ContinuousRange::Inclusive(3, 8)
+        );
+    }
+
+    #[test]
+    pub fn exclusive_bounds() {
+        assert_eq!(
+            ContinuousRange::Exclusive(1.0, 2.0) * ContinuousRange::Inclusive(3.0, 4.0),
+            ContinuousRange::Exclusive(3.0, 8.0)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(-1.0, 2.0) * ContinuousRange::Inclusive(-3.0, 4.0),
+            ContinuousRange::Exclusive(-6.0, 8.0)
+        );
+    }
+
+    #[test]
+    pub fn zero() {
+        assert_eq!(
+            ContinuousRange::Single(0.0) * ContinuousRange::Full,
+            ContinuousRange::Single(0.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(0.0, 1.0) * ContinuousRange::From(1.0),
+            ContinuousRange::From(0.0)
+        );
+        assert_eq!(
+            ContinuousRange::StartExclusive(0.0, 1.0) * ContinuousRange::From(1.0),
+            ContinuousRange::FromExclusive(0.0)
+        );
+        assert_eq!(
+            ContinuousRange::StartExclusive(0.0, 1.0) * ContinuousRange::Exclusive(-5.0, 0.0),
+            ContinuousRange::Exclusive(-5.0, 0.0)
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            ContinuousRange::From(1.0) * ContinuousRange::Inclusive(-1.0, 2.0),
+            ContinuousRange::Full
+        );
+        assert_eq!(
+            ContinuousRange::From(1.0) * ContinuousRange::To(-1.0),
+            ContinuousRange::To(-1.0)
+        );
+    }
+}
+
+mod test_div {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) / ContinuousRange::Empty,
+            Range::empty()
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) / ContinuousRange::Single(0.0),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn not_containing_zero() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) / ContinuousRange::Inclusive(4.0, 8.0),
+            Range::continuous(0.125, 0.5)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-1.0, 2.0) / ContinuousRange::EndExclusive(-4.0, -1.0),
+            Range::continuous_exclusive(-2.0, 1.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(7, 9) / ContinuousRange::Inclusive(2, 3),
+            Range::continuous(2, 4)
+        );
+    }
+
+    #[test]
+    pub fn excluded_zero() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) / ContinuousRange::StartExclusive(0.0, 4.0),
+            Range::from(0.25)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) / ContinuousRange::From(1.0),
+            Range::continuous_start_exclusive(0.0, 2.0)
+        );
+    }
+
+    #[test]
+    pub fn containing_zero() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) / ContinuousRange::Inclusive(-1.0, 1.0),
+            Range::Composite(vec![ContinuousRange::To(-1.0), ContinuousRange::From(1.0)])
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0) / ContinuousRange::Inclusive(0.0, 1.0),
+            Range::from(1.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(2, 4) / ContinuousRange::Inclusive(-2, 2),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(-4, -1),
+                ContinuousRange::Inclusive(1, 4)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn both_containing_zero() {
+        assert_eq!(
+            ContinuousRange::Inclusive(-1.0, 2.0) / ContinuousRange::Inclusive(-1.0, 1.0),
+            Range::full()
+        );
+    }
+}
+
+mod test_unary {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn neg() {
+        assert_eq!(
+            -ContinuousRange::EndExclusive(1.0, 2.0),
+            ContinuousRange::StartExclusive(-2.0, -1.0)
+        );
+        assert_eq!(-ContinuousRange::From(1), ContinuousRange::To(-1));
+        assert_eq!(-ContinuousRange::<i32>::Empty, ContinuousRange::Empty);
+        assert_eq!(-ContinuousRange::Single(i8::MIN), ContinuousRange::Full);
+    }
+
+    #[test]
+    pub fn abs() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0).abs(),
+            ContinuousRange::Inclusive(1.0, 2.0)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(-2.0, -1.0).abs(),
+            ContinuousRange::StartExclusive(1.0, 2.0)
+        );
+        assert_eq!(
+            ContinuousRange::Exclusive(-3.0, 2.0).abs(),
+            ContinuousRange::EndExclusive(0.0, 3.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-3, 3).abs(),
+            ContinuousRange::Inclusive(0, 3)
+        );
+        assert_eq!(ContinuousRange::To(-5).abs(), ContinuousRange::From(5));
+        assert_eq!(
+            ContinuousRange::<f64>::Full.abs(),
+            ContinuousRange::From(0.0)
+        );
+    }
+
+    #[test]
+    pub fn pow() {
+        assert_eq!(
+            ContinuousRange::Inclusive(-2.0, 3.0).pow(2),
+            ContinuousRange::Inclusive(0.0, 9.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-2.0, 3.0).pow(3),
+            ContinuousRange::Inclusive(-8.0, 27.0)
+        );
+        assert_eq!(
+            ContinuousRange::Exclusive(-3, -1).pow(2),
+            ContinuousRange::Single(4)
+        );
+        assert_eq!(
+            ContinuousRange::StartExclusive(-3.0, -1.0).pow(2),
+            ContinuousRange::EndExclusive(1.0, 9.0)
+        );
+        assert_eq!(ContinuousRange::From(2).pow(3), ContinuousRange::From(8));
+        assert_eq!(
+            ContinuousRange::<f64>::Full.pow(0),
+            ContinuousRange::Single(1.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(2i8, 100).pow(2),
+            ContinuousRange::From(4)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(2.0, 3.0).pow(1 << 31),
+            ContinuousRange::Single(f64::INFINITY)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-3.0f32, -2.0).pow(u32::MAX),
+            ContinuousRange::Single(f32::NEG_INFINITY)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(0.5, 1.0).pow(u32::MAX),
+            ContinuousRange::Inclusive(0.0, 1.0)
+        );
+    }
+
+    #[test]
+    pub fn sqrt() {
+        assert_eq!(
+            ContinuousRange::Inclusive(4.0, 9.0).sqrt(),
+            ContinuousRange::Inclusive(2.0, 3.0)
+        );
+        assert_eq!(
+            ContinuousRange::Exclusive(-4.0, 9.0).sqrt(),
+            ContinuousRange::EndExclusive(0.0, 3.0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(-4.0f32, -1.0).sqrt(),
+            ContinuousRange::Empty
+        );
+        assert_eq!(
+            ContinuousRange::FromExclusive(16.0).sqrt(),
+            ContinuousRange::FromExclusive(4.0)
+        );
+    }
+}
+
+mod test_min_max {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn min() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 10).min(&ContinuousRange::Inclusive(5, 8)),
+            ContinuousRange::Inclusive(1, 8)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 5.0).min(&ContinuousRange::EndExclusive(1.0, 5.0)),
+            ContinuousRange::EndExclusive(1.0, 5.0)
+        );
+        assert_eq!(
+            ContinuousRange::From(1).min(&ContinuousRange::To(5)),
+            ContinuousRange::To(5)
+        );
+        assert_eq!(
+            ContinuousRange::From(1).min(&ContinuousRange::Empty),
+            ContinuousRange::Empty
+        );
+    }
+
+    #[test]
+    pub fn max() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 10).max(&ContinuousRange::Inclusive(5, 8)),
+            ContinuousRange::Inclusive(5, 10)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 5.0).max(&ContinuousRange::StartExclusive(1.0, 5.0)),
+            ContinuousRange::StartExclusive(1.0, 5.0)
+        );
+        assert_eq!(
+            ContinuousRange::From(1).max(&ContinuousRange::To(5)),
+            ContinuousRange::From(1)
+        );
+    }
+}
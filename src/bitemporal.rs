@@ -0,0 +1,283 @@
+use std::{error::Error, fmt, ops::Bound};
+
+use crate::{ContinuousRange, Range, RangesRelation};
+
+/// Error returned when a change can't be applied to a [`Bitemporal`] store
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BitemporalError<Idx> {
+    /// The valid time of a new fact intersects the valid time of a current fact, with the relation of the new
+    /// valid time to the existing one
+    Conflict {
+        /// Valid time of the current fact
+        valid: ContinuousRange<Idx>,
+
+        /// Relation of the valid time of the new fact to the valid time of the current one
+        relation: RangesRelation,
+    },
+
+    /// The transaction time is before the one of the latest change, transaction time can only move forward
+    Retroactive {
+        /// Transaction time of the latest change
+        latest: Idx,
+    },
+}
+
+impl<Idx: fmt::Debug> fmt::Display for BitemporalError<Idx> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitemporalError::Conflict { valid, relation } => write!(
+                fmt,
+                "valid time conflicts with the current fact valid during {valid:?} ({relation:?})"
+            ),
+            BitemporalError::Retroactive { latest } => {
+                write!(
+                    fmt,
+                    "transaction time is before the latest change at {latest:?}"
+                )
+            }
+        }
+    }
+}
+
+impl<Idx: fmt::Debug> Error for BitemporalError<Idx> {}
+
+/// A fact of a [`Bitemporal`] store with the time it's true in the modeled world and the time it was known
+#[derive(Clone, PartialEq, Debug)]
+pub struct Fact<Idx, V> {
+    /// When the fact is true in the modeled world
+    pub valid: ContinuousRange<Idx>,
+
+    /// When the fact was part of the store, starting when it was recorded and ending when it was corrected
+    pub recorded: ContinuousRange<Idx>,
+
+    /// The value of the fact
+    pub value: V,
+}
+
+impl<Idx: PartialOrd + Clone, V> Fact<Idx, V> {
+    /// Returns true if the fact hasn't been corrected yet
+    #[must_use]
+    pub fn is_current(&self) -> bool {
+        matches!(self.recorded.end(), Some(Bound::Unbounded))
+    }
+}
+
+/// The relation of `valid` to `other` if they intersect, an empty valid time never does
+fn conflict<Idx: PartialOrd + Clone + fmt::Debug>(
+    valid: &ContinuousRange<Idx>,
+    other: &ContinuousRange<Idx>,
+) -> Option<RangesRelation> {
+    if valid.is_empty() || other.is_empty() {
+        return None;
+    }
+
+    valid.compare(other).filter(RangesRelation::intersects)
+}
+
+/// A store of facts with two time axes, the valid time when they are true and the transaction time when they
+/// were known
+///
+/// Facts are never removed, a correction closes the transaction time of the facts it changes so that the
+/// store can still be queried as it was at any point in transaction time. The current facts never have
+/// intersecting valid times.
+///
+/// ```
+/// use range_ranger::{Bitemporal, ContinuousRange};
+///
+/// let mut salaries = Bitemporal::new();
+/// salaries.record(ContinuousRange::From(2020), 1000, 2020).unwrap();
+/// // In 2022 we learn that the salary was raised in 2021
+/// salaries.correct(ContinuousRange::From(2021), 1200, 2022).unwrap();
+///
+/// assert_eq!(salaries.as_of(&2021, &2021), Some(&1000));
+/// assert_eq!(salaries.as_of(&2022, &2021), Some(&1200));
+/// assert_eq!(salaries.as_of(&2022, &2020), Some(&1000));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bitemporal<Idx, V> {
+    facts: Vec<Fact<Idx, V>>,
+    latest: Option<Idx>,
+}
+
+impl<Idx, V> Default for Bitemporal<Idx, V> {
+    fn default() -> Self {
+        Bitemporal {
+            facts: Vec::new(),
+            latest: None,
+        }
+    }
+}
+
+impl<Idx: PartialOrd + Clone + fmt::Debug, V> Bitemporal<Idx, V> {
+    /// A store without any fact
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All the facts ever recorded, current or corrected
+    #[must_use]
+    pub fn facts(&self) -> &[Fact<Idx, V>] {
+        &self.facts
+    }
+
+    /// The facts that haven't been corrected
+    pub fn current(&self) -> impl Iterator<Item = &Fact<Idx, V>> {
+        self.facts.iter().filter(|fact| fact.is_current())
+    }
+
+    /// The current facts with a valid time intersecting `valid`, with the relation of `valid` to their valid
+    /// time
+    #[must_use]
+    pub fn conflicts(&self, valid: &ContinuousRange<Idx>) -> Vec<(&Fact<Idx, V>, RangesRelation)> {
+        self.current()
+            .filter_map(|fact| Some((fact, conflict(valid, &fact.valid)?)))
+            .collect()
+    }
+
+    /// Record that `value` is true during `valid`, known from the transaction time `at`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BitemporalError::Conflict`] if a current fact is valid during a part of `valid`, use
+    /// [`correct`][Bitemporal::correct] to replace it, or [`BitemporalError::Retroactive`] if `at` is before
+    /// the latest change.
+    pub fn record(
+        &mut self,
+        valid: ContinuousRange<Idx>,
+        value: V,
+        at: Idx,
+    ) -> Result<(), BitemporalError<Idx>> {
+        self.check_order(&at)?;
+        if let Some((fact, relation)) = self.conflicts(&valid).into_iter().next() {
+            return Err(BitemporalError::Conflict {
+                valid: fact.valid.clone(),
+                relation,
+            });
+        }
+
+        self.facts.push(Fact {
+            valid,
+            recorded: ContinuousRange::From(at.clone()),
+            value,
+        });
+        self.latest = Some(at);
+        Ok(())
+    }
+
+    /// Record that `value` is true during `valid` from the transaction time `at`, replacing the current facts
+    /// during `valid`
+    ///
+    /// See [`retract`][Bitemporal::retract] for how the replaced facts are corrected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BitemporalError::Retroactive`] if `at` is before the latest change.
+    pub fn correct(
+        &mut self,
+        valid: ContinuousRange<Idx>,
+        value: V,
+        at: Idx,
+    ) -> Result<(), BitemporalError<Idx>>
+    where
+        V: Clone,
+    {
+        self.retract(&valid, at.clone())?;
+        self.facts.push(Fact {
+            valid,
+            recorded: ContinuousRange::From(at),
+            value,
+        });
+        Ok(())
+    }
+
+    /// Record that nothing is known during `valid` from the transaction time `at`
+    ///
+    /// The transaction time of the current facts intersecting `valid` is closed at `at`, and the parts of their
+    /// valid time outside of `valid` are recorded again as new facts from `at`. Facts recorded at `at` itself
+    /// are dropped as they were never visible.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BitemporalError::Retroactive`] if `at` is before the latest change.
+    pub fn retract(
+        &mut self,
+        valid: &ContinuousRange<Idx>,
+        at: Idx,
+    ) -> Result<(), BitemporalError<Idx>>
+    where
+        V: Clone,
+    {
+        self.check_order(&at)?;
+
+        let mut facts = Vec::with_capacity(self.facts.len());
+        let mut remaining = Vec::new();
+        for fact in std::mem::take(&mut self.facts) {
+            if !fact.is_current() || conflict(valid, &fact.valid).is_none() {
+                facts.push(fact);
+                continue;
+            }
+
+            let outside =
+                Range::Continuous(fact.valid.clone()).difference(&Range::Continuous(valid.clone()));
+            for part in outside.pieces().iter().filter(|part| !part.is_empty()) {
+                remaining.push(Fact {
+                    valid: part.clone(),
+                    recorded: ContinuousRange::From(at.clone()),
+                    value: fact.value.clone(),
+                });
+            }
+
+            let recorded = fact
+                .recorded
+                .intersection(&ContinuousRange::ToExclusive(at.clone()));
+            if !recorded.is_empty() {
+                facts.push(Fact { recorded, ..fact });
+            }
+        }
+
+        facts.extend(remaining);
+        self.facts = facts;
+        self.latest = Some(at);
+        Ok(())
+    }
+
+    /// The value known at the transaction time `transaction` to be true at the valid time `valid`
+    #[must_use]
+    pub fn as_of(&self, transaction: &Idx, valid: &Idx) -> Option<&V> {
+        self.facts
+            .iter()
+            .find(|fact| fact.recorded.contains(transaction) && fact.valid.contains(valid))
+            .map(|fact| &fact.value)
+    }
+
+    /// The facts that were part of the store at the transaction time `transaction`
+    #[must_use]
+    pub fn snapshot(&self, transaction: &Idx) -> Vec<&Fact<Idx, V>> {
+        self.facts
+            .iter()
+            .filter(|fact| fact.recorded.contains(transaction))
+            .collect()
+    }
+
+    /// All the facts that were ever recorded as true at the valid time `valid`, in the order they were
+    /// recorded
+    #[must_use]
+    pub fn history(&self, valid: &Idx) -> Vec<&Fact<Idx, V>> {
+        // Facts are only appended with increasing transaction times so they are already ordered
+        self.facts
+            .iter()
+            .filter(|fact| fact.valid.contains(valid))
+            .collect()
+    }
+
+    fn check_order(&self, at: &Idx) -> Result<(), BitemporalError<Idx>> {
+        match &self.latest {
+            Some(latest) if at < latest => Err(BitemporalError::Retroactive {
+                latest: latest.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
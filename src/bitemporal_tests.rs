@@ -0,0 +1,215 @@
+mod test_record {
+    use crate::{Bitemporal, BitemporalError, ContinuousRange, RangesRelation};
+
+    #[test]
+    pub fn disjoint_facts() {
+        let mut store = Bitemporal::new();
+        store
+            .record(ContinuousRange::EndExclusive(0, 10), "a", 1)
+            .unwrap();
+        store.record(ContinuousRange::From(10), "b", 2).unwrap();
+
+        assert_eq!(store.as_of(&2, &5), Some(&"a"));
+        assert_eq!(store.as_of(&2, &10), Some(&"b"));
+        assert_eq!(store.as_of(&1, &10), None);
+        assert_eq!(store.as_of(&0, &5), None);
+        assert_eq!(store.current().count(), 2);
+    }
+
+    #[test]
+    pub fn conflict() {
+        let mut store = Bitemporal::new();
+        store
+            .record(ContinuousRange::EndExclusive(0, 10), "a", 1)
+            .unwrap();
+
+        assert_eq!(
+            store.record(ContinuousRange::Inclusive(5, 20), "b", 2),
+            Err(BitemporalError::Conflict {
+                valid: ContinuousRange::EndExclusive(0, 10),
+                relation: RangesRelation::IsOverlapped,
+            })
+        );
+        assert_eq!(
+            store.record(ContinuousRange::Inclusive(2, 3), "b", 2),
+            Err(BitemporalError::Conflict {
+                valid: ContinuousRange::EndExclusive(0, 10),
+                relation: RangesRelation::IsStrictlyContained,
+            })
+        );
+        assert_eq!(store.facts().len(), 1);
+    }
+
+    #[test]
+    pub fn empty_valid_time() {
+        let mut store = Bitemporal::new();
+        store.record(ContinuousRange::From(0), "a", 1).unwrap();
+        store.record(ContinuousRange::Empty, "b", 2).unwrap();
+        store.record(ContinuousRange::Empty, "c", 3).unwrap();
+
+        assert!(store.conflicts(&ContinuousRange::Empty).is_empty());
+        assert_eq!(store.conflicts(&ContinuousRange::Full).len(), 1);
+        assert_eq!(store.as_of(&3, &5), Some(&"a"));
+    }
+
+    #[test]
+    pub fn retroactive() {
+        let mut store = Bitemporal::new();
+        store.record(ContinuousRange::Single(0), "a", 5).unwrap();
+
+        assert_eq!(
+            store.record(ContinuousRange::Single(1), "b", 4),
+            Err(BitemporalError::Retroactive { latest: 5 })
+        );
+        assert_eq!(
+            store.correct(ContinuousRange::Single(0), "b", 4),
+            Err(BitemporalError::Retroactive { latest: 5 })
+        );
+        assert_eq!(
+            store.retract(&ContinuousRange::Single(0), 4),
+            Err(BitemporalError::Retroactive { latest: 5 })
+        );
+        store.record(ContinuousRange::Single(1), "b", 5).unwrap();
+    }
+
+    #[test]
+    pub fn conflicts() {
+        let mut store = Bitemporal::new();
+        store
+            .record(ContinuousRange::EndExclusive(0, 10), "a", 1)
+            .unwrap();
+        store
+            .record(ContinuousRange::EndExclusive(10, 20), "b", 1)
+            .unwrap();
+
+        let conflicts = store.conflicts(&ContinuousRange::Inclusive(5, 10));
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].0.value, "a");
+        assert_eq!(conflicts[0].1, RangesRelation::IsOverlapped);
+        assert_eq!(conflicts[1].0.value, "b");
+        assert_eq!(conflicts[1].1, RangesRelation::Meets);
+        assert!(store.conflicts(&ContinuousRange::From(20)).is_empty());
+    }
+}
+
+mod test_correct {
+    use crate::{Bitemporal, ContinuousRange, Fact};
+
+    #[test]
+    pub fn splits_valid_time() {
+        let mut store = Bitemporal::new();
+        store
+            .record(ContinuousRange::EndExclusive(0, 30), "a", 1)
+            .unwrap();
+        store
+            .correct(ContinuousRange::EndExclusive(10, 20), "b", 2)
+            .unwrap();
+
+        assert_eq!(
+            store.facts(),
+            &[
+                Fact {
+                    valid: ContinuousRange::EndExclusive(0, 30),
+                    recorded: ContinuousRange::EndExclusive(1, 2),
+                    value: "a",
+                },
+                Fact {
+                    valid: ContinuousRange::EndExclusive(0, 10),
+                    recorded: ContinuousRange::From(2),
+                    value: "a",
+                },
+                Fact {
+                    valid: ContinuousRange::EndExclusive(20, 30),
+                    recorded: ContinuousRange::From(2),
+                    value: "a",
+                },
+                Fact {
+                    valid: ContinuousRange::EndExclusive(10, 20),
+                    recorded: ContinuousRange::From(2),
+                    value: "b",
+                },
+            ]
+        );
+
+        assert_eq!(store.as_of(&1, &15), Some(&"a"));
+        assert_eq!(store.as_of(&2, &15), Some(&"b"));
+        assert_eq!(store.as_of(&2, &5), Some(&"a"));
+        assert_eq!(store.as_of(&2, &25), Some(&"a"));
+        assert_eq!(store.snapshot(&1).len(), 1);
+        assert_eq!(store.snapshot(&3).len(), 3);
+    }
+
+    #[test]
+    pub fn same_transaction_time() {
+        let mut store = Bitemporal::new();
+        store.record(ContinuousRange::From(0), "a", 1).unwrap();
+        store.correct(ContinuousRange::From(0), "b", 1).unwrap();
+
+        assert_eq!(
+            store.facts(),
+            &[Fact {
+                valid: ContinuousRange::From(0),
+                recorded: ContinuousRange::From(1),
+                value: "b",
+            }]
+        );
+    }
+
+    #[test]
+    pub fn retract() {
+        let mut store = Bitemporal::new();
+        store
+            .record(ContinuousRange::EndExclusive(0, 10), "a", 1)
+            .unwrap();
+        store
+            .retract(&ContinuousRange::EndExclusive(5, 10), 2)
+            .unwrap();
+
+        assert_eq!(store.as_of(&1, &7), Some(&"a"));
+        assert_eq!(store.as_of(&2, &7), None);
+        assert_eq!(store.as_of(&2, &3), Some(&"a"));
+        assert_eq!(
+            store.current().map(|fact| &fact.valid).collect::<Vec<_>>(),
+            vec![&ContinuousRange::EndExclusive(0, 5)]
+        );
+    }
+
+    #[test]
+    pub fn history() {
+        let mut store = Bitemporal::new();
+        store.record(ContinuousRange::From(0), 1, 1).unwrap();
+        store.correct(ContinuousRange::From(5), 2, 2).unwrap();
+        store.correct(ContinuousRange::From(8), 3, 3).unwrap();
+
+        let values = |valid| {
+            store
+                .history(&valid)
+                .iter()
+                .map(|fact| fact.value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(9), vec![1, 2, 3]);
+        assert_eq!(values(6), vec![1, 2, 2]);
+        assert_eq!(values(0), vec![1, 1]);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn datetimes() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let day = |day| -> DateTime<Utc> { Utc.with_ymd_and_hms(2023, 1, day, 0, 0, 0).unwrap() };
+
+        let mut prices = Bitemporal::new();
+        prices
+            .record(ContinuousRange::From(day(1)), 100, day(1))
+            .unwrap();
+        prices
+            .correct(ContinuousRange::EndExclusive(day(5), day(10)), 90, day(7))
+            .unwrap();
+
+        assert_eq!(prices.as_of(&day(6), &day(8)), Some(&100));
+        assert_eq!(prices.as_of(&day(7), &day(8)), Some(&90));
+        assert_eq!(prices.as_of(&day(7), &day(10)), Some(&100));
+    }
+}
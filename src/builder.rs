@@ -0,0 +1,139 @@
+use std::{cmp::Ordering, ops::Bound};
+
+use crate::{continuous::is_comparable, ContinuousRange, RangeError};
+
+/// Build a [`ContinuousRange`] from its bounds, reporting invalid bounds instead of producing an empty range
+///
+/// Bounds that aren't set are unbounded.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, RangeBuilder, RangeError};
+///
+/// let range = RangeBuilder::new().start_inclusive(1).end_exclusive(5).build();
+/// assert_eq!(range, Ok(ContinuousRange::EndExclusive(1, 5)));
+///
+/// let range = RangeBuilder::new().start_inclusive(5).end_inclusive(1).build();
+/// assert_eq!(range, Err(RangeError::InvertedBounds));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RangeBuilder<Idx> {
+    start: Bound<Idx>,
+    end: Bound<Idx>,
+}
+
+impl<Idx> Default for RangeBuilder<Idx> {
+    fn default() -> Self {
+        RangeBuilder {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+        }
+    }
+}
+
+impl<Idx: PartialOrd + Clone> RangeBuilder<Idx> {
+    /// A builder where both bounds are unbounded
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the start bound
+    #[must_use]
+    pub fn start_bound(mut self, start: Bound<Idx>) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Set the end bound
+    #[must_use]
+    pub fn end_bound(mut self, end: Bound<Idx>) -> Self {
+        self.end = end;
+        self
+    }
+
+    /// Start the range at `start` (inclusive)
+    #[must_use]
+    pub fn start_inclusive(self, start: Idx) -> Self {
+        self.start_bound(Bound::Included(start))
+    }
+
+    /// Start the range after `start` (exclusive)
+    #[must_use]
+    pub fn start_exclusive(self, start: Idx) -> Self {
+        self.start_bound(Bound::Excluded(start))
+    }
+
+    /// End the range at `end` (inclusive)
+    #[must_use]
+    pub fn end_inclusive(self, end: Idx) -> Self {
+        self.end_bound(Bound::Included(end))
+    }
+
+    /// End the range before `end` (exclusive)
+    #[must_use]
+    pub fn end_exclusive(self, end: Idx) -> Self {
+        self.end_bound(Bound::Excluded(end))
+    }
+
+    /// Build the range
+    ///
+    /// A start equal to the end produces a [`ContinuousRange::Single`] when both bounds are included.
+    ///
+    /// # Errors
+    ///
+    /// - [`RangeError::IncomparableBounds`] if a bound can't be compared, like `NaN`.
+    /// - [`RangeError::InvertedBounds`] if the start is after the end.
+    /// - [`RangeError::DegenerateBounds`] if the start is equal to the end and one of them is excluded.
+    pub fn build(self) -> Result<ContinuousRange<Idx>, RangeError> {
+        for bound in [&self.start, &self.end] {
+            if let Bound::Included(value) | Bound::Excluded(value) = bound {
+                if !is_comparable(value) {
+                    return Err(RangeError::IncomparableBounds);
+                }
+            }
+        }
+
+        let ordering = match (&self.start, &self.end) {
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) => start
+                .partial_cmp(end)
+                .ok_or(RangeError::IncomparableBounds)?,
+            _ => Ordering::Less,
+        };
+
+        match ordering {
+            Ordering::Greater => return Err(RangeError::InvertedBounds),
+            Ordering::Equal => {
+                return match (self.start, self.end) {
+                    (Bound::Included(start), Bound::Included(_)) => {
+                        Ok(ContinuousRange::Single(start))
+                    }
+                    _ => Err(RangeError::DegenerateBounds),
+                };
+            }
+            Ordering::Less => {}
+        }
+
+        Ok(match (self.start, self.end) {
+            (Bound::Included(start), Bound::Included(end)) => {
+                ContinuousRange::Inclusive(start, end)
+            }
+            (Bound::Excluded(start), Bound::Excluded(end)) => {
+                ContinuousRange::Exclusive(start, end)
+            }
+            (Bound::Excluded(start), Bound::Included(end)) => {
+                ContinuousRange::StartExclusive(start, end)
+            }
+            (Bound::Included(start), Bound::Excluded(end)) => {
+                ContinuousRange::EndExclusive(start, end)
+            }
+            (Bound::Included(start), Bound::Unbounded) => ContinuousRange::From(start),
+            (Bound::Excluded(start), Bound::Unbounded) => ContinuousRange::FromExclusive(start),
+            (Bound::Unbounded, Bound::Included(end)) => ContinuousRange::To(end),
+            (Bound::Unbounded, Bound::Excluded(end)) => ContinuousRange::ToExclusive(end),
+            (Bound::Unbounded, Bound::Unbounded) => ContinuousRange::Full,
+        })
+    }
+}
@@ -0,0 +1,195 @@
+mod test_build {
+    use std::ops::Bound;
+
+    use crate::{ContinuousRange, RangeBuilder, RangeError};
+
+    #[test]
+    pub fn bounded() {
+        assert_eq!(
+            RangeBuilder::new()
+                .start_inclusive(1)
+                .end_inclusive(5)
+                .build(),
+            Ok(ContinuousRange::Inclusive(1, 5))
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_exclusive(1)
+                .end_exclusive(5)
+                .build(),
+            Ok(ContinuousRange::Exclusive(1, 5))
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_exclusive(1)
+                .end_inclusive(5)
+                .build(),
+            Ok(ContinuousRange::StartExclusive(1, 5))
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_inclusive(1)
+                .end_exclusive(5)
+                .build(),
+            Ok(ContinuousRange::EndExclusive(1, 5))
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            RangeBuilder::new().start_inclusive(1).build(),
+            Ok(ContinuousRange::From(1))
+        );
+        assert_eq!(
+            RangeBuilder::new().start_exclusive(1).build(),
+            Ok(ContinuousRange::FromExclusive(1))
+        );
+        assert_eq!(
+            RangeBuilder::new().end_inclusive(1).build(),
+            Ok(ContinuousRange::To(1))
+        );
+        assert_eq!(
+            RangeBuilder::new().end_exclusive(1).build(),
+            Ok(ContinuousRange::ToExclusive(1))
+        );
+        assert_eq!(
+            RangeBuilder::<i32>::new().build(),
+            Ok(ContinuousRange::Full)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_bound(Bound::Included(1))
+                .end_bound(Bound::Unbounded)
+                .build(),
+            Ok(ContinuousRange::From(1))
+        );
+    }
+
+    #[test]
+    pub fn single() {
+        assert_eq!(
+            RangeBuilder::new()
+                .start_inclusive(1)
+                .end_inclusive(1)
+                .build(),
+            Ok(ContinuousRange::Single(1))
+        );
+    }
+
+    #[test]
+    pub fn inverted() {
+        assert_eq!(
+            RangeBuilder::new()
+                .start_inclusive(5)
+                .end_inclusive(1)
+                .build(),
+            Err(RangeError::InvertedBounds)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_exclusive(5)
+                .end_exclusive(1)
+                .build(),
+            Err(RangeError::InvertedBounds)
+        );
+    }
+
+    #[test]
+    pub fn degenerate() {
+        assert_eq!(
+            RangeBuilder::new()
+                .start_exclusive(1)
+                .end_exclusive(1)
+                .build(),
+            Err(RangeError::DegenerateBounds)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_inclusive(1)
+                .end_exclusive(1)
+                .build(),
+            Err(RangeError::DegenerateBounds)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_exclusive(1)
+                .end_inclusive(1)
+                .build(),
+            Err(RangeError::DegenerateBounds)
+        );
+    }
+
+    #[test]
+    pub fn incomparable() {
+        assert_eq!(
+            RangeBuilder::new().start_inclusive(f64::NAN).build(),
+            Err(RangeError::IncomparableBounds)
+        );
+        assert_eq!(
+            RangeBuilder::new()
+                .start_inclusive(5.0)
+                .end_inclusive(f64::NAN)
+                .build(),
+            Err(RangeError::IncomparableBounds)
+        );
+    }
+}
+
+mod test_try_constructors {
+    use crate::{ContinuousRange, RangeError};
+
+    #[test]
+    pub fn valid() {
+        assert_eq!(
+            ContinuousRange::try_inclusive(1, 5),
+            Ok(ContinuousRange::Inclusive(1, 5))
+        );
+        assert_eq!(
+            ContinuousRange::try_inclusive(1, 1),
+            Ok(ContinuousRange::Single(1))
+        );
+        assert_eq!(
+            ContinuousRange::try_exclusive(1, 5),
+            Ok(ContinuousRange::Exclusive(1, 5))
+        );
+        assert_eq!(
+            ContinuousRange::try_start_exclusive(1, 5),
+            Ok(ContinuousRange::StartExclusive(1, 5))
+        );
+        assert_eq!(
+            ContinuousRange::try_end_exclusive(1, 5),
+            Ok(ContinuousRange::EndExclusive(1, 5))
+        );
+    }
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(
+            ContinuousRange::try_inclusive(5, 1),
+            Err(RangeError::InvertedBounds)
+        );
+        assert_eq!(
+            ContinuousRange::try_exclusive(1, 1),
+            Err(RangeError::DegenerateBounds)
+        );
+        assert_eq!(
+            ContinuousRange::try_start_exclusive(5, 1),
+            Err(RangeError::InvertedBounds)
+        );
+        assert_eq!(
+            ContinuousRange::try_end_exclusive(1, 1),
+            Err(RangeError::DegenerateBounds)
+        );
+        assert_eq!(
+            ContinuousRange::try_inclusive(f64::NAN, 1.0),
+            Err(RangeError::IncomparableBounds)
+        );
+    }
+
+    #[test]
+    pub fn lenient_unchanged() {
+        assert_eq!(ContinuousRange::inclusive(5, 1), ContinuousRange::Empty);
+        assert_eq!(ContinuousRange::exclusive(1, 1), ContinuousRange::Empty);
+    }
+}
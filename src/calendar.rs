@@ -0,0 +1,188 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+use crate::{continuous::bound_value, ContinuousRange, Range, RecurringRange};
+
+/// Working time every `weekday` from `start` to `end`, recurring every week
+fn weekly_shift(
+    weekday: Weekday,
+    start: NaiveTime,
+    end: NaiveTime,
+) -> Option<RecurringRange<NaiveDateTime>> {
+    // Any week can be used as the base of the recurrence, 2001-01-01 is a monday
+    let monday = NaiveDate::from_ymd_opt(2001, 1, 1)?;
+    let day =
+        monday.checked_add_signed(Duration::days(i64::from(weekday.num_days_from_monday())))?;
+    let end_day = if end > start {
+        day
+    } else {
+        day.checked_add_signed(Duration::days(1))?
+    };
+
+    let base = ContinuousRange::EndExclusive(day.and_time(start), end_day.and_time(end));
+    RecurringRange::new(base, Duration::weeks(1))
+}
+
+/// Working hours defined by a weekly pattern, minus holidays, used to compute durations and deadlines in
+/// working time
+///
+/// Times are floating local times, the calendar doesn't handle time zones.
+///
+/// ```
+/// use chrono::{Duration, NaiveDate, NaiveTime, Weekday};
+/// use range_ranger::WorkCalendar;
+///
+/// let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+/// let five = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+/// let calendar = WorkCalendar::new()
+///     .with_working_hours(Weekday::Mon, nine, five)
+///     .with_working_hours(Weekday::Tue, nine, five)
+///     .with_holiday_date(NaiveDate::from_ymd_opt(2023, 1, 9).unwrap());
+///
+/// // Friday 2023-01-06 at noon, the next working hours are on tuesday as monday is a holiday
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// assert_eq!(
+///     calendar.add_working_time(start, Duration::hours(2)),
+///     NaiveDate::from_ymd_opt(2023, 1, 10).unwrap().and_hms_opt(11, 0, 0)
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct WorkCalendar {
+    shifts: Vec<RecurringRange<NaiveDateTime>>,
+    holidays: Range<NaiveDateTime>,
+}
+
+impl Default for WorkCalendar {
+    fn default() -> Self {
+        WorkCalendar {
+            shifts: Vec::new(),
+            holidays: Range::empty(),
+        }
+    }
+}
+
+impl WorkCalendar {
+    /// A calendar without any working time
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add working time every `weekday` from `start` (inclusive) to `end` (exclusive)
+    ///
+    /// If `end` isn't after `start` the working time ends on the next day, like a night shift.
+    #[must_use]
+    pub fn with_working_hours(
+        mut self,
+        weekday: Weekday,
+        start: NaiveTime,
+        end: NaiveTime,
+    ) -> Self {
+        if let Some(shift) = weekly_shift(weekday, start, end) {
+            self.shifts.push(shift);
+        }
+        self
+    }
+
+    /// Exclude `holiday` from the working time
+    #[must_use]
+    pub fn with_holiday(mut self, holiday: impl Into<Range<NaiveDateTime>>) -> Self {
+        self.holidays = Range::composite(vec![self.holidays, holiday.into()]).simplify();
+        self
+    }
+
+    /// Exclude the whole day `date` from the working time
+    #[must_use]
+    pub fn with_holiday_date(self, date: NaiveDate) -> Self {
+        let start = date.and_time(NaiveTime::MIN);
+        match start.checked_add_signed(Duration::days(1)) {
+            Some(end) => self.with_holiday(ContinuousRange::EndExclusive(start, end)),
+            None => self.with_holiday(ContinuousRange::From(start)),
+        }
+    }
+
+    /// The working time contained in `window`
+    ///
+    /// Returns [`None`] if `window` is unbounded.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn working_ranges_within(
+        &self,
+        window: &ContinuousRange<NaiveDateTime>,
+    ) -> Option<Range<NaiveDateTime>> {
+        let mut working = Vec::new();
+        for shift in &self.shifts {
+            working.push(shift.occurrences_within(window)?);
+        }
+
+        Some(
+            Range::composite(working)
+                .simplify()
+                .difference(&self.holidays),
+        )
+    }
+
+    /// The total working time contained in `range`
+    ///
+    /// Returns [`None`] if `range` is unbounded.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn working_time_in(&self, range: &Range<NaiveDateTime>) -> Option<Duration> {
+        let mut total = Duration::zero();
+        for piece in range.simplify().pieces() {
+            total += self.working_ranges_within(piece)?.measure()?;
+        }
+        Some(total)
+    }
+
+    /// The instant reached after working for `duration` from `start`, like the deadline of a service level
+    /// agreement
+    ///
+    /// When the working time ends exactly at the end of working hours the end of these hours is returned, not
+    /// the start of the next ones. Returns [`None`] if `duration` is negative, if there isn't enough working
+    /// time after `start` or if the result can't be represented.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn add_working_time(
+        &self,
+        start: NaiveDateTime,
+        duration: Duration,
+    ) -> Option<NaiveDateTime> {
+        if duration < Duration::zero() {
+            return None;
+        }
+        if duration.is_zero() {
+            return Some(start);
+        }
+
+        let mut remaining = duration;
+        let mut cursor = start;
+        loop {
+            let end = cursor.checked_add_signed(Duration::weeks(1))?;
+            let week = ContinuousRange::EndExclusive(cursor, end);
+            for piece in self.working_ranges_within(&week)?.pieces() {
+                let length = piece.length()?;
+                if length >= remaining {
+                    let piece_start = piece.start().and_then(bound_value)?;
+                    return piece_start.checked_add_signed(remaining);
+                }
+                remaining -= length;
+            }
+
+            // Stop once there is no working time left, weeks without working time are all the same
+            let rest = Range::Continuous(ContinuousRange::From(end)).difference(&self.holidays);
+            if self.shifts.is_empty() || rest.is_empty() {
+                return None;
+            }
+            cursor = end;
+        }
+    }
+}
@@ -0,0 +1,242 @@
+mod test_working_ranges_within {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+    use crate::{ContinuousRange, Range, WorkCalendar};
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    pub fn weekly_pattern() {
+        let calendar = WorkCalendar::new()
+            .with_working_hours(Weekday::Mon, time(9), time(12))
+            .with_working_hours(Weekday::Mon, time(13), time(17))
+            .with_working_hours(Weekday::Wed, time(9), time(17));
+
+        // 2023-01-02 is a monday
+        assert_eq!(
+            calendar.working_ranges_within(&ContinuousRange::EndExclusive(at(2, 10), at(5, 0))),
+            Some(Range::Composite(vec![
+                ContinuousRange::EndExclusive(at(2, 10), at(2, 12)),
+                ContinuousRange::EndExclusive(at(2, 13), at(2, 17)),
+                ContinuousRange::EndExclusive(at(4, 9), at(4, 17)),
+            ]))
+        );
+    }
+
+    #[test]
+    pub fn night_shift() {
+        let calendar = WorkCalendar::new().with_working_hours(Weekday::Fri, time(22), time(6));
+
+        assert_eq!(
+            calendar.working_ranges_within(&ContinuousRange::EndExclusive(at(6, 0), at(8, 0))),
+            Some(Range::Continuous(ContinuousRange::EndExclusive(
+                at(6, 22),
+                at(7, 6)
+            )))
+        );
+    }
+
+    #[test]
+    pub fn holidays() {
+        let calendar = WorkCalendar::new()
+            .with_working_hours(Weekday::Mon, time(9), time(17))
+            .with_working_hours(Weekday::Tue, time(9), time(17))
+            .with_holiday_date(NaiveDate::from_ymd_opt(2023, 1, 2).unwrap())
+            .with_holiday(ContinuousRange::EndExclusive(at(3, 12), at(3, 14)));
+
+        assert_eq!(
+            calendar.working_ranges_within(&ContinuousRange::EndExclusive(at(1, 0), at(8, 0))),
+            Some(Range::Composite(vec![
+                ContinuousRange::EndExclusive(at(3, 9), at(3, 12)),
+                ContinuousRange::EndExclusive(at(3, 14), at(3, 17)),
+            ]))
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let calendar = WorkCalendar::new().with_working_hours(Weekday::Mon, time(9), time(17));
+
+        assert_eq!(
+            calendar.working_ranges_within(&ContinuousRange::From(at(1, 0))),
+            None
+        );
+    }
+}
+
+mod test_working_time_in {
+    use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+    use crate::{ContinuousRange, Range, WorkCalendar};
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn office() -> WorkCalendar {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let five = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+        .iter()
+        .fold(WorkCalendar::new(), |calendar, weekday| {
+            calendar.with_working_hours(*weekday, nine, five)
+        })
+    }
+
+    #[test]
+    pub fn weeks() {
+        let range = Range::Continuous(ContinuousRange::EndExclusive(at(2, 0), at(16, 0)));
+        assert_eq!(office().working_time_in(&range), Some(Duration::hours(80)));
+
+        let calendar = office().with_holiday_date(NaiveDate::from_ymd_opt(2023, 1, 6).unwrap());
+        assert_eq!(calendar.working_time_in(&range), Some(Duration::hours(72)));
+    }
+
+    #[test]
+    pub fn composite() {
+        let range = Range::Composite(vec![
+            ContinuousRange::EndExclusive(at(2, 8), at(2, 10)),
+            ContinuousRange::EndExclusive(at(2, 9), at(2, 12)),
+            ContinuousRange::EndExclusive(at(7, 0), at(8, 0)),
+        ]);
+        assert_eq!(office().working_time_in(&range), Some(Duration::hours(3)));
+    }
+
+    #[test]
+    pub fn empty_or_unbounded() {
+        assert_eq!(
+            office().working_time_in(&Range::empty()),
+            Some(Duration::zero())
+        );
+        assert_eq!(
+            office().working_time_in(&Range::Continuous(ContinuousRange::To(at(2, 0)))),
+            None
+        );
+    }
+}
+
+mod test_add_working_time {
+    use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+    use crate::{ContinuousRange, WorkCalendar};
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn office() -> WorkCalendar {
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let five = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+        .iter()
+        .fold(WorkCalendar::new(), |calendar, weekday| {
+            calendar.with_working_hours(*weekday, nine, five)
+        })
+    }
+
+    #[test]
+    pub fn within_a_day() {
+        assert_eq!(
+            office().add_working_time(at(2, 10), Duration::hours(4)),
+            Some(at(2, 14))
+        );
+    }
+
+    #[test]
+    pub fn over_a_weekend() {
+        // Friday 2023-01-06 at 15:00, 2 hours on friday and 6 on monday
+        assert_eq!(
+            office().add_working_time(at(6, 15), Duration::hours(8)),
+            Some(at(9, 15))
+        );
+        // Starting outside of the working hours
+        assert_eq!(
+            office().add_working_time(at(7, 12), Duration::hours(1)),
+            Some(at(9, 10))
+        );
+    }
+
+    #[test]
+    pub fn ends_with_working_hours() {
+        assert_eq!(
+            office().add_working_time(at(2, 9), Duration::hours(8)),
+            Some(at(2, 17))
+        );
+    }
+
+    #[test]
+    pub fn several_weeks() {
+        assert_eq!(
+            office().add_working_time(at(2, 9), Duration::hours(100)),
+            Some(at(18, 13))
+        );
+    }
+
+    #[test]
+    pub fn holidays() {
+        let calendar = office()
+            .with_holiday_date(NaiveDate::from_ymd_opt(2023, 1, 9).unwrap())
+            .with_holiday(ContinuousRange::EndExclusive(at(10, 9), at(10, 12)));
+        assert_eq!(
+            calendar.add_working_time(at(6, 15), Duration::hours(8)),
+            Some(at(11, 10))
+        );
+    }
+
+    #[test]
+    pub fn zero_or_negative() {
+        assert_eq!(
+            office().add_working_time(at(7, 12), Duration::zero()),
+            Some(at(7, 12))
+        );
+        assert_eq!(
+            office().add_working_time(at(2, 10), Duration::hours(-1)),
+            None
+        );
+    }
+
+    #[test]
+    pub fn not_enough_working_time() {
+        assert_eq!(
+            WorkCalendar::new().add_working_time(at(2, 10), Duration::hours(1)),
+            None
+        );
+
+        let calendar = office().with_holiday(ContinuousRange::From(at(4, 0)));
+        assert_eq!(
+            calendar.add_working_time(at(2, 10), Duration::hours(16)),
+            None
+        );
+        assert_eq!(
+            calendar.add_working_time(at(2, 10), Duration::hours(15)),
+            Some(at(3, 17))
+        );
+    }
+}
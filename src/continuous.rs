@@ -5,7 +5,9 @@ use std::{
     ops::{self, Bound},
 };
 
-use crate::RangesRelation;
+use crate::{
+    Discrete, Magnitude, Measure, PointRelation, RangeBuilder, RangeError, RangesRelation,
+};
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Hash, PartialEq)]
@@ -65,12 +67,12 @@ pub enum ContinuousRange<Idx> {
 }
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
-enum BoundSide {
+pub(crate) enum BoundSide {
     Start,
     End,
 }
 
-fn partial_cmp_bounds<Idx: PartialOrd>(
+pub(crate) fn partial_cmp_bounds<Idx: PartialOrd>(
     this: &Bound<&Idx>,
     this_side: BoundSide,
     other: &Bound<&Idx>,
@@ -132,19 +134,106 @@ fn partial_cmp_bounds<Idx: PartialOrd>(
     }
 }
 
-/// Get the Internal value of a bound or panics if [Unbounded][Bound::Unbounded].
-fn expect_bound<'a, Idx>(bound: Option<Bound<&'a Idx>>, msg: &'static str) -> &'a Idx {
-    match bound.expect(msg) {
-        Bound::Included(x) => x,
-        Bound::Excluded(x) => x,
-        Bound::Unbounded => panic!("{}", msg),
+/// Check if a value can be compared with itself, which isn't the case of floating point `NaN`.
+pub(crate) fn is_comparable<Idx: PartialOrd>(value: &Idx) -> bool {
+    value.partial_cmp(value).is_some()
+}
+
+/// Check if `start` is strictly lower than `end`, `false` if they can't be compared.
+fn is_lower<Idx: PartialOrd>(start: &Idx, end: &Idx) -> bool {
+    start.partial_cmp(end) == Some(Ordering::Less)
+}
+
+/// Compare two bounds, failing if their values can't be compared.
+pub(crate) fn try_cmp_bounds<Idx: PartialOrd>(
+    this: &Bound<&Idx>,
+    this_side: BoundSide,
+    other: &Bound<&Idx>,
+    other_side: BoundSide,
+) -> Result<Ordering, RangeError> {
+    partial_cmp_bounds(this, this_side, other, other_side).ok_or(RangeError::IncomparableBounds)
+}
+
+/// Check if the distance between `a` and `b` is at most `tolerance`, [`None`] if they can't be compared.
+pub(crate) fn within_tolerance<Idx: Measure>(
+    a: &Idx,
+    b: &Idx,
+    tolerance: &Idx::Length,
+) -> Option<bool> {
+    let distance = match a.partial_cmp(b)? {
+        Ordering::Less | Ordering::Equal => Idx::measure(a, b),
+        Ordering::Greater => Idx::measure(b, a),
+    };
+    Some(distance <= *tolerance)
+}
+
+/// Compare two bounds like [`partial_cmp_bounds`] but bounds with values within `tolerance` of each other are
+/// equal, whether they are included or not.
+pub(crate) fn cmp_bounds_with_tolerance<Idx: Measure>(
+    this: &Bound<&Idx>,
+    this_side: BoundSide,
+    other: &Bound<&Idx>,
+    other_side: BoundSide,
+    tolerance: &Idx::Length,
+) -> Option<Ordering> {
+    match (this, other) {
+        (
+            Bound::Included(this_value) | Bound::Excluded(this_value),
+            Bound::Included(other_value) | Bound::Excluded(other_value),
+        ) if within_tolerance(*this_value, *other_value, tolerance)? => Some(Ordering::Equal),
+        _ => partial_cmp_bounds(this, this_side, other, other_side),
+    }
+}
+
+/// Get the value of a bound or [`None`] if [Unbounded][Bound::Unbounded].
+pub(crate) fn bound_value<Idx>(bound: Bound<&Idx>) -> Option<&Idx> {
+    match bound {
+        Bound::Included(x) | Bound::Excluded(x) => Some(x),
+        Bound::Unbounded => None,
+    }
+}
+
+/// Unwrap a value that can only be missing if the [`PartialOrd`] contract isn't respected.
+fn expect_some<T>(value: Option<T>) -> Result<T, RangeError> {
+    value.ok_or(RangeError::InconsistentOrdering)
+}
+
+/// Handle the error of a fallible operation the way the infallible ones do: incomparable bounds produce
+/// `fallback` while an inconsistent ordering panics.
+fn lenient<T, Idx: fmt::Debug>(
+    result: Result<T, RangeError>,
+    fallback: T,
+    this: &ContinuousRange<Idx>,
+    other: &ContinuousRange<Idx>,
+) -> T {
+    match result {
+        Ok(value) => value,
+        Err(RangeError::IncomparableBounds) => fallback,
+        Err(RangeError::InconsistentOrdering) => panic!(
+            r"PartialOrd contract isn't correctly implemented.
+No ordering can be found between {this:?} and {other:?}",
+            this = this,
+            other = other
+        ),
+        Err(error @ (RangeError::InvertedBounds | RangeError::DegenerateBounds)) => {
+            unreachable!("Comparison and set operations never report {:?}", error)
+        }
+    }
+}
+
+/// Apply `f` to the value of a bound, keeping its kind.
+pub(crate) fn map_bound<Idx: Clone, T>(bound: Bound<&Idx>, f: impl FnOnce(Idx) -> T) -> Bound<T> {
+    match bound {
+        Bound::Included(x) => Bound::Included(f(x.clone())),
+        Bound::Excluded(x) => Bound::Excluded(f(x.clone())),
+        Bound::Unbounded => Bound::Unbounded,
     }
 }
 
 /// Reverse a bound between [`Bound::Included`] and [`Bound::Excluded`].
 ///
 /// [`Bound::Unbounded`] is kept as-is.
-fn reverse_bound<Idx>(bound: Bound<&Idx>) -> Bound<&Idx> {
+pub(crate) fn reverse_bound<Idx>(bound: Bound<&Idx>) -> Bound<&Idx> {
     match bound {
         Bound::Included(x) => Bound::Excluded(x),
         Bound::Excluded(x) => Bound::Included(x),
@@ -263,6 +352,124 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         ContinuousRange::ToExclusive(end)
     }
 
+    /// A range containing a single value, failing on invalid values
+    ///
+    /// `value`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::IncomparableBounds`] if the value can't be compared, like `NaN`.
+    pub fn try_single(value: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new()
+            .start_inclusive(value.clone())
+            .end_inclusive(value)
+            .build()
+    }
+
+    /// A range between `start` (inclusive) and `end` (inclusive), failing on invalid bounds
+    ///
+    /// `[start..end]`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::InvertedBounds`] if `start` is after `end` and [`RangeError::IncomparableBounds`] if
+    /// a bound can't be compared, like `NaN`.
+    pub fn try_inclusive(start: Idx, end: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new()
+            .start_inclusive(start)
+            .end_inclusive(end)
+            .build()
+    }
+
+    /// A range between `start` (exclusive) and `end` (exclusive), failing on invalid bounds
+    ///
+    /// `(start..end)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::InvertedBounds`] if `start` is after `end`, [`RangeError::DegenerateBounds`] if they
+    /// are equal and [`RangeError::IncomparableBounds`] if a bound can't be compared, like `NaN`.
+    pub fn try_exclusive(start: Idx, end: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new()
+            .start_exclusive(start)
+            .end_exclusive(end)
+            .build()
+    }
+
+    /// A range between `start` (exclusive) and `end` (inclusive), failing on invalid bounds
+    ///
+    /// `(start..end]`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::InvertedBounds`] if `start` is after `end`, [`RangeError::DegenerateBounds`] if they
+    /// are equal and [`RangeError::IncomparableBounds`] if a bound can't be compared, like `NaN`.
+    pub fn try_start_exclusive(start: Idx, end: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new()
+            .start_exclusive(start)
+            .end_inclusive(end)
+            .build()
+    }
+
+    /// A range between `start` (inclusive) and `end` (exclusive), failing on invalid bounds
+    ///
+    /// `[start..end)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::InvertedBounds`] if `start` is after `end`, [`RangeError::DegenerateBounds`] if they
+    /// are equal and [`RangeError::IncomparableBounds`] if a bound can't be compared, like `NaN`.
+    pub fn try_end_exclusive(start: Idx, end: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new()
+            .start_inclusive(start)
+            .end_exclusive(end)
+            .build()
+    }
+
+    /// A range starting from `start` (inclusive), failing on invalid bounds
+    ///
+    /// `[start..)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::IncomparableBounds`] if the bound can't be compared, like `NaN`.
+    pub fn try_from(start: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new().start_inclusive(start).build()
+    }
+
+    /// A range starting from `start` (exclusive), failing on invalid bounds
+    ///
+    /// `(start..)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::IncomparableBounds`] if the bound can't be compared, like `NaN`.
+    pub fn try_from_exclusive(start: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new().start_exclusive(start).build()
+    }
+
+    /// A range ending with `end` (inclusive), failing on invalid bounds
+    ///
+    /// `(..end]`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::IncomparableBounds`] if the bound can't be compared, like `NaN`.
+    pub fn try_to(end: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new().end_inclusive(end).build()
+    }
+
+    /// A range ending with `end` (exclusive), failing on invalid bounds
+    ///
+    /// `(..end)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::IncomparableBounds`] if the bound can't be compared, like `NaN`.
+    pub fn try_to_exclusive(end: Idx) -> Result<ContinuousRange<Idx>, RangeError> {
+        RangeBuilder::new().end_exclusive(end).build()
+    }
+
     /// A range containing all values
     #[must_use]
     pub fn full() -> ContinuousRange<Idx> {
@@ -293,6 +500,14 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         }
     }
 
+    /// Rebuild the range from its bounds, see [`ContinuousRange::from_bounds`]
+    fn normalize(self) -> Self {
+        match self.range_bounds() {
+            Some(bounds) => Self::from_bounds(bounds),
+            None => ContinuousRange::Empty,
+        }
+    }
+
     /// Get the bounds of the range or [None] if empty
     #[must_use]
     pub fn range_bounds(&self) -> Option<(Bound<&Idx>, Bound<&Idx>)> {
@@ -350,11 +565,17 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
     }
 
     /// Check if the range contains the provide value
+    ///
+    /// Values that can't be compared, like `NaN`, are never contained, even in [`ContinuousRange::Full`].
     #[must_use]
     pub fn contains(&self, value: impl Borrow<Idx>) -> bool
     where
         Idx: PartialOrd + PartialEq,
     {
+        if !is_comparable(value.borrow()) {
+            return false;
+        }
+
         match self {
             Self::Empty => false,
             Self::Single(single_value) => single_value == value.borrow(),
@@ -382,6 +603,156 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         }
     }
 
+    /// Find the position of `point` relative to the range
+    ///
+    /// A point equal to an excluded bound is outside of the range, [`PointRelation::Before`] for the start and
+    /// [`PointRelation::After`] for the end. For a [`ContinuousRange::Single`] containing the point
+    /// [`PointRelation::Starts`] is returned.
+    ///
+    /// Returns [`None`] if the range is empty or if the point can't be compared with its bounds.
+    #[must_use]
+    pub fn relate_point(&self, point: &Idx) -> Option<PointRelation> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (start, end) = self.range_bounds()?;
+        let start_relation = match start {
+            Bound::Included(start) => match point.partial_cmp(start)? {
+                Ordering::Less => return Some(PointRelation::Before),
+                Ordering::Equal => Some(PointRelation::Starts),
+                Ordering::Greater => None,
+            },
+            Bound::Excluded(start) => match point.partial_cmp(start)? {
+                Ordering::Less | Ordering::Equal => return Some(PointRelation::Before),
+                Ordering::Greater => None,
+            },
+            Bound::Unbounded => None,
+        };
+
+        let end_relation = match end {
+            Bound::Included(end) => match point.partial_cmp(end)? {
+                Ordering::Greater => return Some(PointRelation::After),
+                Ordering::Equal => Some(PointRelation::Finishes),
+                Ordering::Less => None,
+            },
+            Bound::Excluded(end) => match point.partial_cmp(end)? {
+                Ordering::Greater | Ordering::Equal => return Some(PointRelation::After),
+                Ordering::Less => None,
+            },
+            Bound::Unbounded => None,
+        };
+
+        Some(
+            start_relation
+                .or(end_relation)
+                .unwrap_or(PointRelation::During),
+        )
+    }
+
+    /// Get the value of the range nearest to `value`
+    ///
+    /// `value` is returned as-is when it's contained in the range, otherwise the nearest bound is returned if
+    /// it's included. [`None`] is returned if the nearest bound is excluded as there is no nearest value in that
+    /// case, see [`clamp_discrete`][ContinuousRange::clamp_discrete] for types where there is one. [`None`] is
+    /// also returned for empty ranges and values that can't be compared.
+    ///
+    /// ```text
+    /// [ A ]
+    ///   v   => v
+    ///         v => ]
+    /// ```
+    #[must_use]
+    pub fn clamp(&self, value: Idx) -> Option<Idx> {
+        self.clamp_with(value, |_| None, |_| None)
+    }
+
+    /// Get the value of the range nearest to `value` for a discrete type
+    ///
+    /// Behaves like [`clamp`][ContinuousRange::clamp] except that the value next to an excluded bound is
+    /// returned when it's the nearest one.
+    #[must_use]
+    pub fn clamp_discrete(&self, value: Idx) -> Option<Idx>
+    where
+        Idx: Discrete,
+    {
+        self.clamp_with(value, Discrete::successor, Discrete::predecessor)
+    }
+
+    fn clamp_with(
+        &self,
+        value: Idx,
+        after_start: impl FnOnce(&Idx) -> Option<Idx>,
+        before_end: impl FnOnce(&Idx) -> Option<Idx>,
+    ) -> Option<Idx> {
+        let relation = self.relate_point(&value)?;
+        let (start, end) = self.range_bounds()?;
+        match relation {
+            PointRelation::Starts | PointRelation::During | PointRelation::Finishes => Some(value),
+            PointRelation::Before => match start {
+                Bound::Included(start) => Some(start.clone()),
+                Bound::Excluded(start) => after_start(start).filter(|start| self.contains(start)),
+                Bound::Unbounded => None,
+            },
+            PointRelation::After => match end {
+                Bound::Included(end) => Some(end.clone()),
+                Bound::Excluded(end) => before_end(end).filter(|end| self.contains(end)),
+                Bound::Unbounded => None,
+            },
+        }
+    }
+
+    /// Move the bounds of `other` inside the range
+    ///
+    /// When the ranges intersect this is their intersection, otherwise `other` is collapsed to the bound of the
+    /// range nearest to it, producing an empty range if that bound is excluded.
+    ///
+    /// ```text
+    /// self:   [     ]
+    /// other:     [     ]  =>    [  ]
+    /// other:           [ ]  =>        |
+    /// ```
+    #[must_use]
+    pub fn clamp_range(&self, other: &ContinuousRange<Idx>) -> ContinuousRange<Idx> {
+        self.try_clamp_range(other)
+            .unwrap_or(ContinuousRange::Empty)
+    }
+
+    fn try_clamp_range(&self, other: &ContinuousRange<Idx>) -> Option<ContinuousRange<Idx>> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+
+        let (start, end) = self.range_bounds()?;
+        let (other_start, other_end) = other.range_bounds()?;
+
+        let collapse = |bound: Bound<&Idx>| match bound {
+            Bound::Included(value) => Some(ContinuousRange::Single(value.clone())),
+            Bound::Excluded(_) | Bound::Unbounded => None,
+        };
+        if partial_cmp_bounds(&other_end, BoundSide::End, &start, BoundSide::Start)?
+            == Ordering::Less
+        {
+            return collapse(start);
+        }
+        if partial_cmp_bounds(&other_start, BoundSide::Start, &end, BoundSide::End)?
+            == Ordering::Greater
+        {
+            return collapse(end);
+        }
+
+        let start =
+            match partial_cmp_bounds(&start, BoundSide::Start, &other_start, BoundSide::Start)? {
+                Ordering::Less => other_start,
+                Ordering::Equal | Ordering::Greater => start,
+            };
+        let end = match partial_cmp_bounds(&end, BoundSide::End, &other_end, BoundSide::End)? {
+            Ordering::Greater => other_end,
+            Ordering::Equal | Ordering::Less => end,
+        };
+        Some(ContinuousRange::from_bounds((start, end)))
+    }
+
     #[must_use]
     pub fn contains_range(&self, other: &ContinuousRange<Idx>) -> bool
     where
@@ -398,37 +769,47 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
         self.compare(other).map_or(true, |r| r.disjoint())
     }
 
+    /// Compute the union of two ranges or [`None`] if it can't be represented as a single range
+    ///
+    /// [`None`] is also returned if the bounds of the ranges can't be compared.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`try_union`][ContinuousRange::try_union] for a version returning an error instead.
     #[must_use]
-    #[allow(clippy::missing_panics_doc)]
     pub fn union(&self, other: &ContinuousRange<Idx>) -> Option<ContinuousRange<Idx>>
     where
         Idx: PartialOrd + std::fmt::Debug,
     {
-        match (self, other) {
+        lenient(self.try_union(other), None, self, other)
+    }
+
+    /// Compute the union of two ranges or [`None`] if it can't be represented as a single range
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bounds of the ranges can't be compared or if the [`PartialOrd`] contract isn't
+    /// respected.
+    pub fn try_union(
+        &self,
+        other: &ContinuousRange<Idx>,
+    ) -> Result<Option<ContinuousRange<Idx>>, RangeError> {
+        Ok(match (self, other) {
             (ContinuousRange::Empty, r) | (r, ContinuousRange::Empty) => Some(r.clone()),
             (ContinuousRange::Full, _) | (_, ContinuousRange::Full) => Some(ContinuousRange::Full),
-            _ => match self.compare(other) {
+            _ => match self.try_compare(other)? {
                 Some(cmp) => match cmp {
                     RangesRelation::StrictlyBefore => None,
                     RangesRelation::StrictlyAfter => None,
-                    RangesRelation::Meets => {
-                        let start = self.start().expect("Self meets without bounds");
-                        let end = other.end().expect("Other meets without bounds");
+                    RangesRelation::Meets | RangesRelation::Overlaps => {
+                        let start = expect_some(self.start())?;
+                        let end = expect_some(other.end())?;
                         Some(ContinuousRange::from_bounds((start, end)))
                     }
-                    RangesRelation::IsMet => {
-                        let end = self.end().expect("Self meets without bounds");
-                        let start = other.start().expect("Other meets without bounds");
-                        Some(ContinuousRange::from_bounds((start, end)))
-                    }
-                    RangesRelation::Overlaps => {
-                        let start = self.start().expect("Self meets without bounds");
-                        let end = other.end().expect("Other meets without bounds");
-                        Some(ContinuousRange::from_bounds((start, end)))
-                    }
-                    RangesRelation::IsOverlapped => {
-                        let end = self.end().expect("Self meets without bounds");
-                        let start = other.start().expect("Other meets without bounds");
+                    RangesRelation::IsMet | RangesRelation::IsOverlapped => {
+                        let end = expect_some(self.end())?;
+                        let start = expect_some(other.start())?;
                         Some(ContinuousRange::from_bounds((start, end)))
                     }
                     RangesRelation::Starts => Some(other.clone()),
@@ -441,42 +822,120 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
                 },
                 None => None,
             },
+        })
+    }
+
+    /// Compute the union of two ranges or [`None`] if it can't be represented as a single range, ranges separated
+    /// by at most `tolerance` are joined
+    ///
+    /// The gap between joined ranges is part of the result.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let a = ContinuousRange::EndExclusive(0.0, 1.0);
+    /// let b = ContinuousRange::Inclusive(1.001, 2.0);
+    /// assert_eq!(a.union_with_tolerance(&b, &0.01), Some(ContinuousRange::Inclusive(0.0, 2.0)));
+    /// assert_eq!(a.union_with_tolerance(&b, &0.0001), None);
+    /// ```
+    #[must_use]
+    pub fn union_with_tolerance(
+        &self,
+        other: &ContinuousRange<Idx>,
+        tolerance: &Idx::Length,
+    ) -> Option<ContinuousRange<Idx>>
+    where
+        Idx: Measure,
+    {
+        if self.is_empty() {
+            return Some(other.clone());
+        }
+        if other.is_empty() {
+            return Some(self.clone());
+        }
+
+        if self.compare_with_tolerance(other, tolerance)?.disjoint() {
+            return None;
         }
+
+        self.hull(other)
+    }
+
+    /// The smallest range containing both non-empty ranges
+    pub(crate) fn hull(&self, other: &ContinuousRange<Idx>) -> Option<ContinuousRange<Idx>> {
+        let (self_start, self_end) = self.range_bounds()?;
+        let (other_start, other_end) = other.range_bounds()?;
+
+        let start = match partial_cmp_bounds(
+            &self_start,
+            BoundSide::Start,
+            &other_start,
+            BoundSide::Start,
+        )? {
+            Ordering::Less | Ordering::Equal => self_start,
+            Ordering::Greater => other_start,
+        };
+        let end = match partial_cmp_bounds(&self_end, BoundSide::End, &other_end, BoundSide::End)? {
+            Ordering::Greater | Ordering::Equal => self_end,
+            Ordering::Less => other_end,
+        };
+        Some(ContinuousRange::from_bounds((start, end)))
     }
 
+    /// Compute the values contained in both ranges
+    ///
+    /// The result is empty if the bounds of the ranges can't be compared.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`try_intersection`][ContinuousRange::try_intersection] for a version returning an error instead.
     #[must_use]
-    #[allow(clippy::missing_panics_doc)]
     pub fn intersection(&self, other: &ContinuousRange<Idx>) -> ContinuousRange<Idx>
     where
         Idx: PartialOrd + std::fmt::Debug,
     {
-        match (self, other) {
+        lenient(
+            self.try_intersection(other),
+            ContinuousRange::Empty,
+            self,
+            other,
+        )
+    }
+
+    /// Compute the values contained in both ranges
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bounds of the ranges can't be compared or if the [`PartialOrd`] contract isn't
+    /// respected.
+    pub fn try_intersection(
+        &self,
+        other: &ContinuousRange<Idx>,
+    ) -> Result<ContinuousRange<Idx>, RangeError> {
+        Ok(match (self, other) {
             (ContinuousRange::Empty, _) | (_, ContinuousRange::Empty) => ContinuousRange::Empty,
             (ContinuousRange::Full, r) | (r, ContinuousRange::Full) => r.clone(),
-            _ => match self.compare(other) {
+            _ => match self.try_compare(other)? {
                 Some(cmp) => match cmp {
                     RangesRelation::StrictlyBefore => ContinuousRange::Empty,
                     RangesRelation::StrictlyAfter => ContinuousRange::Empty,
                     RangesRelation::Meets => {
-                        let end = expect_bound(self.end(), "Self meets without end bound");
+                        let end = expect_some(self.end().and_then(bound_value))?;
                         ContinuousRange::single(end.clone())
                     }
                     RangesRelation::IsMet => {
-                        let start = expect_bound(self.start(), "Self is met without start bound");
+                        let start = expect_some(self.start().and_then(bound_value))?;
                         ContinuousRange::single(start.clone())
                     }
                     RangesRelation::Overlaps => {
-                        let (_, end) = self.range_bounds().expect("Self overlaps without bounds");
-                        let (start, _) = other
-                            .range_bounds()
-                            .expect("Other is overlapped without bounds");
+                        let end = expect_some(self.end())?;
+                        let start = expect_some(other.start())?;
                         ContinuousRange::from_bounds((start, end))
                     }
                     RangesRelation::IsOverlapped => {
-                        let (start, _) = self
-                            .range_bounds()
-                            .expect("Self is overlapped without bounds");
-                        let (_, end) = other.range_bounds().expect("Other overlaps without bounds");
+                        let start = expect_some(self.start())?;
+                        let end = expect_some(other.end())?;
                         ContinuousRange::from_bounds((start, end))
                     }
                     RangesRelation::Starts => self.clone(),
@@ -490,19 +949,41 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
                 },
                 None => ContinuousRange::Empty,
             },
-        }
+        })
     }
 
+    /// Compute the values contained in `self` but not in `other` or [`None`] if they can't be represented as a
+    /// single range
+    ///
+    /// [`None`] is also returned if the bounds of the ranges can't be compared.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`try_difference`][ContinuousRange::try_difference] for a version returning an error instead.
     #[must_use]
-    #[allow(clippy::missing_panics_doc)]
     pub fn difference(&self, other: &ContinuousRange<Idx>) -> Option<ContinuousRange<Idx>>
     where
         Idx: PartialOrd + std::fmt::Debug,
     {
-        match (self, other) {
-            (ContinuousRange::Empty, r) => Some(r.clone()),
-            (_, ContinuousRange::Empty) => Some(ContinuousRange::Empty),
-            _ => match self.compare(other) {
+        lenient(self.try_difference(other), None, self, other)
+    }
+
+    /// Compute the values contained in `self` but not in `other` or [`None`] if they can't be represented as a
+    /// single range
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bounds of the ranges can't be compared or if the [`PartialOrd`] contract isn't
+    /// respected.
+    pub fn try_difference(
+        &self,
+        other: &ContinuousRange<Idx>,
+    ) -> Result<Option<ContinuousRange<Idx>>, RangeError> {
+        Ok(match (self, other) {
+            (ContinuousRange::Empty, _) => Some(ContinuousRange::Empty),
+            (r, ContinuousRange::Empty) => Some(r.clone()),
+            _ => match self.try_compare(other)? {
                 Some(cmp) => match cmp {
                     RangesRelation::StrictlyBefore => Some(self.clone()),
                     RangesRelation::StrictlyAfter => Some(self.clone()),
@@ -511,45 +992,31 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
                     RangesRelation::StrictlyContains => None,
 
                     RangesRelation::Meets => {
-                        let (start, end) = self.range_bounds().expect("Self meets without bounds");
+                        let (start, end) = expect_some(self.range_bounds())?;
                         let end = reverse_bound(end);
                         Some(ContinuousRange::from_bounds((start, end)))
                     }
                     RangesRelation::IsMet => {
-                        let (start, end) = self.range_bounds().expect("Self is met without bounds");
+                        let (start, end) = expect_some(self.range_bounds())?;
                         let start = reverse_bound(start);
                         Some(ContinuousRange::from_bounds((start, end)))
                     }
-                    RangesRelation::Overlaps => {
-                        let start = self.start().expect("Self overlaps without bounds");
-                        let end = other.start().expect("Other is overlapped without bounds");
-                        let end = reverse_bound(end);
+                    RangesRelation::Overlaps | RangesRelation::IsFinished => {
+                        let start = expect_some(self.start())?;
+                        let end = reverse_bound(expect_some(other.start())?);
                         Some(ContinuousRange::from_bounds((start, end)))
                     }
-                    RangesRelation::IsOverlapped => {
-                        let end = self.end().expect("Self is overlapped without bounds");
-                        let start = other.end().expect("Other overlaps without bounds");
-                        let start = reverse_bound(start);
+                    RangesRelation::IsOverlapped | RangesRelation::IsStarted => {
+                        let end = expect_some(self.end())?;
+                        let start = reverse_bound(expect_some(other.end())?);
                         Some(ContinuousRange::from_bounds((start, end)))
                     }
                     RangesRelation::Starts => Some(ContinuousRange::Empty),
-                    RangesRelation::IsStarted => {
-                        let end = self.end().expect("Self is overlapped without bounds");
-                        let start = other.end().expect("Other overlaps without bounds");
-                        let start = reverse_bound(start);
-                        Some(ContinuousRange::from_bounds((start, end)))
-                    }
                     RangesRelation::Finishes => Some(ContinuousRange::Empty),
-                    RangesRelation::IsFinished => {
-                        let start = self.start().expect("Self overlaps without bounds");
-                        let end = other.start().expect("Other is overlapped without bounds");
-                        let end = reverse_bound(end);
-                        Some(ContinuousRange::from_bounds((start, end)))
-                    }
                 },
                 None => None,
             },
-        }
+        })
     }
 
     #[must_use]
@@ -570,120 +1037,161 @@ impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
 
     #[must_use]
     /// Compare the bounds of two ranges. Returns [`Option::None`] if an empty range is
-    /// compared to a non-empty range.
+    /// compared to a non-empty range or if the bounds can't be compared.
     ///
     /// # Panics
     ///
-    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`try_compare`][ContinuousRange::try_compare] for a version returning an error instead.
     pub fn compare(&self, other: &ContinuousRange<Idx>) -> Option<RangesRelation>
     where
         Idx: std::fmt::Debug,
     {
+        lenient(self.try_compare(other), None, self, other)
+    }
+
+    /// Compare the bounds of two ranges. Returns [`Option::None`] if an empty range is
+    /// compared to a non-empty range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::IncomparableBounds`] if some bounds can't be compared and
+    /// [`RangeError::InconsistentOrdering`] if the [`PartialOrd`] contract isn't respected.
+    pub fn try_compare(
+        &self,
+        other: &ContinuousRange<Idx>,
+    ) -> Result<Option<RangesRelation>, RangeError> {
+        self.try_compare_by(other, partial_cmp_bounds)
+    }
+
+    /// Compare the bounds of two ranges, considering bounds within `tolerance` of each other as equal, even if
+    /// only one of them is included.
+    ///
+    /// Returns [`Option::None`] if an empty range is compared to a non-empty range or if the bounds can't be
+    /// compared. When the tolerance makes the ordering of the bounds inconsistent, typically for ranges shorter
+    /// than the tolerance, the exact relation is returned instead.
+    ///
+    /// ```
+    /// use range_ranger::{ContinuousRange, RangesRelation};
+    ///
+    /// let a = ContinuousRange::Inclusive(0.0, 1.0);
+    /// let b = ContinuousRange::Inclusive(1.001, 2.0);
+    /// assert_eq!(a.compare_with_tolerance(&b, &0.01), Some(RangesRelation::Meets));
+    /// ```
+    #[must_use]
+    pub fn compare_with_tolerance(
+        &self,
+        other: &ContinuousRange<Idx>,
+        tolerance: &Idx::Length,
+    ) -> Option<RangesRelation>
+    where
+        Idx: Measure,
+    {
+        let tolerant = self.try_compare_by(other, |this, this_side, other, other_side| {
+            cmp_bounds_with_tolerance(this, this_side, other, other_side, tolerance)
+        });
+        match tolerant {
+            Ok(relation) => relation,
+            Err(_) => self.try_compare(other).ok().flatten(),
+        }
+    }
+
+    fn try_compare_by(
+        &self,
+        other: &ContinuousRange<Idx>,
+        cmp_bounds: impl Fn(&Bound<&Idx>, BoundSide, &Bound<&Idx>, BoundSide) -> Option<Ordering>,
+    ) -> Result<Option<RangesRelation>, RangeError> {
+        let try_cmp_bounds = |this: &Bound<&Idx>, this_side, other: &Bound<&Idx>, other_side| {
+            cmp_bounds(this, this_side, other, other_side).ok_or(RangeError::IncomparableBounds)
+        };
+
         // Inspired by "Maintaining Knowledge about Temporal Intervals" by James F. Allen
         // Communications of the ACM - November 1983 - Volume 26 - Number 11
 
         // Empty ranges don't have bounds so we need to special case them before anything else
         if self.is_empty() {
-            return if other.is_empty() {
+            return Ok(if other.is_empty() {
                 Some(RangesRelation::Equal)
             } else {
                 None
-            };
+            });
         } else if other.is_empty() {
-            return None;
+            return Ok(None);
         }
 
-        let (self_start, self_end) = self
-            .range_bounds()
-            .expect("Non-empty self should have bounds");
-        let (other_start, other_end) = other
-            .range_bounds()
-            .expect("Non-empty other should have bounds");
+        let (self_start, self_end) = expect_some(self.range_bounds())?;
+        let (other_start, other_end) = expect_some(other.range_bounds())?;
 
         let cmp_end_start =
-            partial_cmp_bounds(&self_end, BoundSide::End, &other_start, BoundSide::Start)?;
+            try_cmp_bounds(&self_end, BoundSide::End, &other_start, BoundSide::Start)?;
 
         if cmp_end_start == Ordering::Less {
-            return Some(RangesRelation::StrictlyBefore);
+            return Ok(Some(RangesRelation::StrictlyBefore));
         }
 
         let cmp_start_end =
-            partial_cmp_bounds(&self_start, BoundSide::Start, &other_end, BoundSide::End)?;
+            try_cmp_bounds(&self_start, BoundSide::Start, &other_end, BoundSide::End)?;
 
         if cmp_start_end == Ordering::Greater {
-            return Some(RangesRelation::StrictlyAfter);
+            return Ok(Some(RangesRelation::StrictlyAfter));
         }
 
-        let self_cmp =
-            partial_cmp_bounds(&self_start, BoundSide::Start, &self_end, BoundSide::End)?;
+        let self_cmp = try_cmp_bounds(&self_start, BoundSide::Start, &self_end, BoundSide::End)?;
 
-        let other_cmp =
-            partial_cmp_bounds(&other_start, BoundSide::Start, &other_end, BoundSide::End)?;
+        let other_cmp = try_cmp_bounds(&other_start, BoundSide::Start, &other_end, BoundSide::End)?;
 
         if cmp_end_start == Ordering::Equal
             && self_cmp != Ordering::Equal
             && other_cmp != Ordering::Equal
         {
-            return Some(RangesRelation::Meets);
+            return Ok(Some(RangesRelation::Meets));
         }
         if cmp_start_end == Ordering::Equal
             && self_cmp != Ordering::Equal
             && other_cmp != Ordering::Equal
         {
-            return Some(RangesRelation::IsMet);
+            return Ok(Some(RangesRelation::IsMet));
         }
 
-        let cmp_start_start = partial_cmp_bounds(
+        let cmp_start_start = try_cmp_bounds(
             &self_start,
             BoundSide::Start,
             &other_start,
             BoundSide::Start,
         )?;
 
-        let cmp_end_end =
-            partial_cmp_bounds(&self_end, BoundSide::End, &other_end, BoundSide::End)?;
+        let cmp_end_end = try_cmp_bounds(&self_end, BoundSide::End, &other_end, BoundSide::End)?;
 
-        if cmp_start_start == Ordering::Less
+        let relation = if cmp_start_start == Ordering::Less
             && cmp_end_start == Ordering::Greater
             && cmp_end_end == Ordering::Less
         {
-            return Some(RangesRelation::Overlaps);
-        }
-        if cmp_start_start == Ordering::Greater
+            RangesRelation::Overlaps
+        } else if cmp_start_start == Ordering::Greater
             && cmp_start_end == Ordering::Less
             && cmp_end_end == Ordering::Greater
         {
-            return Some(RangesRelation::IsOverlapped);
-        }
-        if cmp_start_start == Ordering::Equal && cmp_end_end == Ordering::Less {
-            return Some(RangesRelation::Starts);
-        }
-        if cmp_start_start == Ordering::Equal && cmp_end_end == Ordering::Greater {
-            return Some(RangesRelation::IsStarted);
-        }
-        if cmp_start_start == Ordering::Greater && cmp_end_end == Ordering::Equal {
-            return Some(RangesRelation::Finishes);
-        }
-        if cmp_start_start == Ordering::Less && cmp_end_end == Ordering::Equal {
-            return Some(RangesRelation::IsFinished);
-        }
-        if cmp_start_start == Ordering::Less && cmp_end_end == Ordering::Greater {
-            return Some(RangesRelation::StrictlyContains);
-        }
-        if cmp_start_start == Ordering::Greater && cmp_end_end == Ordering::Less {
-            return Some(RangesRelation::IsStrictlyContained);
-        }
-        if cmp_start_start == Ordering::Equal && cmp_end_end == Ordering::Equal {
-            return Some(RangesRelation::Equal);
-        }
+            RangesRelation::IsOverlapped
+        } else if cmp_start_start == Ordering::Equal && cmp_end_end == Ordering::Less {
+            RangesRelation::Starts
+        } else if cmp_start_start == Ordering::Equal && cmp_end_end == Ordering::Greater {
+            RangesRelation::IsStarted
+        } else if cmp_start_start == Ordering::Greater && cmp_end_end == Ordering::Equal {
+            RangesRelation::Finishes
+        } else if cmp_start_start == Ordering::Less && cmp_end_end == Ordering::Equal {
+            RangesRelation::IsFinished
+        } else if cmp_start_start == Ordering::Less && cmp_end_end == Ordering::Greater {
+            RangesRelation::StrictlyContains
+        } else if cmp_start_start == Ordering::Greater && cmp_end_end == Ordering::Less {
+            RangesRelation::IsStrictlyContained
+        } else if cmp_start_start == Ordering::Equal && cmp_end_end == Ordering::Equal {
+            RangesRelation::Equal
+        } else {
+            // Should be unreachable if PartialOrd contract is correctly implemented
+            return Err(RangeError::InconsistentOrdering);
+        };
 
-        // Should be unreachable if PartialOrd contract is correctly implemented
-        panic!(
-            r"PartialOrd contract isn't correctly implemented.
-No ordering can be found between {self:?} and {other:?}",
-            self = &self,
-            other = &other
-        );
+        Ok(Some(relation))
     }
 
     pub fn simplify_mut(&mut self)
@@ -691,27 +1199,28 @@ No ordering can be found between {self:?} and {other:?}",
         Idx: PartialOrd,
     {
         match self {
-            ContinuousRange::Inclusive(start, end) => {
-                if start == end {
-                    *self = ContinuousRange::Single(start.clone());
-                } else if start > end {
-                    *self = ContinuousRange::Empty;
-                }
-            }
+            ContinuousRange::Inclusive(start, end) => match start.partial_cmp(&end) {
+                Some(Ordering::Equal) => *self = ContinuousRange::Single(start.clone()),
+                Some(Ordering::Greater) | None => *self = ContinuousRange::Empty,
+                Some(Ordering::Less) => {}
+            },
             ContinuousRange::Exclusive(start, end)
             | ContinuousRange::StartExclusive(start, end)
             | ContinuousRange::EndExclusive(start, end) => {
-                if start >= end {
+                if !is_lower(start, end) {
                     *self = ContinuousRange::Empty;
                 }
             }
-            ContinuousRange::Empty
-            | ContinuousRange::Single(_)
-            | ContinuousRange::From(_)
-            | ContinuousRange::FromExclusive(_)
-            | ContinuousRange::To(_)
-            | ContinuousRange::ToExclusive(_)
-            | ContinuousRange::Full => {}
+            ContinuousRange::Single(value)
+            | ContinuousRange::From(value)
+            | ContinuousRange::FromExclusive(value)
+            | ContinuousRange::To(value)
+            | ContinuousRange::ToExclusive(value) => {
+                if !is_comparable(value) {
+                    *self = ContinuousRange::Empty;
+                }
+            }
+            ContinuousRange::Empty | ContinuousRange::Full => {}
         }
     }
 
@@ -730,20 +1239,23 @@ No ordering can be found between {self:?} and {other:?}",
         match self {
             Self::Empty => true,
 
-            // bounded ranges with inverted bounds are considered empty
-            Self::Inclusive(start, end) => start > end,
+            // bounded ranges with inverted or incomparable bounds are considered empty
+            Self::Inclusive(start, end) => !matches!(
+                start.partial_cmp(end),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
             Self::Exclusive(start, end)
             | Self::StartExclusive(start, end)
-            | Self::EndExclusive(start, end) => start >= end,
+            | Self::EndExclusive(start, end) => !is_lower(start, end),
 
-            Self::Single(_)
+            // other ranges are only empty if their value is incomparable (NaN)
+            Self::Single(value)
+            | Self::From(value)
+            | Self::FromExclusive(value)
+            | Self::To(value)
+            | Self::ToExclusive(value) => !is_comparable(value),
 
-            // unbounded ranges can't be empty
-            | Self::From(_)
-            | Self::FromExclusive(_)
-            | Self::To(_)
-            | Self::ToExclusive(_)
-            | Self::Full => false,
+            Self::Full => false,
         }
     }
 
@@ -751,6 +1263,193 @@ No ordering can be found between {self:?} and {other:?}",
     pub fn is_full(&self) -> bool {
         matches!(self, Self::Full)
     }
+
+    /// Get the distance between the start and the end of the range or [`None`] if it's unbounded
+    ///
+    /// The length doesn't depend on the inclusivity of the bounds, `[1..5]` and `(1..5)` both have a length
+    /// of `4`.
+    #[must_use]
+    pub fn length(&self) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        if self.is_empty() {
+            return Some(Idx::Length::zero());
+        }
+
+        match self.range_bounds()? {
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) => Some(Idx::measure(start, end)),
+            _ => None,
+        }
+    }
+
+    /// Count the number of values contained in the range or [`None`] if it's unbounded
+    ///
+    /// [`None`] is also returned if the count doesn't fit in a [`u128`].
+    #[must_use]
+    pub fn count(&self) -> Option<u128>
+    where
+        Idx: Discrete,
+    {
+        if self.is_empty() {
+            return Some(0);
+        }
+
+        let (start, end) = self.range_bounds()?;
+        let first = match start {
+            Bound::Included(start) => Some(start.clone()),
+            Bound::Excluded(start) => start.successor(),
+            Bound::Unbounded => return None,
+        };
+        let last = match end {
+            Bound::Included(end) => Some(end.clone()),
+            Bound::Excluded(end) => end.predecessor(),
+            Bound::Unbounded => return None,
+        };
+
+        match (first, last) {
+            (Some(first), Some(last)) if first <= last => {
+                Idx::steps_between(&first, &last)?.checked_add(1)
+            }
+            _ => Some(0),
+        }
+    }
+}
+
+impl<Idx> ContinuousRange<Idx> {
+    /// Convert the bounds of the range with a monotonically increasing function, keeping their kind
+    ///
+    /// `f` must preserve the ordering of the values, for a decreasing function use
+    /// [`ContinuousRange::map_bounds_decreasing`]. The result is rebuilt with [`ContinuousRange::from_bounds`], so
+    /// bounds that become equal produce a single value or an empty range.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::EndExclusive(1u32, 5);
+    /// assert_eq!(range.map_bounds(u64::from), ContinuousRange::EndExclusive(1u64, 5));
+    /// ```
+    #[must_use]
+    pub fn map_bounds<T>(self, mut f: impl FnMut(Idx) -> T) -> ContinuousRange<T>
+    where
+        T: PartialOrd + Clone,
+    {
+        let mapped = match self {
+            ContinuousRange::Empty => ContinuousRange::Empty,
+            ContinuousRange::Single(value) => ContinuousRange::Single(f(value)),
+            ContinuousRange::Inclusive(start, end) => ContinuousRange::Inclusive(f(start), f(end)),
+            ContinuousRange::Exclusive(start, end) => ContinuousRange::Exclusive(f(start), f(end)),
+            ContinuousRange::StartExclusive(start, end) => {
+                ContinuousRange::StartExclusive(f(start), f(end))
+            }
+            ContinuousRange::EndExclusive(start, end) => {
+                ContinuousRange::EndExclusive(f(start), f(end))
+            }
+            ContinuousRange::From(start) => ContinuousRange::From(f(start)),
+            ContinuousRange::FromExclusive(start) => ContinuousRange::FromExclusive(f(start)),
+            ContinuousRange::To(end) => ContinuousRange::To(f(end)),
+            ContinuousRange::ToExclusive(end) => ContinuousRange::ToExclusive(f(end)),
+            ContinuousRange::Full => ContinuousRange::Full,
+        };
+        mapped.normalize()
+    }
+
+    /// Convert the bounds of the range with a monotonically decreasing function
+    ///
+    /// The converted start becomes the end of the new range and the converted end its start, each keeping its
+    /// kind. The result is rebuilt with [`ContinuousRange::from_bounds`] like for
+    /// [`map_bounds`][ContinuousRange::map_bounds].
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::StartExclusive(1, 5);
+    /// assert_eq!(range.map_bounds_decreasing(|x| -x), ContinuousRange::EndExclusive(-5, -1));
+    /// assert_eq!(ContinuousRange::From(1).map_bounds_decreasing(|x| -x), ContinuousRange::To(-1));
+    /// ```
+    #[must_use]
+    pub fn map_bounds_decreasing<T>(self, mut f: impl FnMut(Idx) -> T) -> ContinuousRange<T>
+    where
+        T: PartialOrd + Clone,
+    {
+        let mapped = match self {
+            ContinuousRange::Empty => ContinuousRange::Empty,
+            ContinuousRange::Single(value) => ContinuousRange::Single(f(value)),
+            ContinuousRange::Inclusive(start, end) => {
+                let start = f(start);
+                ContinuousRange::Inclusive(f(end), start)
+            }
+            ContinuousRange::Exclusive(start, end) => {
+                let start = f(start);
+                ContinuousRange::Exclusive(f(end), start)
+            }
+            ContinuousRange::StartExclusive(start, end) => {
+                let start = f(start);
+                ContinuousRange::EndExclusive(f(end), start)
+            }
+            ContinuousRange::EndExclusive(start, end) => {
+                let start = f(start);
+                ContinuousRange::StartExclusive(f(end), start)
+            }
+            ContinuousRange::From(start) => ContinuousRange::To(f(start)),
+            ContinuousRange::FromExclusive(start) => ContinuousRange::ToExclusive(f(start)),
+            ContinuousRange::To(end) => ContinuousRange::From(f(end)),
+            ContinuousRange::ToExclusive(end) => ContinuousRange::FromExclusive(f(end)),
+            ContinuousRange::Full => ContinuousRange::Full,
+        };
+        mapped.normalize()
+    }
+
+    /// Convert the bounds of the range with a fallible monotonically increasing function, keeping their kind
+    ///
+    /// The result is rebuilt with [`ContinuousRange::from_bounds`] like for
+    /// [`map_bounds`][ContinuousRange::map_bounds].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`.
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::Inclusive(1u64, 5);
+    /// assert_eq!(range.try_map_bounds(u8::try_from), Ok(ContinuousRange::Inclusive(1u8, 5)));
+    /// assert!(ContinuousRange::From(300u64).try_map_bounds(u8::try_from).is_err());
+    /// ```
+    pub fn try_map_bounds<T, E>(
+        self,
+        mut f: impl FnMut(Idx) -> Result<T, E>,
+    ) -> Result<ContinuousRange<T>, E>
+    where
+        T: PartialOrd + Clone,
+    {
+        let mapped = match self {
+            ContinuousRange::Empty => ContinuousRange::Empty,
+            ContinuousRange::Single(value) => ContinuousRange::Single(f(value)?),
+            ContinuousRange::Inclusive(start, end) => {
+                ContinuousRange::Inclusive(f(start)?, f(end)?)
+            }
+            ContinuousRange::Exclusive(start, end) => {
+                ContinuousRange::Exclusive(f(start)?, f(end)?)
+            }
+            ContinuousRange::StartExclusive(start, end) => {
+                ContinuousRange::StartExclusive(f(start)?, f(end)?)
+            }
+            ContinuousRange::EndExclusive(start, end) => {
+                ContinuousRange::EndExclusive(f(start)?, f(end)?)
+            }
+            ContinuousRange::From(start) => ContinuousRange::From(f(start)?),
+            ContinuousRange::FromExclusive(start) => ContinuousRange::FromExclusive(f(start)?),
+            ContinuousRange::To(end) => ContinuousRange::To(f(end)?),
+            ContinuousRange::ToExclusive(end) => ContinuousRange::ToExclusive(f(end)?),
+            ContinuousRange::Full => ContinuousRange::Full,
+        };
+        Ok(mapped.normalize())
+    }
 }
 
 impl<Idx: PartialOrd + Clone> From<()> for ContinuousRange<Idx> {
@@ -859,6 +1558,8 @@ impl<Idx: fmt::Debug> fmt::Debug for ContinuousRange<Idx> {
     }
 }
 
+// Deriving would require `Idx: Default`
+#[allow(clippy::derivable_impls)]
 impl<Idx> Default for ContinuousRange<Idx> {
     fn default() -> Self {
         Self::Empty
@@ -1692,3 +1692,618 @@ mod test_intersection {
         );
     }
 }
+
+mod test_nan {
+    use assert_matches::assert_matches;
+
+    use crate::{ContinuousRange, RangeError};
+
+    #[test]
+    pub fn constructors_are_empty() {
+        assert!(ContinuousRange::single(f64::NAN).is_empty());
+        assert!(ContinuousRange::inclusive(f64::NAN, 1.0).is_empty());
+        assert!(ContinuousRange::inclusive(1.0, f64::NAN).is_empty());
+        assert!(ContinuousRange::exclusive(f64::NAN, 1.0).is_empty());
+        assert!(ContinuousRange::start_exclusive(1.0, f64::NAN).is_empty());
+        assert!(ContinuousRange::end_exclusive(f64::NAN, f64::NAN).is_empty());
+        assert!(ContinuousRange::from(f64::NAN).is_empty());
+        assert!(ContinuousRange::from_exclusive(f64::NAN).is_empty());
+        assert!(ContinuousRange::to(f64::NAN).is_empty());
+        assert!(ContinuousRange::to_exclusive(f64::NAN).is_empty());
+    }
+
+    #[test]
+    pub fn constructors_accept_numbers() {
+        assert_matches!(
+            ContinuousRange::inclusive(1.0, 1.0),
+            ContinuousRange::Single(_)
+        );
+        assert_matches!(
+            ContinuousRange::inclusive(1.0, 2.0),
+            ContinuousRange::Inclusive(_, _)
+        );
+        assert_matches!(
+            ContinuousRange::from(f64::INFINITY),
+            ContinuousRange::From(_)
+        );
+    }
+
+    #[test]
+    pub fn try_constructors() {
+        let incomparable = Err(RangeError::IncomparableBounds);
+        assert_eq!(ContinuousRange::try_single(f64::NAN), incomparable);
+        assert_eq!(ContinuousRange::try_inclusive(f64::NAN, 1.0), incomparable);
+        assert_eq!(ContinuousRange::try_exclusive(1.0, f64::NAN), incomparable);
+        assert_eq!(
+            ContinuousRange::try_start_exclusive(f64::NAN, 1.0),
+            incomparable
+        );
+        assert_eq!(
+            ContinuousRange::try_end_exclusive(1.0, f64::NAN),
+            incomparable
+        );
+        assert_eq!(ContinuousRange::try_from(f64::NAN), incomparable);
+        assert_eq!(ContinuousRange::try_from_exclusive(f64::NAN), incomparable);
+        assert_eq!(ContinuousRange::try_to(f64::NAN), incomparable);
+        assert_eq!(ContinuousRange::try_to_exclusive(f64::NAN), incomparable);
+
+        assert_eq!(
+            ContinuousRange::try_single(1.0),
+            Ok(ContinuousRange::Single(1.0))
+        );
+        assert_eq!(
+            ContinuousRange::try_from(1.0),
+            Ok(ContinuousRange::From(1.0))
+        );
+        assert_eq!(
+            ContinuousRange::try_to_exclusive(1.0),
+            Ok(ContinuousRange::ToExclusive(1.0))
+        );
+    }
+
+    #[test]
+    pub fn is_empty() {
+        assert!(ContinuousRange::Single(f64::NAN).is_empty());
+        assert!(ContinuousRange::Inclusive(f64::NAN, 1.0).is_empty());
+        assert!(ContinuousRange::Inclusive(1.0, f64::NAN).is_empty());
+        assert!(ContinuousRange::Exclusive(f64::NAN, f64::NAN).is_empty());
+        assert!(ContinuousRange::From(f64::NAN).is_empty());
+        assert!(ContinuousRange::ToExclusive(f64::NAN).is_empty());
+        assert!(!ContinuousRange::<f64>::Full.is_empty());
+    }
+
+    #[test]
+    pub fn simplify() {
+        assert_matches!(
+            ContinuousRange::Inclusive(f64::NAN, 1.0).simplify(),
+            ContinuousRange::Empty
+        );
+        assert_matches!(
+            ContinuousRange::StartExclusive(1.0, f64::NAN).simplify(),
+            ContinuousRange::Empty
+        );
+        assert_matches!(
+            ContinuousRange::To(f64::NAN).simplify(),
+            ContinuousRange::Empty
+        );
+    }
+
+    #[test]
+    pub fn contains() {
+        assert!(!ContinuousRange::<f64>::Full.contains(f64::NAN));
+        assert!(!ContinuousRange::From(0.0).contains(f64::NAN));
+        assert!(!ContinuousRange::Inclusive(0.0, 1.0).contains(f64::NAN));
+    }
+
+    #[test]
+    pub fn compare() {
+        let nan = ContinuousRange::Inclusive(f64::NAN, 1.0);
+        assert_eq!(nan.compare(&ContinuousRange::Inclusive(0.0, 2.0)), None);
+        assert_eq!(ContinuousRange::Inclusive(0.0, 2.0).compare(&nan), None);
+    }
+}
+
+mod test_fallible {
+    use std::cmp::Ordering;
+
+    use assert_matches::assert_matches;
+
+    use crate::{ContinuousRange, Range, RangeError, RangesRelation};
+
+    /// Values ordered component by component, which is a valid partial order where some values can't be compared
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Pair(i32, i32);
+
+    impl PartialOrd for Pair {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            match (self.0.cmp(&other.0), self.1.cmp(&other.1)) {
+                (a, b) if a == b => Some(a),
+                (a, Ordering::Equal) | (Ordering::Equal, a) => Some(a),
+                _ => None,
+            }
+        }
+    }
+
+    /// Values within 1 of each other compare equal, which breaks transitivity
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Sloppy(i32);
+
+    impl PartialOrd for Sloppy {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            if (self.0 - other.0).abs() <= 1 {
+                Some(Ordering::Equal)
+            } else {
+                Some(self.0.cmp(&other.0))
+            }
+        }
+    }
+
+    fn incomparable() -> (ContinuousRange<Pair>, ContinuousRange<Pair>) {
+        (
+            ContinuousRange::Inclusive(Pair(0, 0), Pair(0, 1)),
+            ContinuousRange::Inclusive(Pair(1, 0), Pair(2, 2)),
+        )
+    }
+
+    fn inconsistent() -> (ContinuousRange<Sloppy>, ContinuousRange<Sloppy>) {
+        (
+            ContinuousRange::Inclusive(Sloppy(0), Sloppy(1)),
+            ContinuousRange::Inclusive(Sloppy(2), Sloppy(5)),
+        )
+    }
+
+    #[test]
+    pub fn try_compare() {
+        let (a, b) = incomparable();
+        assert_eq!(a.try_compare(&b), Err(RangeError::IncomparableBounds));
+
+        let (a, b) = inconsistent();
+        assert_eq!(a.try_compare(&b), Err(RangeError::InconsistentOrdering));
+
+        let a = ContinuousRange::Inclusive(Pair(0, 0), Pair(1, 1));
+        let b = ContinuousRange::Inclusive(Pair(2, 2), Pair(3, 3));
+        assert_eq!(a.try_compare(&b), Ok(Some(RangesRelation::StrictlyBefore)));
+        assert_eq!(a.try_compare(&ContinuousRange::Empty), Ok(None));
+    }
+
+    #[test]
+    pub fn try_operations() {
+        let (a, b) = incomparable();
+        assert_eq!(a.try_union(&b), Err(RangeError::IncomparableBounds));
+        assert_eq!(a.try_intersection(&b), Err(RangeError::IncomparableBounds));
+        assert_eq!(a.try_difference(&b), Err(RangeError::IncomparableBounds));
+
+        let (a, b) = inconsistent();
+        assert_eq!(a.try_union(&b), Err(RangeError::InconsistentOrdering));
+        assert_eq!(
+            a.try_intersection(&b),
+            Err(RangeError::InconsistentOrdering)
+        );
+        assert_eq!(a.try_difference(&b), Err(RangeError::InconsistentOrdering));
+
+        let a = ContinuousRange::Inclusive(Pair(0, 0), Pair(2, 2));
+        let b = ContinuousRange::Inclusive(Pair(1, 1), Pair(3, 3));
+        assert_eq!(
+            a.try_union(&b),
+            Ok(Some(ContinuousRange::Inclusive(Pair(0, 0), Pair(3, 3))))
+        );
+        assert_eq!(
+            a.try_intersection(&b),
+            Ok(ContinuousRange::Inclusive(Pair(1, 1), Pair(2, 2)))
+        );
+        assert_eq!(
+            a.try_difference(&b),
+            Ok(Some(ContinuousRange::EndExclusive(Pair(0, 0), Pair(1, 1))))
+        );
+    }
+
+    #[test]
+    pub fn try_difference_empty() {
+        let range = ContinuousRange::Inclusive(1, 5);
+        assert_eq!(
+            range.try_difference(&ContinuousRange::Empty),
+            Ok(Some(ContinuousRange::Inclusive(1, 5)))
+        );
+        assert_eq!(
+            ContinuousRange::Empty.try_difference(&range),
+            Ok(Some(ContinuousRange::Empty))
+        );
+        assert_eq!(
+            range.difference(&ContinuousRange::Empty),
+            Some(ContinuousRange::Inclusive(1, 5))
+        );
+        assert_eq!(
+            ContinuousRange::<i32>::Empty.difference(&ContinuousRange::Empty),
+            Some(ContinuousRange::Empty)
+        );
+    }
+
+    #[test]
+    pub fn try_range_difference() {
+        let (a, b) = incomparable();
+        assert_eq!(
+            Range::Continuous(a).try_difference(&Range::Continuous(b)),
+            Err(RangeError::IncomparableBounds)
+        );
+    }
+
+    #[test]
+    pub fn lenient_incomparable() {
+        let (a, b) = incomparable();
+        assert_eq!(a.compare(&b), None);
+        assert_eq!(a.union(&b), None);
+        assert_matches!(a.intersection(&b), ContinuousRange::Empty);
+        assert_eq!(a.difference(&b), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "PartialOrd contract isn't correctly implemented")]
+    pub fn lenient_inconsistent() {
+        let (a, b) = inconsistent();
+        let _ = a.compare(&b);
+    }
+
+    #[test]
+    pub fn display() {
+        assert_eq!(
+            RangeError::IncomparableBounds.to_string(),
+            "range bounds can't be compared"
+        );
+    }
+}
+
+mod test_relate_point {
+    use crate::{ContinuousRange, PointRelation};
+
+    #[test]
+    pub fn inclusive() {
+        let range = ContinuousRange::Inclusive(1, 5);
+        assert_eq!(range.relate_point(&0), Some(PointRelation::Before));
+        assert_eq!(range.relate_point(&1), Some(PointRelation::Starts));
+        assert_eq!(range.relate_point(&3), Some(PointRelation::During));
+        assert_eq!(range.relate_point(&5), Some(PointRelation::Finishes));
+        assert_eq!(range.relate_point(&6), Some(PointRelation::After));
+    }
+
+    #[test]
+    pub fn exclusive() {
+        let range = ContinuousRange::Exclusive(1, 5);
+        assert_eq!(range.relate_point(&1), Some(PointRelation::Before));
+        assert_eq!(range.relate_point(&2), Some(PointRelation::During));
+        assert_eq!(range.relate_point(&5), Some(PointRelation::After));
+
+        let range = ContinuousRange::StartExclusive(1, 5);
+        assert_eq!(range.relate_point(&1), Some(PointRelation::Before));
+        assert_eq!(range.relate_point(&5), Some(PointRelation::Finishes));
+
+        let range = ContinuousRange::EndExclusive(1, 5);
+        assert_eq!(range.relate_point(&1), Some(PointRelation::Starts));
+        assert_eq!(range.relate_point(&5), Some(PointRelation::After));
+    }
+
+    #[test]
+    pub fn single() {
+        let range = ContinuousRange::Single(1);
+        assert_eq!(range.relate_point(&0), Some(PointRelation::Before));
+        assert_eq!(range.relate_point(&1), Some(PointRelation::Starts));
+        assert_eq!(range.relate_point(&2), Some(PointRelation::After));
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            ContinuousRange::From(1).relate_point(&100),
+            Some(PointRelation::During)
+        );
+        assert_eq!(
+            ContinuousRange::FromExclusive(1).relate_point(&1),
+            Some(PointRelation::Before)
+        );
+        assert_eq!(
+            ContinuousRange::To(1).relate_point(&1),
+            Some(PointRelation::Finishes)
+        );
+        assert_eq!(
+            ContinuousRange::ToExclusive(1).relate_point(&1),
+            Some(PointRelation::After)
+        );
+        assert_eq!(
+            ContinuousRange::Full.relate_point(&1),
+            Some(PointRelation::During)
+        );
+    }
+
+    #[test]
+    pub fn none() {
+        assert_eq!(ContinuousRange::<i32>::Empty.relate_point(&1), None);
+        assert_eq!(ContinuousRange::Inclusive(5, 1).relate_point(&3), None);
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 5.0).relate_point(&f64::NAN),
+            None
+        );
+    }
+
+    #[test]
+    pub fn contained() {
+        let range = ContinuousRange::StartExclusive(1, 5);
+        for point in 0..7 {
+            assert_eq!(
+                range.relate_point(&point).map(|r| r.contained()),
+                Some(range.contains(point))
+            );
+        }
+    }
+}
+
+mod test_clamp {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn inclusive() {
+        let range = ContinuousRange::Inclusive(1, 5);
+        assert_eq!(range.clamp(0), Some(1));
+        assert_eq!(range.clamp(3), Some(3));
+        assert_eq!(range.clamp(5), Some(5));
+        assert_eq!(range.clamp(10), Some(5));
+    }
+
+    #[test]
+    pub fn exclusive() {
+        let range = ContinuousRange::Exclusive(1.0, 5.0);
+        assert_eq!(range.clamp(0.0), None);
+        assert_eq!(range.clamp(1.0), None);
+        assert_eq!(range.clamp(3.0), Some(3.0));
+        assert_eq!(range.clamp(6.0), None);
+
+        let range = ContinuousRange::StartExclusive(1.0, 5.0);
+        assert_eq!(range.clamp(0.0), None);
+        assert_eq!(range.clamp(6.0), Some(5.0));
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(ContinuousRange::From(1).clamp(0), Some(1));
+        assert_eq!(ContinuousRange::From(1).clamp(10), Some(10));
+        assert_eq!(ContinuousRange::To(1).clamp(10), Some(1));
+        assert_eq!(ContinuousRange::ToExclusive(1.0).clamp(10.0), None);
+        assert_eq!(ContinuousRange::Full.clamp(10), Some(10));
+    }
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(ContinuousRange::<i32>::Empty.clamp(1), None);
+        assert_eq!(ContinuousRange::Inclusive(0.0, 1.0).clamp(f64::NAN), None);
+    }
+
+    #[test]
+    pub fn discrete() {
+        let range = ContinuousRange::Exclusive(1, 5);
+        assert_eq!(range.clamp_discrete(0), Some(2));
+        assert_eq!(range.clamp_discrete(3), Some(3));
+        assert_eq!(range.clamp_discrete(10), Some(4));
+        assert_eq!(ContinuousRange::ToExclusive(1).clamp_discrete(10), Some(0));
+        assert_eq!(ContinuousRange::FromExclusive(1).clamp_discrete(0), Some(2));
+
+        // No value between the bounds
+        assert_eq!(ContinuousRange::Exclusive(1, 2).clamp_discrete(0), None);
+        assert_eq!(
+            ContinuousRange::ToExclusive(u8::MIN).clamp_discrete(1),
+            None
+        );
+    }
+
+    #[test]
+    pub fn range() {
+        let range = ContinuousRange::Inclusive(0, 10);
+        assert_eq!(
+            range.clamp_range(&ContinuousRange::Inclusive(5, 15)),
+            ContinuousRange::Inclusive(5, 10)
+        );
+        assert_eq!(
+            range.clamp_range(&ContinuousRange::Exclusive(-5, 5)),
+            ContinuousRange::EndExclusive(0, 5)
+        );
+        assert_eq!(
+            range.clamp_range(&ContinuousRange::Full),
+            ContinuousRange::Inclusive(0, 10)
+        );
+        assert_eq!(
+            range.clamp_range(&ContinuousRange::Inclusive(2, 3)),
+            ContinuousRange::Inclusive(2, 3)
+        );
+        assert_eq!(
+            range.clamp_range(&ContinuousRange::Inclusive(12, 15)),
+            ContinuousRange::Single(10)
+        );
+        assert_eq!(
+            range.clamp_range(&ContinuousRange::To(-1)),
+            ContinuousRange::Single(0)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(0, 10).clamp_range(&ContinuousRange::From(10)),
+            ContinuousRange::Empty
+        );
+        assert_eq!(
+            range.clamp_range(&ContinuousRange::Empty),
+            ContinuousRange::Empty
+        );
+    }
+}
+
+mod test_tolerance {
+    use crate::{ContinuousRange, RangesRelation};
+
+    #[test]
+    pub fn compare() {
+        let a = ContinuousRange::Inclusive(0.0, 1.0);
+        assert_eq!(
+            a.compare_with_tolerance(&ContinuousRange::Inclusive(1.05, 2.0), &0.1),
+            Some(RangesRelation::Meets)
+        );
+        assert_eq!(
+            a.compare_with_tolerance(&ContinuousRange::Inclusive(1.05, 2.0), &0.01),
+            Some(RangesRelation::StrictlyBefore)
+        );
+        assert_eq!(
+            a.compare_with_tolerance(&ContinuousRange::Inclusive(0.01, 0.99), &0.1),
+            Some(RangesRelation::Equal)
+        );
+        assert_eq!(
+            a.compare_with_tolerance(&ContinuousRange::Inclusive(0.01, 2.0), &0.1),
+            Some(RangesRelation::Starts)
+        );
+        assert_eq!(
+            a.compare_with_tolerance(&ContinuousRange::From(0.5), &0.1),
+            Some(RangesRelation::Overlaps)
+        );
+    }
+
+    #[test]
+    pub fn exclusive_bounds_within_tolerance() {
+        let a = ContinuousRange::EndExclusive(0, 10);
+        assert_eq!(
+            a.compare_with_tolerance(&ContinuousRange::Inclusive(10, 20), &0u32),
+            Some(RangesRelation::Meets)
+        );
+        assert_eq!(
+            a.compare(&ContinuousRange::Inclusive(10, 20)),
+            Some(RangesRelation::StrictlyBefore)
+        );
+    }
+
+    #[test]
+    pub fn shorter_than_tolerance() {
+        // The tolerance makes [0, 1] a point equal to both 0 and 1 while 0 and 2 are different, the exact
+        // relation is used
+        let a = ContinuousRange::Inclusive(0, 1);
+        let b = ContinuousRange::Inclusive(2, 5);
+        assert_eq!(
+            a.compare_with_tolerance(&b, &1u32),
+            Some(RangesRelation::StrictlyBefore)
+        );
+    }
+
+    #[test]
+    pub fn empty() {
+        let a = ContinuousRange::Inclusive(0.0, 1.0);
+        assert_eq!(
+            a.compare_with_tolerance(&ContinuousRange::Empty, &0.1),
+            None
+        );
+        assert_eq!(
+            ContinuousRange::<f64>::Empty.compare_with_tolerance(&ContinuousRange::Empty, &0.1),
+            Some(RangesRelation::Equal)
+        );
+    }
+
+    #[test]
+    pub fn union() {
+        let a = ContinuousRange::Inclusive(0.0, 1.0);
+        assert_eq!(
+            a.union_with_tolerance(&ContinuousRange::Exclusive(1.05, 2.0), &0.1),
+            Some(ContinuousRange::EndExclusive(0.0, 2.0))
+        );
+        assert_eq!(
+            a.union_with_tolerance(&ContinuousRange::Exclusive(1.05, 2.0), &0.01),
+            None
+        );
+        assert_eq!(
+            a.union_with_tolerance(&ContinuousRange::Inclusive(-1.0, 0.5), &0.01),
+            Some(ContinuousRange::Inclusive(-1.0, 1.0))
+        );
+        assert_eq!(
+            a.union_with_tolerance(&ContinuousRange::Inclusive(0.01, 0.99), &0.1),
+            Some(ContinuousRange::Inclusive(0.0, 1.0))
+        );
+        assert_eq!(
+            a.union_with_tolerance(&ContinuousRange::Empty, &0.1),
+            Some(a.clone())
+        );
+        assert_eq!(
+            ContinuousRange::To(0).union_with_tolerance(&ContinuousRange::FromExclusive(1), &1u32),
+            Some(ContinuousRange::Full)
+        );
+    }
+}
+
+mod test_map_bounds {
+    use crate::ContinuousRange;
+    use std::convert::TryFrom;
+
+    #[test]
+    pub fn increasing() {
+        assert_eq!(
+            ContinuousRange::StartExclusive(1u32, 5).map_bounds(u64::from),
+            ContinuousRange::StartExclusive(1u64, 5)
+        );
+        assert_eq!(
+            ContinuousRange::ToExclusive(2).map_bounds(|x| x * 10),
+            ContinuousRange::ToExclusive(20)
+        );
+        assert_eq!(
+            ContinuousRange::<i32>::Full.map_bounds(i64::from),
+            ContinuousRange::<i64>::Full
+        );
+    }
+
+    #[test]
+    pub fn decreasing() {
+        let negate = |x: i32| -x;
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 5).map_bounds_decreasing(negate),
+            ContinuousRange::Inclusive(-5, -1)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(1, 5).map_bounds_decreasing(negate),
+            ContinuousRange::StartExclusive(-5, -1)
+        );
+        assert_eq!(
+            ContinuousRange::Exclusive(1, 5).map_bounds_decreasing(negate),
+            ContinuousRange::Exclusive(-5, -1)
+        );
+        assert_eq!(
+            ContinuousRange::ToExclusive(1).map_bounds_decreasing(negate),
+            ContinuousRange::FromExclusive(-1)
+        );
+        assert_eq!(
+            ContinuousRange::Single(1).map_bounds_decreasing(negate),
+            ContinuousRange::Single(-1)
+        );
+    }
+
+    #[test]
+    pub fn fallible() {
+        assert_eq!(
+            ContinuousRange::EndExclusive(1i64, 5).try_map_bounds(u8::try_from),
+            Ok(ContinuousRange::EndExclusive(1u8, 5))
+        );
+        assert!(ContinuousRange::Inclusive(-1i64, 5)
+            .try_map_bounds(u8::try_from)
+            .is_err());
+        assert_eq!(
+            ContinuousRange::<i64>::Empty.try_map_bounds(u8::try_from),
+            Ok(ContinuousRange::Empty)
+        );
+    }
+
+    #[test]
+    pub fn normalized() {
+        let tens = |x: i32| x / 10;
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 5).map_bounds(tens),
+            ContinuousRange::Single(0)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(1, 5).map_bounds(tens),
+            ContinuousRange::Empty
+        );
+        assert_eq!(
+            ContinuousRange::StartExclusive(1, 5).map_bounds_decreasing(|x| -x / 10),
+            ContinuousRange::Empty
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1i64, 5).try_map_bounds(|x| u8::try_from(x / 10)),
+            Ok(ContinuousRange::Single(0))
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use std::{borrow::Borrow, cmp::Ordering, ops::Bound};
+
+use crate::{
+    continuous::{reverse_bound, try_cmp_bounds, BoundSide},
+    ContinuousRange, Measure, Range, RangeError,
+};
+
+const INCONSISTENT: &str = "PartialOrd contract isn't correctly implemented";
+
+/// One of the bounds of a range being swept
+struct Event<'a, Idx> {
+    bound: Bound<&'a Idx>,
+    side: BoundSide,
+}
+
+fn cmp_events<Idx: PartialOrd>(
+    a: &Event<'_, Idx>,
+    b: &Event<'_, Idx>,
+) -> Result<Ordering, RangeError> {
+    let ordering = try_cmp_bounds(&a.bound, a.side, &b.bound, b.side)?;
+
+    Ok(match (ordering, a.side, b.side) {
+        // A start and an end comparing equal share a value that both ranges contain, the start must be
+        // processed first for the value to be counted twice.
+        (Ordering::Equal, BoundSide::Start, BoundSide::End) => Ordering::Less,
+        (Ordering::Equal, BoundSide::End, BoundSide::Start) => Ordering::Greater,
+        (ordering, _, _) => ordering,
+    })
+}
+
+/// Stable merge sort with a fallible comparison.
+///
+/// Unlike [`slice::sort_by`] it never panics when the comparison isn't a total order, the sweep detects the
+/// inconsistencies instead.
+fn try_sort_by<T>(
+    items: Vec<T>,
+    cmp: &mut impl FnMut(&T, &T) -> Result<Ordering, RangeError>,
+) -> Result<Vec<T>, RangeError> {
+    if items.len() <= 1 {
+        return Ok(items);
+    }
+
+    let mut left = items;
+    let right = left.split_off(left.len() / 2);
+    let left = try_sort_by(left, cmp)?;
+    let right = try_sort_by(right, cmp)?;
+
+    let mut sorted = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        if cmp(r, l)? == Ordering::Less {
+            sorted.extend(right.next());
+        } else {
+            sorted.extend(left.next());
+        }
+    }
+    sorted.extend(left);
+    sorted.extend(right);
+
+    Ok(sorted)
+}
+
+fn push_region<Idx: PartialOrd + Clone>(
+    regions: &mut Vec<(ContinuousRange<Idx>, usize)>,
+    region: ContinuousRange<Idx>,
+    depth: usize,
+) {
+    if region.is_empty() {
+        return;
+    }
+
+    if let Some((last, last_depth)) = regions.last_mut() {
+        if *last_depth == depth {
+            // Consecutive regions are always adjacent so they can be merged
+            let start = last.start().expect("Regions are never empty");
+            let end = region.end().expect("Regions are never empty");
+            *last = ContinuousRange::from_bounds((start, end));
+            return;
+        }
+    }
+
+    regions.push((region, depth));
+}
+
+/// Split the whole domain into consecutive regions, each with the number of `ranges` covering it.
+///
+/// The regions are ordered, disjoint, never empty and two consecutive regions never have the same depth.
+pub(crate) fn regions<'a, Idx>(
+    ranges: impl IntoIterator<Item = &'a ContinuousRange<Idx>>,
+) -> Result<Vec<(ContinuousRange<Idx>, usize)>, RangeError>
+where
+    Idx: PartialOrd + Clone + 'a,
+{
+    let mut events = Vec::new();
+    for range in ranges {
+        if range.is_empty() {
+            continue;
+        }
+
+        if let Some((start, end)) = range.range_bounds() {
+            events.push(Event {
+                bound: start,
+                side: BoundSide::Start,
+            });
+            events.push(Event {
+                bound: end,
+                side: BoundSide::End,
+            });
+        }
+    }
+    let events = try_sort_by(events, &mut cmp_events)?;
+
+    let mut regions = Vec::new();
+    let mut depth = 0;
+    let mut region_start = Bound::Unbounded;
+    // Once an unbounded end is reached there is nothing left to cover
+    let mut reached_end = false;
+
+    for event in &events {
+        let unbounded = matches!(event.bound, Bound::Unbounded);
+        match event.side {
+            BoundSide::Start => {
+                if !unbounded {
+                    let region =
+                        ContinuousRange::from_bounds((region_start, reverse_bound(event.bound)));
+                    push_region(&mut regions, region, depth);
+                }
+                depth += 1;
+                region_start = event.bound;
+            }
+            BoundSide::End => {
+                if !reached_end {
+                    let region = ContinuousRange::from_bounds((region_start, event.bound));
+                    push_region(&mut regions, region, depth);
+                }
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(RangeError::InconsistentOrdering)?;
+                region_start = reverse_bound(event.bound);
+                reached_end = unbounded;
+            }
+        }
+    }
+
+    if !reached_end {
+        let region = ContinuousRange::from_bounds((region_start, Bound::Unbounded));
+        push_region(&mut regions, region, depth);
+    }
+
+    Ok(regions)
+}
+
+/// The ordered and disjoint parts of the domain covered by at least one of the `ranges`.
+pub(crate) fn covered<'a, Idx>(
+    ranges: impl IntoIterator<Item = &'a ContinuousRange<Idx>>,
+) -> Result<Vec<ContinuousRange<Idx>>, RangeError>
+where
+    Idx: PartialOrd + Clone + 'a,
+{
+    let mut result: Vec<ContinuousRange<Idx>> = Vec::new();
+    let mut previous_covered = false;
+    for (region, depth) in regions(ranges)? {
+        if depth == 0 {
+            previous_covered = false;
+            continue;
+        }
+
+        match result.last_mut() {
+            // Consecutive regions are adjacent so they can be merged
+            Some(last) if previous_covered => {
+                let start = last.start().expect("Regions are never empty");
+                let end = region.end().expect("Regions are never empty");
+                *last = ContinuousRange::from_bounds((start, end));
+            }
+            _ => result.push(region),
+        }
+        previous_covered = true;
+    }
+
+    Ok(result)
+}
+
+/// The ordered and disjoint parts of the domain not covered by any of the `ranges`.
+pub(crate) fn uncovered<'a, Idx>(
+    ranges: impl IntoIterator<Item = &'a ContinuousRange<Idx>>,
+) -> Result<Vec<ContinuousRange<Idx>>, RangeError>
+where
+    Idx: PartialOrd + Clone + 'a,
+{
+    Ok(regions(ranges)?
+        .into_iter()
+        .filter(|(_, depth)| *depth == 0)
+        .map(|(region, _)| region)
+        .collect())
+}
+
+/// The ordered parts of the domain covered by both `a` and `b`, each of them must already be disjoint.
+pub(crate) fn intersect_disjoint<Idx>(
+    a: &[ContinuousRange<Idx>],
+    b: &[ContinuousRange<Idx>],
+) -> Result<Vec<ContinuousRange<Idx>>, RangeError>
+where
+    Idx: PartialOrd + Clone,
+{
+    Ok(regions(a.iter().chain(b))?
+        .into_iter()
+        .filter(|(_, depth)| *depth == 2)
+        .map(|(region, _)| region)
+        .collect())
+}
+
+/// Compute how many of the `ranges` overlap on each part of their union.
+///
+/// The result is ordered and only contains the parts covered by at least one range, with the number of ranges
+/// covering them. Consecutive parts always have a different count.
+///
+/// ```text
+/// [ A ]
+///   [   B   ]
+///     [ C ]
+/// 1 2 3   2 1
+/// ```
+///
+/// # Panics
+///
+/// This function may panic if the [`PartialOrd`] contract isn't respected.
+#[must_use]
+pub fn depth_profile<Idx>(
+    ranges: impl IntoIterator<Item = impl Borrow<ContinuousRange<Idx>>>,
+) -> Vec<(ContinuousRange<Idx>, usize)>
+where
+    Idx: PartialOrd + Clone,
+{
+    let ranges: Vec<_> = ranges.into_iter().collect();
+    let mut profile = regions(ranges.iter().map(Borrow::borrow)).expect(INCONSISTENT);
+    profile.retain(|(_, depth)| *depth > 0);
+    profile
+}
+
+/// Find the maximum number of `ranges` overlapping at a single point and where this peak occurs.
+///
+/// Returns [`None`] if all the ranges are empty.
+///
+/// # Panics
+///
+/// This function may panic if the [`PartialOrd`] contract isn't respected.
+#[must_use]
+pub fn max_overlap<Idx>(
+    ranges: impl IntoIterator<Item = impl Borrow<ContinuousRange<Idx>>>,
+) -> Option<(usize, Range<Idx>)>
+where
+    Idx: PartialOrd + Clone,
+{
+    let profile = depth_profile(ranges);
+    let max_depth = profile.iter().map(|(_, depth)| *depth).max()?;
+    let peaks = profile
+        .into_iter()
+        .filter(|(_, depth)| *depth == max_depth)
+        .map(|(range, _)| Range::Continuous(range));
+
+    Some((max_depth, Range::composite(peaks)))
+}
+
+/// Find the free slots inside `window` that aren't covered by any of the `busy` ranges and are at least
+/// `min_len` long.
+///
+/// Slots that are unbounded are always considered long enough.
+///
+/// ```text
+/// window: [                 ]
+/// busy:     [ A ]   [ B ]
+/// result: [ ]     [ ]     [ ]
+/// ```
+///
+/// # Panics
+///
+/// This function may panic if the [`PartialOrd`] contract isn't respected.
+#[must_use]
+pub fn free_slots<Idx>(
+    busy: impl IntoIterator<Item = impl Borrow<ContinuousRange<Idx>>>,
+    window: &ContinuousRange<Idx>,
+    min_len: &Idx::Length,
+) -> Vec<ContinuousRange<Idx>>
+where
+    Idx: Measure,
+{
+    let busy: Vec<_> = busy.into_iter().collect();
+    let free = uncovered(busy.iter().map(Borrow::borrow)).expect(INCONSISTENT);
+    let mut slots = intersect_disjoint(&free, std::slice::from_ref(window)).expect(INCONSISTENT);
+    slots.retain(|slot| slot.length().map_or(true, |length| length >= *min_len));
+    slots
+}
@@ -0,0 +1,307 @@
+mod test_depth_profile {
+    use crate::{depth_profile, ContinuousRange};
+
+    #[test]
+    pub fn empty() {
+        let profile = depth_profile(Vec::<ContinuousRange<i32>>::new());
+        assert_eq!(profile, vec![]);
+
+        let profile = depth_profile(vec![
+            ContinuousRange::<i32>::Empty,
+            ContinuousRange::Inclusive(5, 1),
+        ]);
+        assert_eq!(profile, vec![]);
+    }
+
+    #[test]
+    pub fn single_range() {
+        let profile = depth_profile(vec![ContinuousRange::Inclusive(1, 5)]);
+        assert_eq!(profile, vec![(ContinuousRange::Inclusive(1, 5), 1)]);
+    }
+
+    #[test]
+    pub fn disjoint() {
+        let profile = depth_profile(vec![
+            ContinuousRange::Inclusive(10, 20),
+            ContinuousRange::Inclusive(1, 5),
+        ]);
+        assert_eq!(
+            profile,
+            vec![
+                (ContinuousRange::Inclusive(1, 5), 1),
+                (ContinuousRange::Inclusive(10, 20), 1)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn nested() {
+        let profile = depth_profile(vec![
+            ContinuousRange::Inclusive(1, 10),
+            ContinuousRange::Inclusive(3, 12),
+            ContinuousRange::Inclusive(5, 7),
+        ]);
+        assert_eq!(
+            profile,
+            vec![
+                (ContinuousRange::EndExclusive(1, 3), 1),
+                (ContinuousRange::EndExclusive(3, 5), 2),
+                (ContinuousRange::Inclusive(5, 7), 3),
+                (ContinuousRange::StartExclusive(7, 10), 2),
+                (ContinuousRange::StartExclusive(10, 12), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn inclusive_bounds_meet() {
+        let profile = depth_profile(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Inclusive(5, 10),
+        ]);
+        assert_eq!(
+            profile,
+            vec![
+                (ContinuousRange::EndExclusive(1, 5), 1),
+                (ContinuousRange::Single(5), 2),
+                (ContinuousRange::StartExclusive(5, 10), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn exclusive_bounds_touch() {
+        let profile = depth_profile(vec![
+            ContinuousRange::EndExclusive(1, 5),
+            ContinuousRange::EndExclusive(5, 10),
+        ]);
+        assert_eq!(profile, vec![(ContinuousRange::EndExclusive(1, 10), 1)]);
+
+        let profile = depth_profile(vec![
+            ContinuousRange::Exclusive(1, 5),
+            ContinuousRange::Exclusive(5, 10),
+        ]);
+        assert_eq!(
+            profile,
+            vec![
+                (ContinuousRange::Exclusive(1, 5), 1),
+                (ContinuousRange::Exclusive(5, 10), 1)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn identical() {
+        let profile = depth_profile(vec![
+            ContinuousRange::Exclusive(1, 5),
+            ContinuousRange::Exclusive(1, 5),
+        ]);
+        assert_eq!(profile, vec![(ContinuousRange::Exclusive(1, 5), 2)]);
+    }
+
+    #[test]
+    pub fn single_values() {
+        let profile = depth_profile(vec![
+            ContinuousRange::Single(3),
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Single(3),
+        ]);
+        assert_eq!(
+            profile,
+            vec![
+                (ContinuousRange::EndExclusive(1, 3), 1),
+                (ContinuousRange::Single(3), 3),
+                (ContinuousRange::StartExclusive(3, 5), 1),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let profile = depth_profile(vec![
+            ContinuousRange::To(5),
+            ContinuousRange::FromExclusive(3),
+            ContinuousRange::Full,
+        ]);
+        assert_eq!(
+            profile,
+            vec![
+                (ContinuousRange::To(3), 2),
+                (ContinuousRange::StartExclusive(3, 5), 3),
+                (ContinuousRange::FromExclusive(5), 2),
+            ]
+        );
+
+        let profile = depth_profile(vec![ContinuousRange::<i32>::Full, ContinuousRange::Full]);
+        assert_eq!(profile, vec![(ContinuousRange::Full, 2)]);
+    }
+
+    #[test]
+    pub fn references() {
+        let ranges = vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Inclusive(3, 8),
+        ];
+        let profile = depth_profile(&ranges);
+        assert_eq!(
+            profile,
+            vec![
+                (ContinuousRange::EndExclusive(1, 3), 1),
+                (ContinuousRange::Inclusive(3, 5), 2),
+                (ContinuousRange::StartExclusive(5, 8), 1),
+            ]
+        );
+    }
+}
+
+mod test_max_overlap {
+    use crate::{max_overlap, ContinuousRange, Range};
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(max_overlap(Vec::<ContinuousRange<i32>>::new()), None);
+        assert_eq!(max_overlap(vec![ContinuousRange::<i32>::Empty]), None);
+    }
+
+    #[test]
+    pub fn no_overlap() {
+        let result = max_overlap(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Inclusive(10, 20),
+        ]);
+        assert_eq!(
+            result,
+            Some((
+                1,
+                Range::Composite(vec![
+                    ContinuousRange::Inclusive(1, 5),
+                    ContinuousRange::Inclusive(10, 20)
+                ])
+            ))
+        );
+    }
+
+    #[test]
+    pub fn single_peak() {
+        let result = max_overlap(vec![
+            ContinuousRange::EndExclusive(9, 12),
+            ContinuousRange::EndExclusive(10, 11),
+            ContinuousRange::EndExclusive(8, 17),
+        ]);
+        assert_eq!(
+            result,
+            Some((3, Range::Continuous(ContinuousRange::EndExclusive(10, 11))))
+        );
+    }
+
+    #[test]
+    pub fn multiple_peaks() {
+        let result = max_overlap(vec![
+            ContinuousRange::EndExclusive(0, 10),
+            ContinuousRange::EndExclusive(1, 2),
+            ContinuousRange::EndExclusive(5, 7),
+        ]);
+        assert_eq!(
+            result,
+            Some((
+                2,
+                Range::Composite(vec![
+                    ContinuousRange::EndExclusive(1, 2),
+                    ContinuousRange::EndExclusive(5, 7)
+                ])
+            ))
+        );
+    }
+
+    #[test]
+    pub fn peak_on_shared_bound() {
+        let result = max_overlap(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Inclusive(5, 10),
+        ]);
+        assert_eq!(
+            result,
+            Some((2, Range::Continuous(ContinuousRange::Single(5))))
+        );
+
+        let result = max_overlap(vec![
+            ContinuousRange::EndExclusive(1, 5),
+            ContinuousRange::Inclusive(5, 10),
+        ]);
+        assert_eq!(
+            result,
+            Some((1, Range::Continuous(ContinuousRange::Inclusive(1, 10))))
+        );
+    }
+}
+
+mod test_free_slots {
+    use crate::{free_slots, ContinuousRange};
+
+    #[test]
+    pub fn nothing_busy() {
+        let slots = free_slots(
+            Vec::<ContinuousRange<i32>>::new(),
+            &ContinuousRange::EndExclusive(8, 18),
+            &0,
+        );
+        assert_eq!(slots, vec![ContinuousRange::EndExclusive(8, 18)]);
+    }
+
+    #[test]
+    pub fn between_busy_ranges() {
+        let busy = vec![
+            ContinuousRange::EndExclusive(9, 10),
+            ContinuousRange::EndExclusive(14, 16),
+            ContinuousRange::EndExclusive(11, 12),
+            ContinuousRange::EndExclusive(15, 17),
+        ];
+        let slots = free_slots(&busy, &ContinuousRange::EndExclusive(8, 18), &0u32);
+        assert_eq!(
+            slots,
+            vec![
+                ContinuousRange::EndExclusive(8, 9),
+                ContinuousRange::EndExclusive(10, 11),
+                ContinuousRange::EndExclusive(12, 14),
+                ContinuousRange::EndExclusive(17, 18),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn min_len() {
+        let busy = vec![
+            ContinuousRange::EndExclusive(9, 10),
+            ContinuousRange::EndExclusive(11, 12),
+            ContinuousRange::EndExclusive(14, 16),
+        ];
+        let slots = free_slots(&busy, &ContinuousRange::EndExclusive(8, 18), &2u32);
+        assert_eq!(
+            slots,
+            vec![
+                ContinuousRange::EndExclusive(12, 14),
+                ContinuousRange::EndExclusive(16, 18),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn unbounded_slots_are_long_enough() {
+        let busy = vec![ContinuousRange::Inclusive(1.0, 2.0)];
+        let slots = free_slots(&busy, &ContinuousRange::Full, &100.0);
+        assert_eq!(
+            slots,
+            vec![
+                ContinuousRange::ToExclusive(1.0),
+                ContinuousRange::FromExclusive(2.0),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn fully_busy() {
+        let busy = vec![ContinuousRange::Full];
+        let slots = free_slots(&busy, &ContinuousRange::Inclusive(1, 5), &0u32);
+        assert_eq!(slots, vec![]);
+    }
+}
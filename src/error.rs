@@ -0,0 +1,37 @@
+use std::{error::Error, fmt};
+
+/// An error raised when an operation on ranges can't be completed
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum RangeError {
+    /// Two bounds can't be compared, [`PartialOrd::partial_cmp`] returned [`None`] for them
+    IncomparableBounds,
+
+    /// The orderings returned by [`PartialOrd`] contradict each other so no relation can be found
+    InconsistentOrdering,
+
+    /// The start of a range is after its end
+    InvertedBounds,
+
+    /// The start and end of a range are equal but at least one of them is excluded, so it can't contain any value
+    DegenerateBounds,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::IncomparableBounds => write!(fmt, "range bounds can't be compared"),
+            RangeError::InconsistentOrdering => write!(
+                fmt,
+                "range bounds ordering is inconsistent, the PartialOrd contract isn't correctly implemented"
+            ),
+            RangeError::InvertedBounds => write!(fmt, "range start is after its end"),
+            RangeError::DegenerateBounds => write!(
+                fmt,
+                "range start and end are equal but excluded, the range can't contain any value"
+            ),
+        }
+    }
+}
+
+impl Error for RangeError {}
@@ -0,0 +1,95 @@
+use ordered_float::{FloatCore, NotNan, OrderedFloat};
+
+use crate::{ContinuousRange, Magnitude, Measure};
+
+impl<F: FloatCore> ContinuousRange<F> {
+    /// Convert the bounds to [`OrderedFloat`], making the range totally ordered
+    ///
+    /// `NaN` bounds are kept and, as defined by [`OrderedFloat`], compare greater than any other value.
+    #[must_use]
+    pub fn to_ordered(self) -> ContinuousRange<OrderedFloat<F>> {
+        self.map_bounds(OrderedFloat)
+    }
+
+    /// Convert the bounds to [`NotNan`] or [`None`] if any of them is `NaN`
+    #[must_use]
+    pub fn to_not_nan(self) -> Option<ContinuousRange<NotNan<F>>> {
+        self.try_map_bounds(NotNan::new).ok()
+    }
+}
+
+impl<F: FloatCore> From<ContinuousRange<OrderedFloat<F>>> for ContinuousRange<F> {
+    fn from(range: ContinuousRange<OrderedFloat<F>>) -> Self {
+        range.map_bounds(OrderedFloat::into_inner)
+    }
+}
+
+impl<F: FloatCore> From<ContinuousRange<NotNan<F>>> for ContinuousRange<F> {
+    fn from(range: ContinuousRange<NotNan<F>>) -> Self {
+        range.map_bounds(NotNan::into_inner)
+    }
+}
+
+impl<F: FloatCore> Magnitude for OrderedFloat<F> {
+    fn zero() -> Self {
+        OrderedFloat(F::zero())
+    }
+
+    fn checked_sum(&self, other: &Self) -> Option<Self> {
+        Some(*self + *other)
+    }
+
+    fn ratio(&self, total: &Self) -> f64 {
+        (self.0 / total.0).to_f64().unwrap_or(f64::NAN)
+    }
+
+    fn fraction(&self, numerator: u64, denominator: u64) -> Self {
+        match (F::from(numerator), F::from(denominator)) {
+            (Some(numerator), Some(denominator)) => {
+                OrderedFloat(self.0 * (numerator / denominator))
+            }
+            _ => OrderedFloat(F::nan()),
+        }
+    }
+
+    fn times(&self, count: u64) -> Option<Self> {
+        Some(OrderedFloat(self.0 * F::from(count)?))
+    }
+
+    fn quotient(&self, divisor: &Self) -> Option<u64> {
+        (self.0 / divisor.0).floor().to_u64()
+    }
+}
+
+impl<F: FloatCore> Measure for OrderedFloat<F> {
+    type Length = OrderedFloat<F>;
+
+    fn measure(start: &Self, end: &Self) -> Self::Length {
+        *end - *start
+    }
+
+    fn advance(&self, step: &Self::Length) -> Option<Self> {
+        Some(*self + *step)
+    }
+
+    fn retreat(&self, step: &Self::Length) -> Option<Self> {
+        Some(*self - *step)
+    }
+}
+
+impl<F: FloatCore + Magnitude> Measure for NotNan<F> {
+    // Subtracting infinite values can produce NaN, so the length is a raw float
+    type Length = F;
+
+    fn measure(start: &Self, end: &Self) -> Self::Length {
+        end.into_inner() - start.into_inner()
+    }
+
+    fn advance(&self, step: &Self::Length) -> Option<Self> {
+        NotNan::new(self.into_inner() + *step).ok()
+    }
+
+    fn retreat(&self, step: &Self::Length) -> Option<Self> {
+        NotNan::new(self.into_inner() - *step).ok()
+    }
+}
@@ -0,0 +1,66 @@
+mod test_conversions {
+    use ordered_float::{NotNan, OrderedFloat};
+
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn to_ordered() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 2.0).to_ordered(),
+            ContinuousRange::Inclusive(OrderedFloat(1.0), OrderedFloat(2.0))
+        );
+        assert_eq!(
+            ContinuousRange::From(f64::NAN).to_ordered(),
+            ContinuousRange::From(OrderedFloat(f64::NAN))
+        );
+    }
+
+    #[test]
+    pub fn to_not_nan() {
+        assert_eq!(
+            ContinuousRange::Exclusive(1.0, 2.0).to_not_nan(),
+            Some(ContinuousRange::Exclusive(
+                NotNan::new(1.0).unwrap(),
+                NotNan::new(2.0).unwrap()
+            ))
+        );
+        assert_eq!(ContinuousRange::Exclusive(1.0, f64::NAN).to_not_nan(), None);
+        assert_eq!(
+            ContinuousRange::<f64>::Full.to_not_nan(),
+            Some(ContinuousRange::Full)
+        );
+    }
+
+    #[test]
+    pub fn back_to_float() {
+        let ordered: ContinuousRange<f32> = ContinuousRange::To(OrderedFloat(1.0f32)).into();
+        assert_eq!(ordered, ContinuousRange::To(1.0f32));
+
+        let not_nan: ContinuousRange<f32> =
+            ContinuousRange::Single(NotNan::new(1.0f32).unwrap()).into();
+        assert_eq!(not_nan, ContinuousRange::Single(1.0f32));
+    }
+}
+
+mod test_total_order {
+    use ordered_float::OrderedFloat;
+
+    use crate::{ContinuousRange, RangesRelation};
+
+    #[test]
+    pub fn compare_nan() {
+        let nan = ContinuousRange::Single(f64::NAN).to_ordered();
+        let range = ContinuousRange::Inclusive(0.0, 1.0).to_ordered();
+        assert_eq!(range.compare(&nan), Some(RangesRelation::StrictlyBefore));
+        assert!(nan.contains(OrderedFloat(f64::NAN)));
+    }
+
+    #[test]
+    pub fn length() {
+        let range = ContinuousRange::Inclusive(1.0, 3.5).to_ordered();
+        assert_eq!(range.length(), Some(OrderedFloat(2.5)));
+
+        let range = ContinuousRange::Inclusive(1.0, 3.5).to_not_nan().unwrap();
+        assert_eq!(range.length(), Some(2.5));
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    error::Error,
+    fmt,
+    net::{AddrParseError, Ipv4Addr, Ipv6Addr},
+    ops::Bound,
+    str::FromStr,
+};
+
+use crate::{ContinuousRange, Discrete, Range};
+
+/// An error raised when an address range can't be parsed
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum IpRangeError {
+    /// An address is malformed
+    Address(AddrParseError),
+
+    /// The prefix length of a block is malformed or longer than the address, with its content
+    Prefix(String),
+
+    /// The address of a block has bits set after its prefix
+    HostBits,
+
+    /// The start of the range is after its end
+    Reversed,
+}
+
+impl fmt::Display for IpRangeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpRangeError::Address(error) => write!(fmt, "invalid address: {error}"),
+            IpRangeError::Prefix(content) => write!(fmt, "invalid prefix length: {content}"),
+            IpRangeError::HostBits => write!(fmt, "block address has bits set after its prefix"),
+            IpRangeError::Reversed => write!(fmt, "range start is after its end"),
+        }
+    }
+}
+
+impl Error for IpRangeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IpRangeError::Address(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<AddrParseError> for IpRangeError {
+    fn from(error: AddrParseError) -> Self {
+        IpRangeError::Address(error)
+    }
+}
+
+/// An IP address type that can be split in CIDR blocks
+pub trait IpAddress: Discrete + Ord + Copy + fmt::Display + FromStr<Err = AddrParseError> {
+    /// The number of bits of an address
+    const BITS: u8;
+
+    /// The lowest address, with all its bits unset
+    const MIN: Self;
+
+    /// The highest address, with all its bits set
+    const MAX: Self;
+
+    /// The address as an integer
+    fn to_bits(self) -> u128;
+
+    /// The address of an integer, only the lowest [`IpAddress::BITS`] bits are used
+    fn from_bits(bits: u128) -> Self;
+}
+
+impl IpAddress for Ipv4Addr {
+    const BITS: u8 = 32;
+    const MIN: Self = Ipv4Addr::UNSPECIFIED;
+    const MAX: Self = Ipv4Addr::BROADCAST;
+
+    fn to_bits(self) -> u128 {
+        u128::from(u32::from(self))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_bits(bits: u128) -> Self {
+        Ipv4Addr::from(bits as u32)
+    }
+}
+
+impl IpAddress for Ipv6Addr {
+    const BITS: u8 = 128;
+    const MIN: Self = Ipv6Addr::UNSPECIFIED;
+    const MAX: Self = Ipv6Addr::new(
+        0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
+    );
+
+    fn to_bits(self) -> u128 {
+        u128::from(self)
+    }
+
+    fn from_bits(bits: u128) -> Self {
+        Ipv6Addr::from(bits)
+    }
+}
+
+/// The integer with the lowest `bits` bits set
+fn low_mask(bits: u8) -> u128 {
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// The first and last addresses of a range as integers or [`None`] if it's empty
+fn inclusive_bits<A: IpAddress>(range: &ContinuousRange<A>) -> Option<(u128, u128)> {
+    let (start, end) = range.range_bounds()?;
+    let first = match start {
+        Bound::Included(start) => Some(*start),
+        Bound::Excluded(start) => start.successor(),
+        Bound::Unbounded => Some(A::MIN),
+    }?;
+    let last = match end {
+        Bound::Included(end) => Some(*end),
+        Bound::Excluded(end) => end.predecessor(),
+        Bound::Unbounded => Some(A::MAX),
+    }?;
+
+    if first <= last {
+        Some((first.to_bits(), last.to_bits()))
+    } else {
+        None
+    }
+}
+
+/// Sort and merge the overlapping or adjacent integer ranges
+fn merge_bits(mut ranges: Vec<(u128, u128)>) -> Vec<(u128, u128)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(u128, u128)> = Vec::with_capacity(ranges.len());
+    for (first, last) in ranges {
+        match merged.last_mut() {
+            Some(previous) if previous.1 == u128::MAX || first <= previous.1 + 1 => {
+                previous.1 = previous.1.max(last);
+            }
+            _ => merged.push((first, last)),
+        }
+    }
+    merged
+}
+
+/// The minimal list of blocks covering the integers from `first` to `last` included
+#[allow(clippy::cast_possible_truncation)]
+fn blocks_of<A: IpAddress>(mut first: u128, last: u128, blocks: &mut Vec<Cidr<A>>) {
+    loop {
+        // The largest block aligned on `first` that doesn't go past `last`
+        let mut host_bits = if first == 0 {
+            A::BITS
+        } else {
+            A::BITS.min(first.trailing_zeros() as u8)
+        };
+        while last - first < low_mask(host_bits) {
+            host_bits -= 1;
+        }
+
+        blocks.push(Cidr {
+            network: A::from_bits(first),
+            prefix_len: A::BITS - host_bits,
+        });
+
+        let block_last = first + low_mask(host_bits);
+        if block_last >= last {
+            return;
+        }
+        first = block_last + 1;
+    }
+}
+
+/// A block of IP addresses sharing the same prefix, like `10.0.0.0/8`
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use range_ranger::{Cidr, ContinuousRange};
+///
+/// let block: Cidr<Ipv4Addr> = "192.168.0.0/16".parse().unwrap();
+/// assert_eq!(block.last(), Ipv4Addr::new(192, 168, 255, 255));
+/// assert_eq!(
+///     block.to_range(),
+///     ContinuousRange::Inclusive(Ipv4Addr::new(192, 168, 0, 0), Ipv4Addr::new(192, 168, 255, 255))
+/// );
+/// ```
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Cidr<A> {
+    network: A,
+    prefix_len: u8,
+}
+
+impl<A: IpAddress> Cidr<A> {
+    /// The block of the addresses starting with the `prefix_len` first bits of `network`
+    ///
+    /// [`None`] is returned if `prefix_len` is longer than the address or if `network` has bits set after the
+    /// prefix.
+    #[must_use]
+    pub fn new(network: A, prefix_len: u8) -> Option<Self> {
+        if prefix_len > A::BITS || network.to_bits() & low_mask(A::BITS - prefix_len) != 0 {
+            return None;
+        }
+        Some(Cidr {
+            network,
+            prefix_len,
+        })
+    }
+
+    /// The first address of the block
+    #[must_use]
+    pub fn network(&self) -> A {
+        self.network
+    }
+
+    /// The number of leading bits shared by the addresses of the block
+    #[must_use]
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// The last address of the block
+    #[must_use]
+    pub fn last(&self) -> A {
+        A::from_bits(self.network.to_bits() | low_mask(A::BITS - self.prefix_len))
+    }
+
+    /// Returns true if `address` is in the block
+    #[must_use]
+    pub fn contains(&self, address: &A) -> bool {
+        self.network <= *address && *address <= self.last()
+    }
+
+    /// The addresses of the block as an inclusive range
+    #[must_use]
+    pub fn to_range(&self) -> ContinuousRange<A> {
+        ContinuousRange::Inclusive(self.network, self.last())
+    }
+}
+
+impl<A: IpAddress> From<Cidr<A>> for ContinuousRange<A> {
+    fn from(block: Cidr<A>) -> Self {
+        block.to_range()
+    }
+}
+
+impl<A: fmt::Display> fmt::Display for Cidr<A> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}/{}", self.network, self.prefix_len)
+    }
+}
+
+impl<A: IpAddress> FromStr for Cidr<A> {
+    type Err = IpRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (network, prefix_len) = match s.split_once('/') {
+            Some(parts) => parts,
+            None => return Err(IpRangeError::Prefix(String::new())),
+        };
+        let network = network.trim().parse::<A>()?;
+        let prefix_len = match prefix_len.trim().parse::<u8>() {
+            Ok(prefix_len) if prefix_len <= A::BITS => prefix_len,
+            _ => return Err(IpRangeError::Prefix(prefix_len.to_owned())),
+        };
+        Cidr::new(network, prefix_len).ok_or(IpRangeError::HostBits)
+    }
+}
+
+/// Parse a block like `10.0.0.0/8`, a range like `10.0.0.1-10.0.0.9` or a single address
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use range_ranger::ContinuousRange;
+///
+/// let range: ContinuousRange<Ipv4Addr> = "10.0.0.1-10.0.0.9".parse().unwrap();
+/// assert_eq!(range, ContinuousRange::Inclusive(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 9)));
+/// ```
+impl<A: IpAddress> FromStr for ContinuousRange<A> {
+    type Err = IpRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('/') {
+            return s.parse::<Cidr<A>>().map(|block| block.to_range());
+        }
+
+        match s.split_once('-') {
+            Some((start, end)) => {
+                let start = start.trim().parse::<A>()?;
+                let end = end.trim().parse::<A>()?;
+                if start > end {
+                    return Err(IpRangeError::Reversed);
+                }
+                Ok(ContinuousRange::Inclusive(start, end))
+            }
+            None => Ok(ContinuousRange::Single(s.trim().parse::<A>()?)),
+        }
+    }
+}
+
+impl<A: IpAddress> ContinuousRange<A> {
+    /// The minimal list of CIDR blocks covering the addresses of the range, in increasing order
+    ///
+    /// Unbounded sides extend to [`IpAddress::MIN`] or [`IpAddress::MAX`]. [`ContinuousRange::count`] and
+    /// [`ContinuousRange::length`] don't, like for any other index they return [`None`] for an unbounded range,
+    /// intersect it with `ContinuousRange::Inclusive(A::MIN, A::MAX)` first to count its addresses.
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use range_ranger::{ContinuousRange, IpAddress};
+    ///
+    /// let range: ContinuousRange<Ipv4Addr> = "10.0.0.0-10.0.2.255".parse().unwrap();
+    /// let blocks: Vec<_> = range.to_cidrs().iter().map(ToString::to_string).collect();
+    /// assert_eq!(blocks, vec!["10.0.0.0/23", "10.0.2.0/24"]);
+    ///
+    /// let range = ContinuousRange::From(Ipv4Addr::new(255, 255, 255, 0));
+    /// assert_eq!(range.count(), None);
+    /// let bounded = range.intersection(&ContinuousRange::Inclusive(Ipv4Addr::MIN, Ipv4Addr::MAX));
+    /// assert_eq!(bounded.count(), Some(256));
+    /// ```
+    #[must_use]
+    pub fn to_cidrs(&self) -> Vec<Cidr<A>> {
+        let mut blocks = Vec::new();
+        if let Some((first, last)) = inclusive_bits(self) {
+            blocks_of(first, last, &mut blocks);
+        }
+        blocks
+    }
+}
+
+impl<A: IpAddress> Range<A> {
+    /// The minimal list of CIDR blocks covering the addresses of the range, in increasing order
+    ///
+    /// Overlapping and adjacent pieces are merged first.
+    #[must_use]
+    pub fn to_cidrs(&self) -> Vec<Cidr<A>> {
+        let ranges = self.pieces().iter().filter_map(inclusive_bits).collect();
+        let mut blocks = Vec::new();
+        for (first, last) in merge_bits(ranges) {
+            blocks_of(first, last, &mut blocks);
+        }
+        blocks
+    }
+
+    /// The range of the addresses contained in any of the blocks
+    ///
+    /// Overlapping and adjacent blocks are merged in a single inclusive range.
+    #[must_use]
+    pub fn from_cidrs(blocks: impl IntoIterator<Item = Cidr<A>>) -> Self {
+        let ranges = blocks
+            .into_iter()
+            .map(|block| (block.network.to_bits(), block.last().to_bits()))
+            .collect();
+        Range::composite(merge_bits(ranges).into_iter().map(|(first, last)| {
+            Range::Continuous(ContinuousRange::Inclusive(
+                A::from_bits(first),
+                A::from_bits(last),
+            ))
+        }))
+    }
+}
@@ -0,0 +1,248 @@
+mod test_discrete {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use crate::{ContinuousRange, Discrete, IpAddress};
+
+    #[test]
+    pub fn successor_and_predecessor() {
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 0, 255).successor(),
+            Some(Ipv4Addr::new(10, 0, 1, 0))
+        );
+        assert_eq!(Ipv4Addr::BROADCAST.successor(), None);
+        assert_eq!(Ipv4Addr::UNSPECIFIED.predecessor(), None);
+        assert_eq!(Ipv6Addr::UNSPECIFIED.successor(), Some(Ipv6Addr::LOCALHOST));
+        assert_eq!(
+            Ipv6Addr::from(u128::MAX).predecessor(),
+            Some(Ipv6Addr::from(u128::MAX - 1))
+        );
+    }
+
+    #[test]
+    pub fn count() {
+        assert_eq!(
+            ContinuousRange::EndExclusive(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 1, 0))
+                .count(),
+            Some(256)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(Ipv4Addr::UNSPECIFIED, Ipv4Addr::BROADCAST).count(),
+            Some(1 << 32)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(Ipv6Addr::UNSPECIFIED, Ipv6Addr::from(u128::MAX)).count(),
+            None
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 9))
+                .length(),
+            Some(9)
+        );
+    }
+
+    #[test]
+    pub fn address_limits() {
+        assert_eq!(<Ipv4Addr as IpAddress>::MAX.successor(), None);
+        assert_eq!(<Ipv4Addr as IpAddress>::MIN.predecessor(), None);
+        assert_eq!(<Ipv6Addr as IpAddress>::MAX, Ipv6Addr::from(u128::MAX));
+        assert_eq!(<Ipv6Addr as IpAddress>::MIN.predecessor(), None);
+
+        // Unbounded ranges only have a count once bounded by the address limits
+        let all = ContinuousRange::Inclusive(<Ipv4Addr as IpAddress>::MIN, IpAddress::MAX);
+        assert_eq!(ContinuousRange::<Ipv4Addr>::Full.count(), None);
+        assert_eq!(
+            ContinuousRange::<Ipv4Addr>::Full.intersection(&all).count(),
+            Some(1 << 32)
+        );
+        assert_eq!(
+            ContinuousRange::From(Ipv4Addr::new(255, 255, 255, 0))
+                .intersection(&all)
+                .length(),
+            Some(255)
+        );
+    }
+}
+
+mod test_parse {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use crate::{Cidr, ContinuousRange, IpRangeError};
+
+    #[test]
+    pub fn blocks() {
+        let block: Cidr<Ipv4Addr> = "10.0.0.0/8".parse().unwrap();
+        assert_eq!(block.network(), Ipv4Addr::new(10, 0, 0, 0));
+        assert_eq!(block.prefix_len(), 8);
+        assert_eq!(block.last(), Ipv4Addr::new(10, 255, 255, 255));
+        assert!(block.contains(&Ipv4Addr::new(10, 1, 2, 3)));
+        assert!(!block.contains(&Ipv4Addr::new(11, 0, 0, 0)));
+        assert_eq!(block.to_string(), "10.0.0.0/8");
+
+        let all: Cidr<Ipv4Addr> = "0.0.0.0/0".parse().unwrap();
+        assert_eq!(all.last(), Ipv4Addr::BROADCAST);
+        let host: Cidr<Ipv6Addr> = "::1/128".parse().unwrap();
+        assert_eq!(
+            host.to_range(),
+            ContinuousRange::Inclusive(Ipv6Addr::LOCALHOST, Ipv6Addr::LOCALHOST)
+        );
+    }
+
+    #[test]
+    pub fn ranges() {
+        let range: ContinuousRange<Ipv4Addr> = "192.168.1.0/24".parse().unwrap();
+        assert_eq!(
+            range,
+            ContinuousRange::Inclusive(
+                Ipv4Addr::new(192, 168, 1, 0),
+                Ipv4Addr::new(192, 168, 1, 255)
+            )
+        );
+
+        let range: ContinuousRange<Ipv4Addr> = "10.0.0.1 - 10.0.0.9".parse().unwrap();
+        assert_eq!(
+            range,
+            ContinuousRange::Inclusive(Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 9))
+        );
+
+        let range: ContinuousRange<Ipv6Addr> = "2001:db8::-2001:db8::ff".parse().unwrap();
+        assert_eq!(range.count(), Some(256));
+
+        let range: ContinuousRange<Ipv4Addr> = "127.0.0.1".parse().unwrap();
+        assert_eq!(range, ContinuousRange::Single(Ipv4Addr::LOCALHOST));
+    }
+
+    #[test]
+    pub fn errors() {
+        assert!(matches!(
+            "10.0.0/8".parse::<Cidr<Ipv4Addr>>(),
+            Err(IpRangeError::Address(_))
+        ));
+        assert_eq!(
+            "10.0.0.0/33".parse::<Cidr<Ipv4Addr>>(),
+            Err(IpRangeError::Prefix("33".to_owned()))
+        );
+        assert_eq!(
+            "10.0.0.0".parse::<Cidr<Ipv4Addr>>(),
+            Err(IpRangeError::Prefix(String::new()))
+        );
+        assert_eq!(
+            "10.0.0.1/8".parse::<Cidr<Ipv4Addr>>(),
+            Err(IpRangeError::HostBits)
+        );
+        assert_eq!(
+            "10.0.0.9-10.0.0.1".parse::<ContinuousRange<Ipv4Addr>>(),
+            Err(IpRangeError::Reversed)
+        );
+        assert!(matches!(
+            "10.0.0.1-::1".parse::<ContinuousRange<Ipv4Addr>>(),
+            Err(IpRangeError::Address(_))
+        ));
+    }
+}
+
+mod test_cidrs {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use crate::{Cidr, ContinuousRange, Range};
+
+    fn strings<A: std::fmt::Display>(blocks: &[Cidr<A>]) -> Vec<String> {
+        blocks.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    pub fn minimal_blocks() {
+        let range: ContinuousRange<Ipv4Addr> = "10.0.0.1-10.0.0.9".parse().unwrap();
+        assert_eq!(
+            strings(&range.to_cidrs()),
+            vec!["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/30", "10.0.0.8/31"]
+        );
+
+        let range: ContinuousRange<Ipv4Addr> = "10.0.0.0/8".parse().unwrap();
+        assert_eq!(strings(&range.to_cidrs()), vec!["10.0.0.0/8"]);
+    }
+
+    #[test]
+    pub fn exclusive_and_unbounded_bounds() {
+        let range =
+            ContinuousRange::Exclusive(Ipv4Addr::new(10, 0, 0, 255), Ipv4Addr::new(10, 0, 2, 0));
+        assert_eq!(strings(&range.to_cidrs()), vec!["10.0.1.0/24"]);
+
+        assert_eq!(
+            strings(&ContinuousRange::<Ipv4Addr>::Full.to_cidrs()),
+            vec!["0.0.0.0/0"]
+        );
+        assert_eq!(
+            strings(&ContinuousRange::<Ipv6Addr>::Full.to_cidrs()),
+            vec!["::/0"]
+        );
+        assert_eq!(
+            strings(&ContinuousRange::From(Ipv4Addr::new(128, 0, 0, 0)).to_cidrs()),
+            vec!["128.0.0.0/1"]
+        );
+        assert_eq!(
+            strings(&ContinuousRange::ToExclusive(Ipv4Addr::new(0, 0, 0, 3)).to_cidrs()),
+            vec!["0.0.0.0/31", "0.0.0.2/32"]
+        );
+        assert!(
+            ContinuousRange::Exclusive(Ipv4Addr::LOCALHOST, Ipv4Addr::new(127, 0, 0, 2))
+                .to_cidrs()
+                .is_empty()
+        );
+        assert!(ContinuousRange::FromExclusive(Ipv4Addr::BROADCAST)
+            .to_cidrs()
+            .is_empty());
+    }
+
+    #[test]
+    pub fn merges_pieces() {
+        let range = Range::composite(vec![
+            Range::continuous(Ipv4Addr::new(10, 0, 1, 0), Ipv4Addr::new(10, 0, 1, 255)),
+            Range::continuous(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 255)),
+            Range::single(Ipv4Addr::new(10, 0, 0, 7)),
+            Range::single(Ipv4Addr::new(192, 168, 0, 1)),
+        ]);
+        let blocks = range.to_cidrs();
+        assert_eq!(strings(&blocks), vec!["10.0.0.0/23", "192.168.0.1/32"]);
+
+        assert_eq!(
+            Range::from_cidrs(blocks),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(
+                    Ipv4Addr::new(10, 0, 0, 0),
+                    Ipv4Addr::new(10, 0, 1, 255)
+                ),
+                ContinuousRange::Inclusive(
+                    Ipv4Addr::new(192, 168, 0, 1),
+                    Ipv4Addr::new(192, 168, 0, 1)
+                ),
+            ])
+        );
+        assert_eq!(Range::<Ipv4Addr>::from_cidrs(Vec::new()), Range::empty());
+    }
+
+    #[test]
+    pub fn round_trip() {
+        let mut state: u32 = 0x9e37_79b9;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for _ in 0..200 {
+            let (a, b) = (next(), next());
+            let (first, last) = (a.min(b), a.max(b));
+            let range = ContinuousRange::Inclusive(Ipv4Addr::from(first), Ipv4Addr::from(last));
+            let blocks = range.to_cidrs();
+
+            assert!(blocks.len() <= 62);
+            let total: u64 = blocks
+                .iter()
+                .map(|block| 1u64 << (32 - block.prefix_len()))
+                .sum();
+            assert_eq!(total, u64::from(last - first) + 1);
+            assert_eq!(Range::from_cidrs(blocks), Range::Continuous(range));
+        }
+    }
+}
@@ -4,16 +4,112 @@
     clippy::module_name_repetitions,
 )]
 
+mod arithmetic;
+mod bitemporal;
+mod builder;
+#[cfg(feature = "chrono")]
+mod calendar;
 mod continuous;
+mod coverage;
+mod error;
+#[cfg(feature = "ordered-float")]
+mod float;
+mod ip;
+mod measure;
+mod metric;
+mod network;
+mod outward;
 mod range;
+mod range_box;
+mod recurring;
 mod relation;
+mod relation_set;
+#[cfg(feature = "rrule")]
+mod rrule;
+mod rtree;
+mod shift;
+mod split;
 
+pub use arithmetic::{Numeric, Real};
+pub use bitemporal::{Bitemporal, BitemporalError, Fact};
+pub use builder::RangeBuilder;
+#[cfg(feature = "chrono")]
+pub use calendar::WorkCalendar;
 pub use continuous::ContinuousRange;
+pub use coverage::{depth_profile, free_slots, max_overlap};
+pub use error::RangeError;
+pub use ip::{Cidr, IpAddress, IpRangeError};
+pub use measure::{Discrete, Magnitude, Measure};
+pub use network::{Inconsistency, IntervalNetwork};
+pub use outward::Outward;
 pub use range::Range;
-pub use relation::RangesRelation;
+pub use range_box::RangeBox;
+pub use recurring::RecurringRange;
+pub use relation::{PointRelation, RangesRelation};
+pub use relation_set::RelationSet;
+#[cfg(feature = "rrule")]
+pub use rrule::{Frequency, Occurrences, RecurrenceError, RecurrenceRule, RecurringEvent};
+pub use rtree::RTree;
+#[cfg(feature = "chrono")]
+pub use split::CalendarUnit;
+pub use split::SplitSide;
 
 #[cfg(test)]
 mod tests;
 
 #[cfg(test)]
 mod continuous_tests;
+
+#[cfg(test)]
+mod builder_tests;
+
+#[cfg(test)]
+mod coverage_tests;
+
+#[cfg(test)]
+mod measure_tests;
+
+#[cfg(test)]
+mod arithmetic_tests;
+
+#[cfg(test)]
+mod outward_tests;
+
+#[cfg(all(test, feature = "ordered-float"))]
+mod float_tests;
+
+#[cfg(test)]
+mod relation_tests;
+
+#[cfg(test)]
+mod network_tests;
+
+#[cfg(test)]
+mod shift_tests;
+
+#[cfg(test)]
+mod split_tests;
+
+#[cfg(test)]
+mod metric_tests;
+
+#[cfg(test)]
+mod recurring_tests;
+
+#[cfg(all(test, feature = "rrule"))]
+mod rrule_tests;
+
+#[cfg(all(test, feature = "chrono"))]
+mod calendar_tests;
+
+#[cfg(test)]
+mod bitemporal_tests;
+
+#[cfg(test)]
+mod range_box_tests;
+
+#[cfg(test)]
+mod rtree_tests;
+
+#[cfg(test)]
+mod ip_tests;
@@ -0,0 +1,484 @@
+use std::{
+    convert::TryFrom,
+    net::{Ipv4Addr, Ipv6Addr},
+    ops::Add,
+    time::{Duration, Instant},
+};
+
+/// A quantity representing how far apart two values of an index type are
+///
+/// Magnitudes can also be used as deltas to move a [`ContinuousRange`][crate::ContinuousRange] or a
+/// [`Range`][crate::Range] with the `+` and `-` operators, see
+/// [`ContinuousRange::shift`][crate::ContinuousRange::shift].
+pub trait Magnitude: Clone + PartialOrd + Add<Output = Self> {
+    /// The magnitude separating a value from itself
+    fn zero() -> Self;
+
+    /// The sum of both magnitudes or [`None`] if it can't be represented
+    fn checked_sum(&self, other: &Self) -> Option<Self>;
+
+    /// The value of `self / total`, `total` is expected to be non-zero
+    fn ratio(&self, total: &Self) -> f64;
+
+    /// The value of `self * numerator / denominator`
+    ///
+    /// `numerator` is expected to be lower or equal to a non-zero `denominator`. Integer magnitudes are rounded
+    /// toward zero.
+    #[must_use]
+    fn fraction(&self, numerator: u64, denominator: u64) -> Self;
+
+    /// The value of `self * count` or [`None`] if it can't be represented
+    fn times(&self, count: u64) -> Option<Self>;
+
+    /// The number of whole `divisor` contained in `self` or [`None`] if it doesn't fit in a [`u64`]
+    ///
+    /// `self` is expected to be positive or zero and `divisor` positive.
+    fn quotient(&self, divisor: &Self) -> Option<u64>;
+}
+
+/// An index type where the distance between two values can be measured and values can be moved by a distance
+///
+/// Signed integers are measured with the unsigned type of the same size, so that the distance between any two
+/// values can be represented.
+pub trait Measure: PartialOrd + Clone {
+    /// The type of the distance between two values
+    type Length: Magnitude;
+
+    /// Measure the distance from `start` to `end`
+    ///
+    /// `start` is expected to be lower or equal to `end`.
+    fn measure(start: &Self, end: &Self) -> Self::Length;
+
+    /// The value located `step` after this one or [`None`] if it can't be represented
+    fn advance(&self, step: &Self::Length) -> Option<Self>;
+
+    /// The value located `step` before this one or [`None`] if it can't be represented
+    fn retreat(&self, step: &Self::Length) -> Option<Self>;
+
+    /// The value located at `numerator / denominator` of the way from `start` to `end`
+    ///
+    /// `start` is expected to be lower or equal to `end` and `numerator` lower or equal to a non-zero
+    /// `denominator`. Discrete types round the result down.
+    fn interpolate(start: &Self, end: &Self, numerator: u64, denominator: u64) -> Option<Self> {
+        start.advance(&Self::measure(start, end).fraction(numerator, denominator))
+    }
+}
+
+/// An index type where each value has a well defined successor and predecessor
+pub trait Discrete: PartialOrd + Clone {
+    /// The value directly after this one or [`None`] if it's the maximum value
+    fn successor(&self) -> Option<Self>;
+
+    /// The value directly before this one or [`None`] if it's the minimum value
+    fn predecessor(&self) -> Option<Self>;
+
+    /// The number of successors to go through to reach `end` from `start`
+    ///
+    /// `start` is expected to be lower or equal to `end`, [`None`] is returned if the number doesn't fit in a
+    /// [`u128`].
+    fn steps_between(start: &Self, end: &Self) -> Option<u128>;
+}
+
+/// `length * numerator / denominator` without overflowing when `numerator <= denominator`
+fn fraction(length: u128, numerator: u64, denominator: u64) -> u128 {
+    let (numerator, denominator) = (u128::from(numerator), u128::from(denominator));
+    length / denominator * numerator + length % denominator * numerator / denominator
+}
+
+/// The duration of `nanos` nanoseconds or [`None`] if it can't be represented
+#[allow(clippy::cast_possible_truncation)]
+fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+    Some(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+macro_rules! impl_integer {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl Magnitude for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_sum(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+                fn ratio(&self, total: &Self) -> f64 {
+                    *self as f64 / *total as f64
+                }
+
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    clippy::cast_sign_loss,
+                    clippy::cast_lossless
+                )]
+                fn fraction(&self, numerator: u64, denominator: u64) -> Self {
+                    // The magnitude of a negative value always fits in the unsigned type of the same size
+                    if *self < Self::zero() {
+                        let magnitude = self.wrapping_neg() as $unsigned as u128;
+                        (fraction(magnitude, numerator, denominator) as $t).wrapping_neg()
+                    } else {
+                        fraction(*self as u128, numerator, denominator) as $t
+                    }
+                }
+
+                fn times(&self, count: u64) -> Option<Self> {
+                    self.checked_mul(<$t>::try_from(count).ok()?)
+                }
+
+                fn quotient(&self, divisor: &Self) -> Option<u64> {
+                    u64::try_from(*self / *divisor).ok()
+                }
+            }
+
+            impl Measure for $t {
+                type Length = $unsigned;
+
+                #[allow(clippy::cast_sign_loss)]
+                fn measure(start: &Self, end: &Self) -> Self::Length {
+                    // The difference always fits in the unsigned type of the same size when start <= end
+                    end.wrapping_sub(*start) as $unsigned
+                }
+
+                #[allow(clippy::cast_possible_wrap, clippy::cast_lossless)]
+                fn advance(&self, step: &Self::Length) -> Option<Self> {
+                    // The step is lower than the size of the type, so the sum wrapped if it's lower than the value
+                    let value = self.wrapping_add(*step as $t);
+                    (value >= *self).then(|| value)
+                }
+
+                #[allow(clippy::cast_possible_wrap, clippy::cast_lossless)]
+                fn retreat(&self, step: &Self::Length) -> Option<Self> {
+                    let value = self.wrapping_sub(*step as $t);
+                    (value <= *self).then(|| value)
+                }
+            }
+
+            impl Discrete for $t {
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                #[allow(clippy::cast_lossless)]
+                fn steps_between(start: &Self, end: &Self) -> Option<u128> {
+                    Some(Self::measure(start, end) as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize
+);
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Magnitude for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn checked_sum(&self, other: &Self) -> Option<Self> {
+                    Some(self + other)
+                }
+
+                #[allow(clippy::cast_lossless)]
+                fn ratio(&self, total: &Self) -> f64 {
+                    *self as f64 / *total as f64
+                }
+
+                #[allow(clippy::cast_precision_loss)]
+                fn fraction(&self, numerator: u64, denominator: u64) -> Self {
+                    self * (numerator as $t / denominator as $t)
+                }
+
+                #[allow(clippy::cast_precision_loss)]
+                fn times(&self, count: u64) -> Option<Self> {
+                    Some(self * count as $t)
+                }
+
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss
+                )]
+                fn quotient(&self, divisor: &Self) -> Option<u64> {
+                    let count = (self / divisor).floor();
+                    if count >= 0.0 && count < u64::MAX as $t {
+                        Some(count as u64)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl Measure for $t {
+                type Length = $t;
+
+                fn measure(start: &Self, end: &Self) -> Self::Length {
+                    end - start
+                }
+
+                fn advance(&self, step: &Self::Length) -> Option<Self> {
+                    Some(self + step)
+                }
+
+                fn retreat(&self, step: &Self::Length) -> Option<Self> {
+                    Some(self - step)
+                }
+
+                #[allow(clippy::cast_precision_loss)]
+                fn interpolate(start: &Self, end: &Self, numerator: u64, denominator: u64) -> Option<Self> {
+                    // Weighting both values stays finite when the distance between them doesn't
+                    let ratio = numerator as $t / denominator as $t;
+                    Some(start * (1.0 - ratio) + end * ratio)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+macro_rules! impl_ip_address {
+    ($($t:ty => $bits:ty),*) => {
+        $(
+            impl Measure for $t {
+                type Length = $bits;
+
+                fn measure(start: &Self, end: &Self) -> Self::Length {
+                    <$bits>::from(*end) - <$bits>::from(*start)
+                }
+
+                fn advance(&self, step: &Self::Length) -> Option<Self> {
+                    <$bits>::from(*self).checked_add(*step).map(Self::from)
+                }
+
+                fn retreat(&self, step: &Self::Length) -> Option<Self> {
+                    <$bits>::from(*self).checked_sub(*step).map(Self::from)
+                }
+            }
+
+            impl Discrete for $t {
+                fn successor(&self) -> Option<Self> {
+                    <$bits>::from(*self).checked_add(1).map(Self::from)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    <$bits>::from(*self).checked_sub(1).map(Self::from)
+                }
+
+                #[allow(clippy::cast_lossless)]
+                fn steps_between(start: &Self, end: &Self) -> Option<u128> {
+                    Some((<$bits>::from(*end) - <$bits>::from(*start)) as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_ip_address!(Ipv4Addr => u32, Ipv6Addr => u128);
+
+impl Magnitude for Duration {
+    fn zero() -> Self {
+        Duration::from_secs(0)
+    }
+
+    fn checked_sum(&self, other: &Self) -> Option<Self> {
+        Duration::checked_add(*self, *other)
+    }
+
+    fn ratio(&self, total: &Self) -> f64 {
+        self.as_secs_f64() / total.as_secs_f64()
+    }
+
+    fn fraction(&self, numerator: u64, denominator: u64) -> Self {
+        // The result is lower or equal to the duration so it can always be represented
+        duration_from_nanos(fraction(self.as_nanos(), numerator, denominator)).unwrap_or(*self)
+    }
+
+    fn times(&self, count: u64) -> Option<Self> {
+        duration_from_nanos(self.as_nanos().checked_mul(u128::from(count))?)
+    }
+
+    fn quotient(&self, divisor: &Self) -> Option<u64> {
+        u64::try_from(self.as_nanos() / divisor.as_nanos()).ok()
+    }
+}
+
+impl Measure for Instant {
+    type Length = Duration;
+
+    fn measure(start: &Self, end: &Self) -> Self::Length {
+        end.saturating_duration_since(*start)
+    }
+
+    fn advance(&self, step: &Self::Length) -> Option<Self> {
+        self.checked_add(*step)
+    }
+
+    fn retreat(&self, step: &Self::Length) -> Option<Self> {
+        self.checked_sub(*step)
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+    use super::{Discrete, Magnitude, Measure};
+
+    impl Magnitude for Duration {
+        fn zero() -> Self {
+            Duration::zero()
+        }
+
+        fn checked_sum(&self, other: &Self) -> Option<Self> {
+            Duration::checked_add(self, other)
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        fn ratio(&self, total: &Self) -> f64 {
+            match (self.num_nanoseconds(), total.num_nanoseconds()) {
+                (Some(part), Some(total)) => part as f64 / total as f64,
+                _ => self.num_milliseconds() as f64 / total.num_milliseconds() as f64,
+            }
+        }
+
+        // Durations are handled in nanoseconds when they fit in an `i64` or milliseconds otherwise
+        fn fraction(&self, numerator: u64, denominator: u64) -> Self {
+            match self.num_nanoseconds() {
+                Some(nanos) => Duration::nanoseconds(nanos.fraction(numerator, denominator)),
+                None => {
+                    Duration::milliseconds(self.num_milliseconds().fraction(numerator, denominator))
+                }
+            }
+        }
+
+        fn times(&self, count: u64) -> Option<Self> {
+            match self.num_nanoseconds() {
+                Some(nanos) => nanos.times(count).map(Duration::nanoseconds),
+                None => self
+                    .num_milliseconds()
+                    .times(count)
+                    .map(Duration::milliseconds),
+            }
+        }
+
+        fn quotient(&self, divisor: &Self) -> Option<u64> {
+            match (self.num_nanoseconds(), divisor.num_nanoseconds()) {
+                (Some(nanos), Some(divisor)) => nanos.quotient(&divisor),
+                _ => self
+                    .num_milliseconds()
+                    .quotient(&divisor.num_milliseconds()),
+            }
+        }
+    }
+
+    impl<Tz: TimeZone> Measure for DateTime<Tz> {
+        type Length = Duration;
+
+        fn measure(start: &Self, end: &Self) -> Self::Length {
+            end.clone().signed_duration_since(start.clone())
+        }
+
+        fn advance(&self, step: &Self::Length) -> Option<Self> {
+            self.clone().checked_add_signed(*step)
+        }
+
+        fn retreat(&self, step: &Self::Length) -> Option<Self> {
+            self.clone().checked_sub_signed(*step)
+        }
+    }
+
+    impl Measure for NaiveDateTime {
+        type Length = Duration;
+
+        fn measure(start: &Self, end: &Self) -> Self::Length {
+            end.signed_duration_since(*start)
+        }
+
+        fn advance(&self, step: &Self::Length) -> Option<Self> {
+            self.checked_add_signed(*step)
+        }
+
+        fn retreat(&self, step: &Self::Length) -> Option<Self> {
+            self.checked_sub_signed(*step)
+        }
+    }
+
+    impl Measure for NaiveDate {
+        type Length = Duration;
+
+        fn measure(start: &Self, end: &Self) -> Self::Length {
+            end.signed_duration_since(*start)
+        }
+
+        // Moving a date ignores the part of the step smaller than a day
+        fn advance(&self, step: &Self::Length) -> Option<Self> {
+            self.checked_add_signed(*step)
+        }
+
+        fn retreat(&self, step: &Self::Length) -> Option<Self> {
+            self.checked_sub_signed(*step)
+        }
+    }
+
+    impl Measure for NaiveTime {
+        type Length = Duration;
+
+        fn measure(start: &Self, end: &Self) -> Self::Length {
+            end.signed_duration_since(*start)
+        }
+
+        // Times wrap around midnight, so moving past it can't be represented
+        fn advance(&self, step: &Self::Length) -> Option<Self> {
+            match self.overflowing_add_signed(*step) {
+                (time, 0) => Some(time),
+                _ => None,
+            }
+        }
+
+        fn retreat(&self, step: &Self::Length) -> Option<Self> {
+            match self.overflowing_sub_signed(*step) {
+                (time, 0) => Some(time),
+                _ => None,
+            }
+        }
+    }
+
+    impl Discrete for NaiveDate {
+        fn successor(&self) -> Option<Self> {
+            self.succ_opt()
+        }
+
+        fn predecessor(&self) -> Option<Self> {
+            self.pred_opt()
+        }
+
+        #[allow(clippy::cast_sign_loss)]
+        fn steps_between(start: &Self, end: &Self) -> Option<u128> {
+            Some(end.signed_duration_since(*start).num_days() as u128)
+        }
+    }
+}
@@ -0,0 +1,323 @@
+mod test_length {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(ContinuousRange::<i32>::Empty.length(), Some(0));
+        assert_eq!(ContinuousRange::Inclusive(5, 1).length(), Some(0u32));
+        assert_eq!(ContinuousRange::Exclusive(1.0, 1.0).length(), Some(0.0));
+    }
+
+    #[test]
+    pub fn single() {
+        assert_eq!(ContinuousRange::Single(5).length(), Some(0u32));
+    }
+
+    #[test]
+    pub fn bounded() {
+        assert_eq!(ContinuousRange::Inclusive(1, 5).length(), Some(4u32));
+        assert_eq!(ContinuousRange::Exclusive(1, 5).length(), Some(4u32));
+        assert_eq!(ContinuousRange::StartExclusive(1, 5).length(), Some(4u32));
+        assert_eq!(ContinuousRange::EndExclusive(1, 5).length(), Some(4u32));
+        assert_eq!(ContinuousRange::EndExclusive(0.5, 2.0).length(), Some(1.5));
+        assert_eq!(ContinuousRange::Inclusive(3u8, 250u8).length(), Some(247));
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(ContinuousRange::From(1).length(), None);
+        assert_eq!(ContinuousRange::FromExclusive(1).length(), None);
+        assert_eq!(ContinuousRange::To(1).length(), None);
+        assert_eq!(ContinuousRange::ToExclusive(1).length(), None);
+        assert_eq!(ContinuousRange::<i32>::Full.length(), None);
+    }
+
+    #[test]
+    pub fn instant() {
+        use std::time::{Duration, Instant};
+
+        let start = Instant::now();
+        let end = start + Duration::from_secs(60);
+        assert_eq!(
+            ContinuousRange::EndExclusive(start, end).length(),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn chrono() {
+        use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+        let start: DateTime<Utc> = "2000-01-01T00:00:00Z".parse().unwrap();
+        let end: DateTime<Utc> = "2000-01-02T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            ContinuousRange::EndExclusive(start, end).length(),
+            Some(Duration::hours(36))
+        );
+
+        let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2000, 3, 1).unwrap();
+        assert_eq!(
+            ContinuousRange::Inclusive(start, end).length(),
+            Some(Duration::days(60))
+        );
+    }
+
+    #[test]
+    pub fn extremes() {
+        assert_eq!(
+            ContinuousRange::Inclusive(-100i8, 100).length(),
+            Some(200u8)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(i8::MIN, i8::MAX).length(),
+            Some(u8::MAX)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(i128::MIN, i128::MAX).length(),
+            Some(u128::MAX)
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(isize::MIN, 0).length(),
+            Some(isize::MAX.unsigned_abs() + 1)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(0, u64::MAX).length(),
+            Some(u64::MAX)
+        );
+    }
+}
+
+mod test_count {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(ContinuousRange::<i32>::Empty.count(), Some(0));
+        assert_eq!(ContinuousRange::Inclusive(5, 1).count(), Some(0));
+        assert_eq!(ContinuousRange::Exclusive(1, 2).count(), Some(0));
+    }
+
+    #[test]
+    pub fn single() {
+        assert_eq!(ContinuousRange::Single(5).count(), Some(1));
+    }
+
+    #[test]
+    pub fn bounded() {
+        assert_eq!(ContinuousRange::Inclusive(1, 5).count(), Some(5));
+        assert_eq!(ContinuousRange::Exclusive(1, 5).count(), Some(3));
+        assert_eq!(ContinuousRange::StartExclusive(1, 5).count(), Some(4));
+        assert_eq!(ContinuousRange::EndExclusive(1, 5).count(), Some(4));
+        assert_eq!(ContinuousRange::Inclusive(-3, 3).count(), Some(7));
+    }
+
+    #[test]
+    pub fn type_limits() {
+        assert_eq!(ContinuousRange::Inclusive(0u8, 255u8).count(), Some(256));
+        assert_eq!(ContinuousRange::Exclusive(0u8, 255u8).count(), Some(254));
+        assert_eq!(
+            ContinuousRange::StartExclusive(255u8, 255u8).count(),
+            Some(0)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(i64::MIN, i64::MAX).count(),
+            Some(1 << 64)
+        );
+        assert_eq!(ContinuousRange::Inclusive(0, u128::MAX).count(), None);
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(ContinuousRange::From(1).count(), None);
+        assert_eq!(ContinuousRange::ToExclusive(1).count(), None);
+        assert_eq!(ContinuousRange::<i32>::Full.count(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn chrono() {
+        use chrono::NaiveDate;
+
+        let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2000, 3, 1).unwrap();
+        assert_eq!(ContinuousRange::EndExclusive(start, end).count(), Some(60));
+    }
+}
+
+mod test_range_measure {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(Range::<i32>::empty().measure(), Some(0));
+    }
+
+    #[test]
+    pub fn continuous() {
+        let r: Range<_> = (1.0..=5.5).into();
+        assert_eq!(r.measure(), Some(4.5));
+    }
+
+    #[test]
+    pub fn composite_disjoint() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Exclusive(10, 20),
+        ]);
+        assert_eq!(r.measure(), Some(14u32));
+    }
+
+    #[test]
+    pub fn composite_overlapping() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Inclusive(3, 8),
+            ContinuousRange::Single(4),
+        ]);
+        assert_eq!(r.measure(), Some(7u32));
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::From(10),
+        ]);
+        assert_eq!(r.measure(), None);
+    }
+
+    #[test]
+    pub fn extremes() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(i8::MIN, -1),
+            ContinuousRange::Inclusive(0, i8::MAX),
+        ]);
+        assert_eq!(r.measure(), Some(254u8));
+
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(i64::MIN, 0),
+            ContinuousRange::Inclusive(0, i64::MAX),
+        ]);
+        assert_eq!(r.measure(), Some(u64::MAX));
+    }
+}
+
+mod test_range_count {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn empty() {
+        assert_eq!(Range::<i32>::empty().count(), Some(0));
+    }
+
+    #[test]
+    pub fn continuous() {
+        let r: Range<_> = (1..5).into();
+        assert_eq!(r.count(), Some(4));
+    }
+
+    #[test]
+    pub fn composite_overlapping() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Inclusive(3, 8),
+            ContinuousRange::Single(20),
+            ContinuousRange::Single(20),
+        ]);
+        assert_eq!(r.count(), Some(9));
+    }
+
+    #[test]
+    pub fn unbounded() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::ToExclusive(0),
+        ]);
+        assert_eq!(r.count(), None);
+    }
+}
+
+mod test_magnitude {
+    use std::time::Duration;
+
+    use crate::Magnitude;
+
+    #[test]
+    pub fn integers() {
+        assert_eq!(10u8.fraction(1, 3), 3);
+        assert_eq!((-10i8).fraction(1, 3), -3);
+        assert_eq!(i128::MIN.fraction(1, 1), i128::MIN);
+        assert_eq!(u128::MAX.fraction(u64::MAX, u64::MAX), u128::MAX);
+        assert_eq!(100u8.times(2), Some(200));
+        assert_eq!(100u8.times(3), None);
+        assert_eq!(1u8.times(256), None);
+        assert_eq!(10u32.quotient(&3), Some(3));
+        assert_eq!(u128::MAX.quotient(&1), None);
+        assert!((1u32.ratio(&4) - 0.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    pub fn floats() {
+        assert!((10.0f64.fraction(1, 4) - 2.5).abs() < f64::EPSILON);
+        assert_eq!(2.5.times(4), Some(10.0));
+        assert_eq!(10.0.quotient(&3.0), Some(3));
+        assert_eq!((-1.0).quotient(&3.0), None);
+        assert_eq!(f64::NAN.quotient(&3.0), None);
+    }
+
+    #[test]
+    pub fn durations() {
+        let duration = Duration::from_secs(10);
+        assert_eq!(duration.fraction(1, 4), Duration::from_millis(2500));
+        assert_eq!(duration.times(3), Some(Duration::from_secs(30)));
+        assert_eq!(Duration::MAX.times(2), None);
+        assert_eq!(duration.quotient(&Duration::from_secs(3)), Some(3));
+        assert!((duration.ratio(&Duration::from_secs(40)) - 0.25).abs() < f64::EPSILON);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn chrono_durations() {
+        use chrono::Duration;
+
+        let duration = Duration::hours(10);
+        assert_eq!(duration.fraction(1, 4), Duration::minutes(150));
+        assert_eq!(duration.times(3), Some(Duration::hours(30)));
+        assert_eq!(duration.quotient(&Duration::hours(3)), Some(3));
+        assert_eq!(Duration::hours(-10).quotient(&Duration::hours(3)), None);
+    }
+}
+
+mod test_move {
+    use std::net::Ipv4Addr;
+
+    use crate::Measure;
+
+    #[test]
+    pub fn integers() {
+        assert_eq!(10i8.advance(&117), Some(127));
+        assert_eq!(10i8.advance(&118), None);
+        assert_eq!((-128i8).advance(&255), Some(127));
+        assert_eq!(10i8.retreat(&138), Some(-128));
+        assert_eq!(10i8.retreat(&139), None);
+        assert_eq!(0u8.retreat(&1), None);
+    }
+
+    #[test]
+    pub fn addresses() {
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 0, 255).advance(&1),
+            Some(Ipv4Addr::new(10, 0, 1, 0))
+        );
+        assert_eq!(Ipv4Addr::UNSPECIFIED.retreat(&1), None);
+    }
+
+    #[test]
+    pub fn interpolate() {
+        assert_eq!(i8::interpolate(&-128, &127, 1, 2), Some(-1));
+        assert_eq!(u8::interpolate(&0, &255, 1, 1), Some(255));
+        assert_eq!(f64::interpolate(&f64::MIN, &f64::MAX, 1, 2), Some(0.0));
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{
+    continuous::bound_value, coverage, ContinuousRange, Magnitude, Measure, PointRelation, Range,
+};
+
+/// The distance between two values in any order
+pub(crate) fn between<Idx: Measure>(a: &Idx, b: &Idx) -> Idx::Length {
+    if a <= b {
+        Idx::measure(a, b)
+    } else {
+        Idx::measure(b, a)
+    }
+}
+
+/// The distance between `point` and the nearest value of `range`, which must not be empty
+fn point_distance<Idx: Measure>(point: &Idx, range: &ContinuousRange<Idx>) -> Option<Idx::Length> {
+    match range.relate_point(point)? {
+        PointRelation::Before => Some(Idx::measure(point, range.start().and_then(bound_value)?)),
+        PointRelation::After => Some(Idx::measure(range.end().and_then(bound_value)?, point)),
+        PointRelation::Starts | PointRelation::During | PointRelation::Finishes => {
+            Some(Idx::Length::zero())
+        }
+    }
+}
+
+/// The size of the gap between two disjoint ranges
+fn gap<Idx: Measure>(a: &ContinuousRange<Idx>, b: &ContinuousRange<Idx>) -> Option<Idx::Length> {
+    match (
+        a.end().and_then(bound_value),
+        b.start().and_then(bound_value),
+    ) {
+        (Some(end), Some(start)) if end <= start => Some(Idx::measure(end, start)),
+        _ => Some(Idx::measure(
+            b.end().and_then(bound_value)?,
+            a.start().and_then(bound_value)?,
+        )),
+    }
+}
+
+/// The greatest distance from a value of `from` to the nearest value of `to`, both ordered and disjoint
+fn directed_hausdorff<Idx: Measure>(
+    from: &[ContinuousRange<Idx>],
+    to: &[ContinuousRange<Idx>],
+) -> Option<Idx::Length> {
+    // The distance to `to` is the highest either on the bounds of `from` or in the middle of the gaps of `to`
+    let mut candidates = Vec::new();
+    for piece in from {
+        candidates.push(piece.start().and_then(bound_value)?.clone());
+        candidates.push(piece.end().and_then(bound_value)?.clone());
+    }
+    for pair in to.windows(2) {
+        let gap_start = pair[0].end().and_then(bound_value)?;
+        let gap_end = pair[1].start().and_then(bound_value)?;
+        let middle = Idx::interpolate(gap_start, gap_end, 1, 2)?;
+        if from.iter().any(|piece| piece.contains(&middle)) {
+            candidates.push(middle);
+        }
+    }
+
+    let mut result: Option<Idx::Length> = None;
+    for candidate in &candidates {
+        let mut nearest: Option<Idx::Length> = None;
+        for piece in to {
+            let distance = point_distance(candidate, piece)?;
+            nearest = Some(match nearest {
+                Some(nearest) if nearest <= distance => nearest,
+                _ => distance,
+            });
+        }
+        let nearest = nearest?;
+        result = Some(match result {
+            Some(result) if result >= nearest => result,
+            _ => nearest,
+        });
+    }
+
+    result
+}
+
+impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
+    /// The size of the gap between the two ranges, zero if they intersect or are adjacent
+    ///
+    /// Returns [`None`] if one of the ranges is empty, see [`Range::distance`].
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range: ContinuousRange<i32> = ContinuousRange::Inclusive(0, 10);
+    /// assert_eq!(range.distance(&ContinuousRange::Inclusive(15, 20)), Some(5));
+    /// assert_eq!(range.distance(&ContinuousRange::From(5)), Some(0));
+    /// ```
+    #[must_use]
+    pub fn distance(&self, other: &ContinuousRange<Idx>) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        Range::Continuous(self.clone()).distance(&Range::Continuous(other.clone()))
+    }
+
+    /// The length of the part contained in both ranges, see [`Range::overlap_length`]
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range: ContinuousRange<i32> = ContinuousRange::Inclusive(0, 10);
+    /// assert_eq!(range.overlap_length(&ContinuousRange::Inclusive(5, 20)), Some(5));
+    /// assert_eq!(range.overlap_length(&ContinuousRange::Inclusive(15, 20)), Some(0));
+    /// ```
+    #[must_use]
+    pub fn overlap_length(&self, other: &ContinuousRange<Idx>) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        Range::Continuous(self.clone()).overlap_length(&Range::Continuous(other.clone()))
+    }
+
+    /// The Jaccard similarity of the ranges, the length of their intersection divided by the length of their
+    /// union, see [`Range::jaccard`]
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::Inclusive(0.0, 10.0);
+    /// assert_eq!(range.jaccard(&ContinuousRange::Inclusive(5.0, 15.0)), Some(1.0 / 3.0));
+    /// ```
+    #[must_use]
+    pub fn jaccard(&self, other: &ContinuousRange<Idx>) -> Option<f64>
+    where
+        Idx: Measure,
+    {
+        Range::Continuous(self.clone()).jaccard(&Range::Continuous(other.clone()))
+    }
+
+    /// The Hausdorff distance between the ranges, the greatest distance from a value of one of them to the
+    /// nearest value of the other
+    ///
+    /// For continuous ranges it's the greatest of the distances between their starts and between their ends.
+    /// Returns [`None`] if one of the ranges is empty or unbounded.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range: ContinuousRange<i32> = ContinuousRange::Inclusive(0, 10);
+    /// assert_eq!(range.hausdorff(&ContinuousRange::Inclusive(2, 20)), Some(10));
+    /// ```
+    #[must_use]
+    pub fn hausdorff(&self, other: &ContinuousRange<Idx>) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+
+        let starts = between(
+            self.start().and_then(bound_value)?,
+            other.start().and_then(bound_value)?,
+        );
+        let ends = between(
+            self.end().and_then(bound_value)?,
+            other.end().and_then(bound_value)?,
+        );
+        Some(if ends > starts { ends } else { starts })
+    }
+}
+
+impl<Idx: PartialOrd + Clone> Range<Idx> {
+    /// The size of the smallest gap between a part of `self` and a part of `other`, zero if they intersect or
+    /// are adjacent
+    ///
+    /// Returns [`None`] if one of the ranges is empty.
+    ///
+    /// ```text
+    /// [ A ]       [ A ]
+    ///        [ B ]
+    ///      <->
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn distance(&self, other: &Range<Idx>) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        let a = coverage::covered(self.pieces())
+            .expect("PartialOrd contract isn't correctly implemented");
+        let b = coverage::covered(other.pieces())
+            .expect("PartialOrd contract isn't correctly implemented");
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+
+        let intersection = coverage::intersect_disjoint(&a, &b)
+            .expect("PartialOrd contract isn't correctly implemented");
+        if !intersection.is_empty() {
+            return Some(Idx::Length::zero());
+        }
+
+        let mut distance: Option<Idx::Length> = None;
+        for piece in &a {
+            for other_piece in &b {
+                let gap = gap(piece, other_piece)?;
+                distance = Some(match distance {
+                    Some(distance) if distance <= gap => distance,
+                    _ => gap,
+                });
+            }
+        }
+        distance
+    }
+
+    /// The length of the values contained in both ranges or [`None`] if it's unbounded
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn overlap_length(&self, other: &Range<Idx>) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        self.clone().intersection(other).measure()
+    }
+
+    /// The Jaccard similarity of the ranges, the length of their intersection divided by the length of their
+    /// union
+    ///
+    /// The result is between `0.0` for disjoint ranges and `1.0` for equal ones. Returns [`None`] if the union is
+    /// unbounded or has a length of zero.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn jaccard(&self, other: &Range<Idx>) -> Option<f64>
+    where
+        Idx: Measure,
+    {
+        let union = Range::composite(vec![self.clone(), other.clone()]).measure()?;
+        if union <= Idx::Length::zero() {
+            return None;
+        }
+
+        Some(self.overlap_length(other)?.ratio(&union))
+    }
+
+    /// The Hausdorff distance between the ranges, the greatest distance from a value of one of them to the
+    /// nearest value of the other
+    ///
+    /// Returns [`None`] if one of the ranges is empty or unbounded.
+    ///
+    /// ```text
+    /// [ A ]       [ A ]
+    ///    [   B   ]
+    ///             <--->
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn hausdorff(&self, other: &Range<Idx>) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        let a = coverage::covered(self.pieces())
+            .expect("PartialOrd contract isn't correctly implemented");
+        let b = coverage::covered(other.pieces())
+            .expect("PartialOrd contract isn't correctly implemented");
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+
+        let (from_a, from_b) = (directed_hausdorff(&a, &b)?, directed_hausdorff(&b, &a)?);
+        Some(if from_b > from_a { from_b } else { from_a })
+    }
+}
@@ -0,0 +1,165 @@
+mod test_distance {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn continuous() {
+        let range: ContinuousRange<i32> = ContinuousRange::Inclusive(0, 10);
+        assert_eq!(range.distance(&ContinuousRange::Inclusive(5, 20)), Some(0));
+        assert_eq!(range.distance(&ContinuousRange::Inclusive(15, 20)), Some(5));
+        assert_eq!(range.distance(&ContinuousRange::To(-3)), Some(3));
+        assert_eq!(
+            range.distance(&ContinuousRange::StartExclusive(10, 20)),
+            Some(0)
+        );
+        assert_eq!(range.distance(&ContinuousRange::Empty), None);
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            ContinuousRange::From(10i32).distance(&ContinuousRange::To(4)),
+            Some(6)
+        );
+        assert_eq!(
+            ContinuousRange::To(4i32).distance(&ContinuousRange::Full),
+            Some(0)
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let range: Range<i32> = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(30, 40),
+        ]);
+        let other = Range::Composite(vec![
+            ContinuousRange::Inclusive(-20, -15),
+            ContinuousRange::Inclusive(12, 25),
+        ]);
+        assert_eq!(range.distance(&other), Some(2));
+        assert_eq!(
+            range.distance(&Range::Continuous(ContinuousRange::Single(35))),
+            Some(0)
+        );
+    }
+}
+
+mod test_overlap_length {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn continuous() {
+        let range = ContinuousRange::EndExclusive(0.0, 10.0);
+        assert_eq!(range.overlap_length(&ContinuousRange::From(2.5)), Some(7.5));
+        assert_eq!(
+            range.overlap_length(&ContinuousRange::From(10.0)),
+            Some(0.0)
+        );
+        assert_eq!(
+            ContinuousRange::From(0).overlap_length(&ContinuousRange::Full),
+            None
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let range: Range<i32> = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(20, 30),
+        ]);
+        assert_eq!(
+            range.overlap_length(&Range::Continuous(ContinuousRange::Inclusive(5, 25))),
+            Some(10)
+        );
+    }
+}
+
+mod test_jaccard {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn continuous() {
+        let range: ContinuousRange<i32> = ContinuousRange::Inclusive(0, 10);
+        assert_eq!(range.jaccard(&range), Some(1.0));
+        assert_eq!(
+            range.jaccard(&ContinuousRange::Inclusive(20, 30)),
+            Some(0.0)
+        );
+        assert_eq!(range.jaccard(&ContinuousRange::Inclusive(0, 5)), Some(0.5));
+        assert_eq!(range.jaccard(&ContinuousRange::From(5)), None);
+        assert_eq!(
+            ContinuousRange::Single(1).jaccard(&ContinuousRange::Single(1)),
+            None
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let range: Range<i32> = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(20, 30),
+        ]);
+        assert_eq!(
+            range.jaccard(&Range::Continuous(ContinuousRange::Inclusive(0, 30))),
+            Some(20.0 / 30.0)
+        );
+    }
+
+    #[test]
+    pub fn durations() {
+        use std::time::{Duration, Instant};
+
+        let now = Instant::now();
+        let second = Duration::from_secs(1);
+        let range = ContinuousRange::Inclusive(now, now + second * 4);
+        assert_eq!(
+            range.jaccard(&ContinuousRange::Inclusive(now + second, now + second * 5)),
+            Some(0.6)
+        );
+    }
+}
+
+mod test_hausdorff {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn continuous() {
+        let range: ContinuousRange<i32> = ContinuousRange::Inclusive(0, 10);
+        assert_eq!(range.hausdorff(&range), Some(0));
+        assert_eq!(range.hausdorff(&ContinuousRange::Inclusive(3, 8)), Some(3));
+        assert_eq!(
+            range.hausdorff(&ContinuousRange::Inclusive(20, 25)),
+            Some(20)
+        );
+        assert_eq!(range.hausdorff(&ContinuousRange::From(3)), None);
+        assert_eq!(range.hausdorff(&ContinuousRange::Empty), None);
+    }
+
+    #[test]
+    pub fn composite() {
+        let range: Range<i32> = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(30, 40),
+        ]);
+        let whole = Range::Continuous(ContinuousRange::Inclusive(0, 40));
+        // The middle of the gap, 20, is 10 away from both parts
+        assert_eq!(range.hausdorff(&whole), Some(10));
+        assert_eq!(whole.hausdorff(&range), Some(10));
+
+        let shifted = Range::Composite(vec![
+            ContinuousRange::Inclusive(2, 10),
+            ContinuousRange::Inclusive(30, 45),
+        ]);
+        assert_eq!(range.hausdorff(&shifted), Some(5));
+    }
+
+    #[test]
+    pub fn agrees_with_continuous() {
+        let a = ContinuousRange::Inclusive(0.0, 4.0);
+        let b = ContinuousRange::Inclusive(1.0, 10.0);
+        assert_eq!(
+            Range::Continuous(a.clone()).hausdorff(&Range::Continuous(b.clone())),
+            a.hausdorff(&b)
+        );
+    }
+}
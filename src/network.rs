@@ -0,0 +1,401 @@
+use std::{collections::HashMap, error::Error, fmt, hash::Hash};
+
+use crate::{ContinuousRange, RangesRelation, RelationSet};
+
+/// Error returned when the constraints of an [`IntervalNetwork`] can't all be satisfied
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Inconsistency<K> {
+    /// First interval of the pair that has no possible relation left
+    pub first: K,
+
+    /// Second interval of the pair that has no possible relation left
+    pub second: K,
+}
+
+impl<K: fmt::Debug> fmt::Display for Inconsistency<K> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "no relation can satisfy the constraints between {:?} and {:?}",
+            self.first, self.second
+        )
+    }
+}
+
+impl<K: fmt::Debug> Error for Inconsistency<K> {}
+
+/// A network of named intervals with constraints on the relations between them
+///
+/// Each pair of intervals is constrained by a [`RelationSet`] of the relations allowed between them, pairs
+/// without constraint allow all of them. Reasoning is done with Allen's interval algebra so the intervals are
+/// assumed to have a start strictly before their end.
+///
+/// ```
+/// use range_ranger::{IntervalNetwork, RangesRelation};
+///
+/// let mut network = IntervalNetwork::new();
+/// network.constrain("breakfast", "commute", RangesRelation::StrictlyBefore);
+/// network.constrain("commute", "work", RangesRelation::Meets);
+/// network.path_consistency().unwrap();
+///
+/// let relations = network.relations(&"breakfast", &"work").unwrap();
+/// assert!(relations.contains(RangesRelation::StrictlyBefore));
+/// assert_eq!(relations.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntervalNetwork<K> {
+    names: Vec<K>,
+    indices: HashMap<K, usize>,
+    constraints: Constraints,
+}
+
+impl<K: Eq + Hash + Clone> Default for IntervalNetwork<K> {
+    fn default() -> Self {
+        IntervalNetwork {
+            names: Vec::new(),
+            indices: HashMap::new(),
+            constraints: Constraints::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> IntervalNetwork<K> {
+    /// A network without any interval
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an interval without any constraint, does nothing if it's already part of the network
+    pub fn add_interval(&mut self, name: K) {
+        self.index_of(name);
+    }
+
+    /// The intervals of the network, in insertion order
+    #[must_use]
+    pub fn intervals(&self) -> &[K] {
+        &self.names
+    }
+
+    /// Restrict the relation of `first` to `second` to `relations`
+    ///
+    /// The intervals are added to the network if they aren't already part of it. Constraining the same pair
+    /// multiple times keeps only the relations allowed by all the constraints.
+    pub fn constrain(&mut self, first: K, second: K, relations: impl Into<RelationSet>) {
+        let first = self.index_of(first);
+        let second = self.index_of(second);
+        let relations = self.constraints.get(first, second) & relations.into();
+        self.constraints.set(first, second, relations);
+    }
+
+    /// The relations currently allowed between `first` and `second` or [`None`] if one of them isn't part of
+    /// the network
+    #[must_use]
+    pub fn relations(&self, first: &K, second: &K) -> Option<RelationSet> {
+        let first = *self.indices.get(first)?;
+        let second = *self.indices.get(second)?;
+        Some(self.constraints.get(first, second))
+    }
+
+    /// Propagate the constraints between all the intervals, removing the relations that can't be part of any
+    /// solution considering each triplet of intervals (PC-2 algorithm)
+    ///
+    /// Path consistency doesn't guarantee that a solution exists, use [`IntervalNetwork::solve`] for that.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first pair of intervals found without any possible relation left.
+    pub fn path_consistency(&mut self) -> Result<(), Inconsistency<K>> {
+        self.constraints
+            .propagate(self.constraints.all_pairs())
+            .map_err(|(first, second)| Inconsistency {
+                first: self.names[first].clone(),
+                second: self.names[second].clone(),
+            })
+    }
+
+    /// Find a range for each interval satisfying all the constraints or [`None`] if there is no solution
+    ///
+    /// The ranges are all [`ContinuousRange::Inclusive`] with a start strictly before the end, meeting intervals
+    /// share their common bound.
+    #[must_use]
+    pub fn solve(&self) -> Option<HashMap<K, ContinuousRange<i64>>> {
+        let mut constraints = self.constraints.clone();
+        constraints.propagate(constraints.all_pairs()).ok()?;
+        let constraints = constraints.search()?;
+        let ranges = constraints.assign()?;
+
+        Some(self.names.iter().cloned().zip(ranges).collect())
+    }
+
+    fn index_of(&mut self, name: K) -> usize {
+        if let Some(index) = self.indices.get(&name) {
+            return *index;
+        }
+
+        let index = self.names.len();
+        self.names.push(name.clone());
+        self.indices.insert(name, index);
+        self.constraints.grow();
+        index
+    }
+}
+
+/// The relations allowed between each pair of intervals, identified by their index
+#[derive(Clone, Default, Debug)]
+struct Constraints {
+    size: usize,
+    matrix: Vec<RelationSet>,
+}
+
+impl Constraints {
+    fn get(&self, first: usize, second: usize) -> RelationSet {
+        self.matrix[first * self.size + second]
+    }
+
+    fn set(&mut self, first: usize, second: usize, relations: RelationSet) {
+        self.matrix[first * self.size + second] = relations;
+        self.matrix[second * self.size + first] = relations.inverse();
+    }
+
+    fn grow(&mut self) {
+        let size = self.size + 1;
+        let mut matrix = vec![RelationSet::ALL; size * size];
+        for first in 0..self.size {
+            for second in 0..self.size {
+                matrix[first * size + second] = self.get(first, second);
+            }
+        }
+        matrix[size * size - 1] = RangesRelation::Equal.into();
+
+        self.size = size;
+        self.matrix = matrix;
+    }
+
+    fn all_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for first in 0..self.size {
+            for second in (first + 1)..self.size {
+                pairs.push((first, second));
+            }
+        }
+        pairs
+    }
+
+    /// Restrict the relations of `first` to `second` to the ones in `relations`, returning if anything changed
+    fn revise(
+        &mut self,
+        first: usize,
+        second: usize,
+        relations: RelationSet,
+    ) -> Result<bool, (usize, usize)> {
+        let current = self.get(first, second);
+        let revised = current & relations;
+        if revised == current {
+            return Ok(false);
+        }
+        if revised.is_empty() {
+            return Err((first, second));
+        }
+
+        self.set(first, second, revised);
+        Ok(true)
+    }
+
+    /// Path consistency starting from the pairs in `queue`
+    fn propagate(&mut self, mut queue: Vec<(usize, usize)>) -> Result<(), (usize, usize)> {
+        if let Some(&(first, second)) = queue.iter().find(|(i, j)| self.get(*i, *j).is_empty()) {
+            return Err((first, second));
+        }
+
+        while let Some((i, j)) = queue.pop() {
+            for k in 0..self.size {
+                if k == i || k == j {
+                    continue;
+                }
+
+                let ik = self.get(i, j).compose(self.get(j, k));
+                if self.revise(i, k, ik)? {
+                    queue.push((i, k));
+                }
+
+                let kj = self.get(k, i).compose(self.get(i, j));
+                if self.revise(k, j, kj)? {
+                    queue.push((k, j));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Backtrack over the possible relations until all pairs have a single one
+    fn search(self) -> Option<Constraints> {
+        let pair = self
+            .all_pairs()
+            .into_iter()
+            .find(|(first, second)| self.get(*first, *second).len() > 1);
+
+        let (first, second) = match pair {
+            Some(pair) => pair,
+            None => return Some(self),
+        };
+
+        for relation in self.get(first, second).iter() {
+            let mut candidate = self.clone();
+            candidate.set(first, second, relation.into());
+            if candidate.propagate(vec![(first, second)]).is_ok() {
+                if let Some(solution) = candidate.search() {
+                    return Some(solution);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Compute ranges for a network where each pair has a single relation
+    fn assign(&self) -> Option<Vec<ContinuousRange<i64>>> {
+        // Interval `i` has its start at point `2 * i` and its end at point `2 * i + 1`
+        let mut points = Points::new(self.size * 2);
+        for interval in 0..self.size {
+            points.less(2 * interval, 2 * interval + 1);
+        }
+
+        for first in 0..self.size {
+            for second in (first + 1)..self.size {
+                let relation = self.get(first, second).iter().next()?;
+                let (a_start, a_end) = (2 * first, 2 * first + 1);
+                let (b_start, b_end) = (2 * second, 2 * second + 1);
+                match relation {
+                    RangesRelation::StrictlyBefore => points.less(a_end, b_start),
+                    RangesRelation::StrictlyAfter => points.less(b_end, a_start),
+                    RangesRelation::Meets => points.equal(a_end, b_start),
+                    RangesRelation::IsMet => points.equal(a_start, b_end),
+                    RangesRelation::Overlaps => {
+                        points.less(a_start, b_start);
+                        points.less(b_start, a_end);
+                        points.less(a_end, b_end);
+                    }
+                    RangesRelation::IsOverlapped => {
+                        points.less(b_start, a_start);
+                        points.less(a_start, b_end);
+                        points.less(b_end, a_end);
+                    }
+                    RangesRelation::Starts => {
+                        points.equal(a_start, b_start);
+                        points.less(a_end, b_end);
+                    }
+                    RangesRelation::IsStarted => {
+                        points.equal(a_start, b_start);
+                        points.less(b_end, a_end);
+                    }
+                    RangesRelation::StrictlyContains => {
+                        points.less(a_start, b_start);
+                        points.less(b_end, a_end);
+                    }
+                    RangesRelation::IsStrictlyContained => {
+                        points.less(b_start, a_start);
+                        points.less(a_end, b_end);
+                    }
+                    RangesRelation::Finishes => {
+                        points.less(b_start, a_start);
+                        points.equal(a_end, b_end);
+                    }
+                    RangesRelation::IsFinished => {
+                        points.less(a_start, b_start);
+                        points.equal(a_end, b_end);
+                    }
+                    RangesRelation::Equal => {
+                        points.equal(a_start, b_start);
+                        points.equal(a_end, b_end);
+                    }
+                }
+            }
+        }
+
+        let values = points.values()?;
+        Some(
+            (0..self.size)
+                .map(|i| ContinuousRange::Inclusive(values[2 * i], values[2 * i + 1]))
+                .collect(),
+        )
+    }
+}
+
+/// Ordering constraints between points, solved by giving each point its rank
+struct Points {
+    parents: Vec<usize>,
+    less: Vec<(usize, usize)>,
+}
+
+impl Points {
+    fn new(count: usize) -> Self {
+        Points {
+            parents: (0..count).collect(),
+            less: Vec::new(),
+        }
+    }
+
+    fn root(&mut self, point: usize) -> usize {
+        let mut root = point;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        self.parents[point] = root;
+        root
+    }
+
+    fn equal(&mut self, a: usize, b: usize) {
+        let a = self.root(a);
+        let b = self.root(b);
+        self.parents[a] = b;
+    }
+
+    fn less(&mut self, a: usize, b: usize) {
+        self.less.push((a, b));
+    }
+
+    /// The value of each point or [`None`] if the constraints contain a cycle
+    fn values(mut self) -> Option<Vec<i64>> {
+        let count = self.parents.len();
+        let less = std::mem::take(&mut self.less);
+
+        let mut successors = vec![Vec::new(); count];
+        let mut predecessors = vec![0usize; count];
+        for (a, b) in less {
+            let (a, b) = (self.root(a), self.root(b));
+            if a == b {
+                return None;
+            }
+            successors[a].push(b);
+            predecessors[b] += 1;
+        }
+
+        // Topological order, each point is placed right after its highest predecessor
+        let mut values = vec![0i64; count];
+        let mut ready: Vec<usize> = (0..count)
+            .filter(|point| self.parents[*point] == *point && predecessors[*point] == 0)
+            .collect();
+        let mut visited = 0;
+        while let Some(point) = ready.pop() {
+            visited += 1;
+            for successor in std::mem::take(&mut successors[point]) {
+                values[successor] = values[successor].max(values[point] + 1);
+                predecessors[successor] -= 1;
+                if predecessors[successor] == 0 {
+                    ready.push(successor);
+                }
+            }
+        }
+
+        let roots = (0..count)
+            .filter(|point| self.parents[*point] == *point)
+            .count();
+        if visited != roots {
+            return None;
+        }
+
+        Some((0..count).map(|point| values[self.root(point)]).collect())
+    }
+}
@@ -0,0 +1,158 @@
+mod test_path_consistency {
+    use crate::{Inconsistency, IntervalNetwork, RangesRelation, RelationSet};
+
+    #[test]
+    pub fn infers_relations() {
+        let mut network = IntervalNetwork::new();
+        network.constrain('a', 'b', RangesRelation::IsStrictlyContained);
+        network.constrain('b', 'c', RangesRelation::StrictlyBefore);
+        assert_eq!(network.relations(&'a', &'c'), Some(RelationSet::ALL));
+
+        network.path_consistency().unwrap();
+        assert_eq!(
+            network.relations(&'a', &'c'),
+            Some(RangesRelation::StrictlyBefore.into())
+        );
+        assert_eq!(
+            network.relations(&'c', &'a'),
+            Some(RangesRelation::StrictlyAfter.into())
+        );
+    }
+
+    #[test]
+    pub fn constraints_are_intersected() {
+        let mut network = IntervalNetwork::new();
+        let before_or_meets =
+            RelationSet::from(RangesRelation::StrictlyBefore) | RangesRelation::Meets.into();
+        network.constrain('a', 'b', before_or_meets);
+        network.constrain('b', 'a', RangesRelation::IsMet);
+        assert_eq!(
+            network.relations(&'a', &'b'),
+            Some(RangesRelation::Meets.into())
+        );
+    }
+
+    #[test]
+    pub fn detects_cycle() {
+        let mut network = IntervalNetwork::new();
+        network.constrain('a', 'b', RangesRelation::StrictlyBefore);
+        network.constrain('b', 'c', RangesRelation::StrictlyBefore);
+        network.constrain('c', 'a', RangesRelation::StrictlyBefore);
+
+        let error = network.path_consistency().unwrap_err();
+        assert!(matches!(error, Inconsistency { .. }));
+        assert!(network.solve().is_none());
+    }
+
+    #[test]
+    pub fn detects_empty_constraint() {
+        let mut network = IntervalNetwork::new();
+        network.constrain('a', 'b', RangesRelation::StrictlyBefore);
+        network.constrain('a', 'b', RangesRelation::StrictlyAfter);
+        assert_eq!(
+            network.path_consistency(),
+            Err(Inconsistency {
+                first: 'a',
+                second: 'b'
+            })
+        );
+    }
+
+    #[test]
+    pub fn intervals() {
+        let mut network = IntervalNetwork::new();
+        network.add_interval("x");
+        network.constrain("y", "x", RangesRelation::Equal);
+        network.add_interval("x");
+        assert_eq!(network.intervals(), &["x", "y"]);
+        assert_eq!(network.relations(&"x", &"z"), None);
+        assert_eq!(
+            network.relations(&"x", &"x"),
+            Some(RangesRelation::Equal.into())
+        );
+    }
+}
+
+mod test_solve {
+    use crate::{ContinuousRange, IntervalNetwork, RangesRelation, RelationSet};
+
+    fn check(network: &IntervalNetwork<usize>) {
+        let solution = network.solve().expect("The network has a solution");
+        for first in network.intervals() {
+            let first_range = &solution[first];
+            assert!(matches!(first_range, ContinuousRange::Inclusive(s, e) if s < e));
+            for second in network.intervals() {
+                let relation = first_range.compare(&solution[second]).unwrap();
+                let allowed = network.relations(first, second).unwrap();
+                assert!(allowed.contains(relation));
+            }
+        }
+    }
+
+    #[test]
+    pub fn chain() {
+        let mut network = IntervalNetwork::new();
+        network.constrain(0, 1, RangesRelation::Meets);
+        network.constrain(1, 2, RangesRelation::Overlaps);
+        network.constrain(3, 2, RangesRelation::Finishes);
+        network.constrain(3, 0, RangesRelation::StrictlyAfter);
+        check(&network);
+    }
+
+    #[test]
+    pub fn disjunctions() {
+        let mut network = IntervalNetwork::new();
+        let set =
+            |relations: &[RangesRelation]| -> RelationSet { relations.iter().copied().collect() };
+        network.constrain(
+            0,
+            1,
+            set(&[RangesRelation::StrictlyBefore, RangesRelation::Starts]),
+        );
+        network.constrain(1, 2, set(&[RangesRelation::Equal, RangesRelation::Meets]));
+        network.constrain(
+            0,
+            2,
+            set(&[RangesRelation::Starts, RangesRelation::Overlaps]),
+        );
+        check(&network);
+    }
+
+    #[test]
+    pub fn random_networks() {
+        // Small linear congruential generator to stay deterministic without dependencies
+        let mut seed = 42u64;
+        let mut next = move |max: u64| {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (seed >> 33) % max
+        };
+
+        for _ in 0..50 {
+            let ranges: Vec<_> = (0..5)
+                .map(|_| {
+                    let start = next(10);
+                    ContinuousRange::Inclusive(start, start + 1 + next(5))
+                })
+                .collect();
+
+            let mut network = IntervalNetwork::new();
+            for (first, first_range) in ranges.iter().enumerate() {
+                for (second, second_range) in ranges.iter().enumerate().skip(first + 1) {
+                    if next(3) == 0 {
+                        continue;
+                    }
+
+                    let mut allowed = RelationSet::from(first_range.compare(second_range).unwrap());
+                    for _ in 0..next(3) {
+                        #[allow(clippy::cast_possible_truncation)]
+                        allowed.insert(RangesRelation::ALL[next(13) as usize]);
+                    }
+                    network.constrain(first, second, allowed);
+                }
+            }
+            check(&network);
+        }
+    }
+}
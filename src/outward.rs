@@ -0,0 +1,103 @@
+use std::fmt;
+
+use crate::{Numeric, Real};
+
+/// A floating point index type whose interval arithmetic rounds outward
+///
+/// Each bound computed by the operations of [`Numeric`] is moved to the next representable value away from the
+/// range (toward negative infinity for the start and positive infinity for the end) so that the resulting range
+/// always contains the exact result, even when it can't be represented.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, Outward};
+///
+/// let sum = ContinuousRange::Single(Outward(0.1)) + ContinuousRange::Single(Outward(0.2));
+/// assert!(sum.contains(Outward(0.3)));
+/// assert!(sum.contains(Outward(0.1 + 0.2)));
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Outward<F>(pub F);
+
+impl<F: fmt::Debug> fmt::Debug for Outward<F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(fmt)
+    }
+}
+
+impl<F> From<F> for Outward<F> {
+    fn from(value: F) -> Self {
+        Outward(value)
+    }
+}
+
+macro_rules! impl_outward {
+    ($($t:ty),*) => {
+        $(
+            impl Outward<$t> {
+                /// The smallest representable value greater than this one
+                #[must_use]
+                pub fn next_up(self) -> Self {
+                    let value = self.0;
+                    if value.is_nan() || value == <$t>::INFINITY {
+                        self
+                    } else if value == 0.0 {
+                        // Smallest positive subnormal, for both 0.0 and -0.0
+                        Outward(<$t>::from_bits(1))
+                    } else if value > 0.0 {
+                        Outward(<$t>::from_bits(value.to_bits() + 1))
+                    } else {
+                        Outward(<$t>::from_bits(value.to_bits() - 1))
+                    }
+                }
+
+                /// The largest representable value lower than this one
+                #[must_use]
+                pub fn next_down(self) -> Self {
+                    Outward(-Outward(-self.0).next_up().0)
+                }
+            }
+
+            impl Numeric for Outward<$t> {
+                fn zero() -> Self {
+                    Outward(0.0)
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    self.0.checked_add(&other.0).map(Outward)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    self.0.checked_sub(&other.0).map(Outward)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    self.0.checked_mul(&other.0).map(Outward)
+                }
+
+                fn checked_div(&self, other: &Self) -> Option<Self> {
+                    self.0.checked_div(&other.0).map(Outward)
+                }
+
+                fn checked_pow(&self, exp: u32) -> Option<Self> {
+                    self.0.checked_pow(exp).map(Outward)
+                }
+
+                fn round_down(self) -> Self {
+                    self.next_down()
+                }
+
+                fn round_up(self) -> Self {
+                    self.next_up()
+                }
+            }
+
+            impl Real for Outward<$t> {
+                fn sqrt(&self) -> Self {
+                    Outward(self.0.sqrt())
+                }
+            }
+        )*
+    };
+}
+
+impl_outward!(f32, f64);
@@ -0,0 +1,124 @@
+mod test_next {
+    use crate::Outward;
+
+    #[test]
+    pub fn next_up() {
+        assert!(Outward(1.0f64).next_up() > Outward(1.0));
+        assert_eq!(Outward(1.0f64).next_up(), Outward(1.0 + f64::EPSILON));
+        assert_eq!(Outward(0.0f64).next_up(), Outward(f64::from_bits(1)));
+        assert_eq!(Outward(-0.0f64).next_up(), Outward(f64::from_bits(1)));
+        assert_eq!(Outward(-f64::from_bits(1)).next_up(), Outward(0.0));
+        assert_eq!(Outward(f64::MAX).next_up(), Outward(f64::INFINITY));
+        assert_eq!(Outward(f64::INFINITY).next_up(), Outward(f64::INFINITY));
+        assert_eq!(Outward(f64::NEG_INFINITY).next_up(), Outward(f64::MIN));
+        assert!(Outward(f64::NAN).next_up().0.is_nan());
+    }
+
+    #[test]
+    pub fn next_down() {
+        assert!(Outward(1.0f64).next_down() < Outward(1.0));
+        assert_eq!(
+            Outward(1.0f64).next_down(),
+            Outward(1.0 - f64::EPSILON / 2.0)
+        );
+        assert_eq!(Outward(0.0f64).next_down(), Outward(-f64::from_bits(1)));
+        assert_eq!(
+            Outward(f64::NEG_INFINITY).next_down(),
+            Outward(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            Outward(1.0f32).next_down(),
+            Outward(1.0 - f32::EPSILON / 2.0)
+        );
+    }
+}
+
+mod test_arithmetic {
+    use crate::{ContinuousRange, Outward, Range};
+
+    fn single(value: f64) -> ContinuousRange<Outward<f64>> {
+        ContinuousRange::Single(Outward(value))
+    }
+
+    #[test]
+    pub fn plain_floats_lose_the_exact_result() {
+        let sum = ContinuousRange::Single(0.1) + ContinuousRange::Single(0.2);
+        assert_eq!(sum, ContinuousRange::Single(0.300_000_000_000_000_04));
+        assert_eq!(sum.contains(0.3), false);
+    }
+
+    #[test]
+    pub fn add() {
+        let sum = single(0.1) + single(0.2);
+        assert!(sum.contains(Outward(0.3)));
+        assert!(sum.contains(Outward(0.1 + 0.2)));
+        assert_eq!(
+            sum,
+            ContinuousRange::Inclusive(Outward(0.3), Outward(0.300_000_000_000_000_1))
+        );
+    }
+
+    #[test]
+    pub fn sub() {
+        let difference = single(0.3) - single(0.1);
+        assert!(difference.contains(Outward(0.2)));
+        assert!(difference.contains(Outward(0.3 - 0.1)));
+    }
+
+    #[test]
+    pub fn mul() {
+        let product = single(0.1) * single(3.0);
+        assert!(product.contains(Outward(0.3)));
+        assert!(product.contains(Outward(0.1 * 3.0)));
+
+        let product = ContinuousRange::Inclusive(Outward(0.0), Outward(1.0)) * single(0.1);
+        assert_eq!(
+            product.range_bounds().map(|(start, _)| start.cloned()),
+            Some(std::ops::Bound::Included(Outward(0.0)))
+        );
+    }
+
+    #[test]
+    pub fn div() {
+        let quotient = single(1.0) / single(3.0);
+        let third = match quotient {
+            Range::Continuous(r) => r,
+            Range::Composite(_) => panic!("Expected a continuous range"),
+        };
+        let one = third.clone() * single(3.0);
+        assert!(one.contains(Outward(1.0)));
+        assert!(third.contains(Outward(1.0 / 3.0)));
+    }
+
+    #[test]
+    pub fn sqrt() {
+        let root = single(2.0).sqrt();
+        let two = root.clone() * root;
+        assert!(two.contains(Outward(2.0)));
+    }
+
+    #[test]
+    pub fn repeated_sum() {
+        let mut sum = single(0.0);
+        for _ in 0..10 {
+            sum = sum + single(0.1);
+        }
+        assert!(sum.contains(Outward(1.0)));
+
+        let mut plain_sum = ContinuousRange::Single(0.0);
+        for _ in 0..10 {
+            plain_sum = plain_sum + ContinuousRange::Single(0.1);
+        }
+        assert_eq!(plain_sum.contains(1.0), false);
+    }
+
+    #[test]
+    pub fn exclusive_and_unbounded() {
+        let sum = ContinuousRange::FromExclusive(Outward(0.1)) + single(0.2);
+        assert!(sum.contains(Outward(0.300_000_000_000_000_1)));
+        assert_eq!(
+            sum.range_bounds().map(|(_, end)| end.cloned()),
+            Some(std::ops::Bound::Unbounded)
+        );
+    }
+}
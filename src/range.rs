@@ -6,7 +6,10 @@ use std::{
     ops::{self, Add, Bound, Sub},
 };
 
-use crate::{ContinuousRange, RangesRelation};
+use crate::{
+    coverage, metric, ContinuousRange, Discrete, Magnitude, Measure, PointRelation, RangeError,
+    RangesRelation,
+};
 
 macro_rules! todo {
     () => {
@@ -169,6 +172,14 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         }
     }
 
+    /// The continuous ranges composing this range
+    pub(crate) fn pieces(&self) -> &[ContinuousRange<Idx>] {
+        match self {
+            Self::Continuous(r) => std::slice::from_ref(r),
+            Self::Composite(v) => v,
+        }
+    }
+
     #[must_use]
     pub fn range_bounds(&self) -> Option<(Bound<&Idx>, Bound<&Idx>)> {
         match self {
@@ -191,6 +202,86 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         }
     }
 
+    /// Get the value contained in the range nearest to `value`
+    ///
+    /// Each part of a composite range is clamped with [`ContinuousRange::clamp`] and the nearest result is
+    /// kept, the lowest one when two are at the same distance. [`None`] is returned if no part has a nearest
+    /// value, or if an excluded bound is nearer than any of the results as the range then has no nearest value.
+    /// Use [`nearest_discrete`][Range::nearest_discrete] for discrete types.
+    ///
+    /// ```text
+    /// [ A ]     [ B ]
+    ///         v         => [
+    /// ```
+    #[must_use]
+    pub fn nearest(&self, value: &Idx) -> Option<Idx>
+    where
+        Idx: Measure,
+    {
+        let (nearest, distance) = self.nearest_with(value, |piece| piece.clamp(value.clone()))?;
+        let excluded_is_nearer = self.pieces().iter().any(|piece| {
+            let bound = match piece.relate_point(value) {
+                Some(PointRelation::Before) => piece.start(),
+                Some(PointRelation::After) => piece.end(),
+                _ => None,
+            };
+            match bound {
+                Some(Bound::Excluded(bound)) => metric::between(bound, value) < distance,
+                _ => false,
+            }
+        });
+
+        if excluded_is_nearer {
+            None
+        } else {
+            Some(nearest)
+        }
+    }
+
+    /// Get the value contained in the range nearest to `value` for a discrete type
+    ///
+    /// Behaves like [`nearest`][Range::nearest] except that each part is clamped with
+    /// [`ContinuousRange::clamp_discrete`], so the value next to an excluded bound can be the result.
+    #[must_use]
+    pub fn nearest_discrete(&self, value: &Idx) -> Option<Idx>
+    where
+        Idx: Measure + Discrete,
+    {
+        self.nearest_with(value, |piece| piece.clamp_discrete(value.clone()))
+            .map(|(nearest, _)| nearest)
+    }
+
+    fn nearest_with(
+        &self,
+        value: &Idx,
+        clamp: impl Fn(&ContinuousRange<Idx>) -> Option<Idx>,
+    ) -> Option<(Idx, Idx::Length)>
+    where
+        Idx: Measure,
+    {
+        let mut nearest: Option<(Idx, Idx::Length)> = None;
+        for piece in self.pieces() {
+            let candidate = match clamp(piece) {
+                Some(candidate) => candidate,
+                None => continue,
+            };
+            let distance = metric::between(&candidate, value);
+
+            let is_nearer = match &nearest {
+                None => true,
+                Some((nearest, nearest_distance)) => {
+                    distance < *nearest_distance
+                        || (distance == *nearest_distance && candidate < *nearest)
+                }
+            };
+            if is_nearer {
+                nearest = Some((candidate, distance));
+            }
+        }
+
+        nearest
+    }
+
     #[must_use]
     pub fn union(&self, other: &Range<Idx>) -> Range<Idx>
     where
@@ -207,14 +298,78 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         }
     }
 
+    /// Compute the values contained in both `self` and `other`
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`try_intersection`][Range::try_intersection] for a version returning an error instead.
     #[must_use]
-    pub fn intersection(self, _other: &Range<Idx>) -> Range<Idx> {
-        todo!()
+    pub fn intersection(self, other: &Range<Idx>) -> Range<Idx> {
+        self.try_intersection(other)
+            .expect("PartialOrd contract isn't correctly implemented")
     }
 
+    /// Compute the values contained in both `self` and `other`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bounds of the ranges can't be compared or if the [`PartialOrd`] contract isn't
+    /// respected.
+    pub fn try_intersection(self, other: &Range<Idx>) -> Result<Range<Idx>, RangeError> {
+        let covered = coverage::covered(self.pieces())?;
+        let other = coverage::covered(other.pieces())?;
+
+        Ok(Range::composite(
+            coverage::intersect_disjoint(&covered, &other)?
+                .into_iter()
+                .map(Range::Continuous),
+        ))
+    }
+
+    /// Compute the values contained in `self` but not in `other`
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`try_difference`][Range::try_difference] for a version returning an error instead.
     #[must_use]
-    pub fn difference(self, _other: &Range<Idx>) -> Range<Idx> {
-        todo!()
+    pub fn difference(self, other: &Range<Idx>) -> Range<Idx> {
+        self.try_difference(other)
+            .expect("PartialOrd contract isn't correctly implemented")
+    }
+
+    /// Compute the values contained in `self` but not in `other`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bounds of the ranges can't be compared or if the [`PartialOrd`] contract isn't
+    /// respected.
+    pub fn try_difference(self, other: &Range<Idx>) -> Result<Range<Idx>, RangeError> {
+        let covered = coverage::covered(self.pieces())?;
+        let free = coverage::uncovered(other.pieces())?;
+
+        Ok(Range::composite(
+            coverage::intersect_disjoint(&covered, &free)?
+                .into_iter()
+                .map(Range::Continuous),
+        ))
+    }
+
+    /// Find the parts of `window` that aren't contained in this range
+    ///
+    /// ```text
+    /// window: [                 ]
+    /// self:     [ A ]   [ B ]
+    /// result: [ ]     [ ]     [ ]
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn gaps_within(&self, window: &ContinuousRange<Idx>) -> Range<Idx> {
+        Range::Continuous(window.clone()).difference(self)
     }
 
     #[must_use]
@@ -229,6 +384,14 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         todo!()
     }
 
+    /// Simplify the representation of the range
+    ///
+    /// The parts of a composite range are sorted, empty parts are removed and the parts that overlap or are
+    /// adjacent are merged. A composite range left with a single part becomes a [`Range::Continuous`] one.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
     pub fn simplify_mut(&mut self)
     where
         Idx: PartialOrd,
@@ -236,17 +399,40 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         match self {
             Self::Continuous(r) => r.simplify_mut(),
             Self::Composite(v) => {
-                // TODO: Handle much more cases
-                // - Empty ranges
-                // - Full ranges
-                // - Ranges with only one element
-                // - Merge overlapping ranges
-                // - ...
-                for item in &mut *v {
-                    item.simplify_mut();
+                let merged = coverage::covered(v.iter())
+                    .expect("PartialOrd contract isn't correctly implemented");
+                *self = Range::composite(merged.into_iter().map(Range::Continuous));
+            }
+        }
+    }
+
+    /// Simplify the representation of the range like [`simplify`][Range::simplify], also merging the parts
+    /// separated by at most `tolerance`
+    ///
+    /// The gaps between merged parts are part of the result.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn simplify_with_tolerance(&self, tolerance: &Idx::Length) -> Self
+    where
+        Idx: Measure,
+    {
+        let mut merged: Vec<ContinuousRange<Idx>> = Vec::new();
+        for piece in coverage::covered(self.pieces())
+            .expect("PartialOrd contract isn't correctly implemented")
+        {
+            if let Some(last) = merged.last_mut() {
+                if let Some(hull) = last.union_with_tolerance(&piece, tolerance) {
+                    *last = hull;
+                    continue;
                 }
             }
+            merged.push(piece);
         }
+
+        Range::composite(merged.into_iter().map(Range::Continuous))
     }
 
     #[must_use]
@@ -267,6 +453,46 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
         }
     }
 
+    /// Get the total length of the values contained in the range or [`None`] if it's unbounded
+    ///
+    /// Overlapping parts of a composite range are only measured once. [`None`] is also returned if the total
+    /// length can't be represented.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn measure(&self) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+    {
+        coverage::covered(self.pieces())
+            .expect("PartialOrd contract isn't correctly implemented")
+            .iter()
+            .try_fold(Idx::Length::zero(), |total, piece| {
+                total.checked_sum(&piece.length()?)
+            })
+    }
+
+    /// Count the number of values contained in the range or [`None`] if it's unbounded
+    ///
+    /// Values contained in multiple parts of a composite range are only counted once. [`None`] is also returned
+    /// if the count doesn't fit in a [`u128`].
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn count(&self) -> Option<u128>
+    where
+        Idx: Discrete,
+    {
+        coverage::covered(self.pieces())
+            .expect("PartialOrd contract isn't correctly implemented")
+            .iter()
+            .try_fold(0u128, |total, piece| total.checked_add(piece.count()?))
+    }
+
     #[must_use]
     pub fn is_full(&self) -> bool
     where
@@ -281,6 +507,73 @@ impl<Idx: PartialOrd + Clone> Range<Idx> {
     }
 }
 
+impl<Idx> Range<Idx> {
+    /// Convert the bounds of each part of the range with a monotonically increasing function, see
+    /// [`ContinuousRange::map_bounds`]
+    ///
+    /// The result is rebuilt with [`Range::composite`], dropping the parts that become empty.
+    #[must_use]
+    pub fn map_bounds<T>(self, mut f: impl FnMut(Idx) -> T) -> Range<T>
+    where
+        T: PartialOrd + Clone,
+    {
+        match self {
+            Range::Continuous(range) => Range::Continuous(range.map_bounds(f)),
+            Range::Composite(pieces) => Range::composite(
+                pieces
+                    .into_iter()
+                    .map(|piece| Range::Continuous(piece.map_bounds(&mut f)))
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    /// Convert the bounds of each part of the range with a monotonically decreasing function, see
+    /// [`ContinuousRange::map_bounds_decreasing`]
+    ///
+    /// The order of the parts is reversed so that a sorted range stays sorted. The result is rebuilt with
+    /// [`Range::composite`], dropping the parts that become empty.
+    #[must_use]
+    pub fn map_bounds_decreasing<T>(self, mut f: impl FnMut(Idx) -> T) -> Range<T>
+    where
+        T: PartialOrd + Clone,
+    {
+        match self {
+            Range::Continuous(range) => Range::Continuous(range.map_bounds_decreasing(f)),
+            Range::Composite(pieces) => Range::composite(
+                pieces
+                    .into_iter()
+                    .rev()
+                    .map(|piece| Range::Continuous(piece.map_bounds_decreasing(&mut f)))
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    /// Convert the bounds of each part of the range with a fallible monotonically increasing function, see
+    /// [`ContinuousRange::try_map_bounds`]
+    ///
+    /// The result is rebuilt with [`Range::composite`], dropping the parts that become empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`.
+    pub fn try_map_bounds<T, E>(self, mut f: impl FnMut(Idx) -> Result<T, E>) -> Result<Range<T>, E>
+    where
+        T: PartialOrd + Clone,
+    {
+        Ok(match self {
+            Range::Continuous(range) => Range::Continuous(range.try_map_bounds(f)?),
+            Range::Composite(pieces) => Range::composite(
+                pieces
+                    .into_iter()
+                    .map(|piece| piece.try_map_bounds(&mut f).map(Range::Continuous))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        })
+    }
+}
+
 impl<Idx: PartialOrd + Clone> Add<&Range<Idx>> for Range<Idx> {
     type Output = Range<Idx>;
 
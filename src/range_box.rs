@@ -0,0 +1,226 @@
+use std::{fmt, ops::Mul};
+
+use crate::{ContinuousRange, Magnitude, Measure, Range};
+
+/// A box in `N` dimensions, the product of one [`ContinuousRange`] per axis
+///
+/// A box contains the points that have each of their coordinates in the range of the corresponding axis, it's
+/// empty as soon as one of its axes is.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, RangeBox};
+///
+/// // Prices in cents by hour of the day
+/// let off_peak = RangeBox::new([ContinuousRange::EndExclusive(0, 6), ContinuousRange::To(500)]);
+/// assert!(off_peak.contains(&[3, 250]));
+/// assert!(!off_peak.contains(&[8, 250]));
+/// ```
+#[derive(Clone, Hash, PartialEq, Debug)]
+pub struct RangeBox<const N: usize, Idx> {
+    axes: [ContinuousRange<Idx>; N],
+}
+
+impl<const N: usize, Idx> From<[ContinuousRange<Idx>; N]> for RangeBox<N, Idx> {
+    fn from(axes: [ContinuousRange<Idx>; N]) -> Self {
+        RangeBox { axes }
+    }
+}
+
+impl<const N: usize, Idx> RangeBox<N, Idx> {
+    /// A box with the range of each axis
+    #[must_use]
+    pub fn new(axes: [ContinuousRange<Idx>; N]) -> Self {
+        RangeBox { axes }
+    }
+
+    /// A box that doesn't contain any point
+    #[must_use]
+    pub fn empty() -> Self {
+        RangeBox {
+            axes: std::array::from_fn(|_| ContinuousRange::Empty),
+        }
+    }
+
+    /// A box that contains all the points
+    #[must_use]
+    pub fn full() -> Self {
+        RangeBox {
+            axes: std::array::from_fn(|_| ContinuousRange::Full),
+        }
+    }
+
+    /// The ranges of the axes
+    #[must_use]
+    pub fn axes(&self) -> &[ContinuousRange<Idx>; N] {
+        &self.axes
+    }
+
+    /// The range of the axis `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't lower than `N`.
+    #[must_use]
+    pub fn axis(&self, index: usize) -> &ContinuousRange<Idx> {
+        &self.axes[index]
+    }
+
+    /// The ranges of the axes
+    #[must_use]
+    pub fn into_axes(self) -> [ContinuousRange<Idx>; N] {
+        self.axes
+    }
+}
+
+impl<const N: usize, Idx: PartialOrd + Clone> RangeBox<N, Idx> {
+    /// Returns true if the box doesn't contain any point
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(ContinuousRange::is_empty)
+    }
+
+    /// Returns true if each coordinate of `point` is contained in the range of its axis
+    #[must_use]
+    pub fn contains(&self, point: &[Idx; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point.iter())
+            .all(|(axis, value)| axis.contains(value))
+    }
+
+    /// Returns true if all the points of `other` are contained in this box
+    #[must_use]
+    pub fn contains_box(&self, other: &RangeBox<N, Idx>) -> bool
+    where
+        Idx: fmt::Debug,
+    {
+        other.is_empty()
+            || self
+                .axes
+                .iter()
+                .zip(other.axes.iter())
+                .all(|(axis, other)| axis.contains_range(other))
+    }
+
+    /// Returns true if the boxes have at least one point in common
+    #[must_use]
+    pub fn intersects(&self, other: &RangeBox<N, Idx>) -> bool
+    where
+        Idx: fmt::Debug,
+    {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The points contained in both boxes
+    ///
+    /// The result is [`RangeBox::empty`] if the boxes don't intersect.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`ContinuousRange::intersection`].
+    #[must_use]
+    pub fn intersection(&self, other: &RangeBox<N, Idx>) -> RangeBox<N, Idx>
+    where
+        Idx: fmt::Debug,
+    {
+        let mut axes = self.axes.clone();
+        for (axis, other) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(other);
+            if axis.is_empty() {
+                return RangeBox::empty();
+            }
+        }
+        RangeBox { axes }
+    }
+
+    /// The smallest box containing both boxes
+    ///
+    /// ```
+    /// use range_ranger::{ContinuousRange, RangeBox};
+    ///
+    /// let a = RangeBox::new([ContinuousRange::EndExclusive(0, 5), ContinuousRange::Inclusive(0, 5)]);
+    /// let b = RangeBox::new([ContinuousRange::Inclusive(3, 8), ContinuousRange::To(2)]);
+    /// assert_eq!(
+    ///     a.hull(&b),
+    ///     RangeBox::new([ContinuousRange::Inclusive(0, 8), ContinuousRange::To(5)])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn hull(&self, other: &RangeBox<N, Idx>) -> RangeBox<N, Idx> {
+        if self.is_empty() {
+            return other.clone();
+        }
+        if other.is_empty() {
+            return self.clone();
+        }
+
+        let mut axes = self.axes.clone();
+        for (axis, other) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.hull(other).unwrap_or_else(|| other.clone());
+        }
+        RangeBox { axes }
+    }
+
+    /// The points contained in `self` but not in `other`, as disjoint boxes
+    ///
+    /// The box is cut along each axis in turn, producing at most two boxes per axis.
+    ///
+    /// ```text
+    /// +-----------+
+    /// |     1     |
+    /// +---+---+---+
+    /// | 2 | B | 3 |
+    /// +---+---+---+
+    /// |     4     |
+    /// +-----------+
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn difference(&self, other: &RangeBox<N, Idx>) -> Vec<RangeBox<N, Idx>>
+    where
+        Idx: fmt::Debug,
+    {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        if !self.intersects(other) {
+            return vec![self.clone()];
+        }
+
+        let mut boxes = Vec::new();
+        let mut rest = self.axes.clone();
+        for index in 0..N {
+            let outside = Range::Continuous(rest[index].clone())
+                .difference(&Range::Continuous(other.axes[index].clone()));
+            for part in outside.pieces().iter().filter(|part| !part.is_empty()) {
+                let mut axes = rest.clone();
+                axes[index] = part.clone();
+                boxes.push(RangeBox { axes });
+            }
+            rest[index] = rest[index].intersection(&other.axes[index]);
+        }
+        boxes
+    }
+
+    /// The product of the lengths of the axes or [`None`] if one of them is unbounded
+    ///
+    /// The volume of an empty box is zero. [`None`] is also returned for boxes without any axis.
+    #[must_use]
+    pub fn volume(&self) -> Option<Idx::Length>
+    where
+        Idx: Measure,
+        Idx::Length: Mul<Output = Idx::Length>,
+    {
+        if self.is_empty() {
+            return Some(Idx::Length::zero());
+        }
+
+        let mut lengths = self.axes.iter().map(ContinuousRange::length);
+        let first = lengths.next()??;
+        lengths.try_fold(first, |volume, length| Some(volume * length?))
+    }
+}
@@ -0,0 +1,240 @@
+mod test_contains {
+    use crate::{ContinuousRange, RangeBox};
+
+    #[test]
+    pub fn points() {
+        let range_box = RangeBox::new([
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::EndExclusive(5, 8),
+        ]);
+        assert!(range_box.contains(&[0, 5]));
+        assert!(range_box.contains(&[10, 7]));
+        assert!(!range_box.contains(&[10, 8]));
+        assert!(!range_box.contains(&[11, 6]));
+    }
+
+    #[test]
+    pub fn boxes() {
+        let range_box =
+            RangeBox::new([ContinuousRange::Inclusive(0, 10), ContinuousRange::From(0)]);
+        assert!(range_box.contains_box(&RangeBox::new([
+            ContinuousRange::Inclusive(2, 3),
+            ContinuousRange::Inclusive(100, 200),
+        ])));
+        assert!(!range_box.contains_box(&RangeBox::new([
+            ContinuousRange::Inclusive(2, 3),
+            ContinuousRange::Inclusive(-1, 200),
+        ])));
+        assert!(range_box.contains_box(&RangeBox::empty()));
+        assert!(RangeBox::full().contains_box(&range_box));
+    }
+
+    #[test]
+    pub fn empty() {
+        let range_box = RangeBox::new([ContinuousRange::Inclusive(0, 10), ContinuousRange::Empty]);
+        assert!(range_box.is_empty());
+        assert!(!range_box.contains(&[5, 5]));
+        assert!(RangeBox::<2, i32>::empty().is_empty());
+        assert!(!RangeBox::<2, i32>::full().is_empty());
+        assert!(!RangeBox::<0, i32>::new([]).is_empty());
+    }
+}
+
+mod test_intersection {
+    use crate::{ContinuousRange, RangeBox};
+
+    #[test]
+    pub fn overlapping() {
+        let a = RangeBox::new([
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(0, 10),
+        ]);
+        let b = RangeBox::new([ContinuousRange::From(5), ContinuousRange::Exclusive(-5, 5)]);
+        assert_eq!(
+            a.intersection(&b),
+            RangeBox::new([
+                ContinuousRange::Inclusive(5, 10),
+                ContinuousRange::EndExclusive(0, 5),
+            ])
+        );
+        assert!(a.intersects(&b));
+    }
+
+    #[test]
+    pub fn disjoint() {
+        let a = RangeBox::new([
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(0, 10),
+        ]);
+        let b = RangeBox::new([
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::StartExclusive(10, 20),
+        ]);
+        assert_eq!(a.intersection(&b), RangeBox::empty());
+        assert!(!a.intersects(&b));
+    }
+}
+
+mod test_difference {
+    use crate::{ContinuousRange, RangeBox};
+
+    #[test]
+    pub fn hole() {
+        let a = RangeBox::new([
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(0, 10),
+        ]);
+        let b = RangeBox::new([
+            ContinuousRange::Inclusive(3, 6),
+            ContinuousRange::Inclusive(3, 6),
+        ]);
+        assert_eq!(
+            a.difference(&b),
+            vec![
+                RangeBox::new([
+                    ContinuousRange::EndExclusive(0, 3),
+                    ContinuousRange::Inclusive(0, 10),
+                ]),
+                RangeBox::new([
+                    ContinuousRange::StartExclusive(6, 10),
+                    ContinuousRange::Inclusive(0, 10),
+                ]),
+                RangeBox::new([
+                    ContinuousRange::Inclusive(3, 6),
+                    ContinuousRange::EndExclusive(0, 3),
+                ]),
+                RangeBox::new([
+                    ContinuousRange::Inclusive(3, 6),
+                    ContinuousRange::StartExclusive(6, 10),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn corner() {
+        let a = RangeBox::new([
+            ContinuousRange::EndExclusive(0.0, 2.0),
+            ContinuousRange::EndExclusive(0.0, 2.0),
+        ]);
+        let b = RangeBox::new([ContinuousRange::From(1.0), ContinuousRange::From(1.0)]);
+        let difference = a.difference(&b);
+        assert_eq!(
+            difference,
+            vec![
+                RangeBox::new([
+                    ContinuousRange::EndExclusive(0.0, 1.0),
+                    ContinuousRange::EndExclusive(0.0, 2.0),
+                ]),
+                RangeBox::new([
+                    ContinuousRange::EndExclusive(1.0, 2.0),
+                    ContinuousRange::EndExclusive(0.0, 1.0),
+                ]),
+            ]
+        );
+
+        let total: f64 = difference
+            .iter()
+            .map(|range_box| range_box.volume().unwrap())
+            .sum();
+        let expected: f64 = a.volume().unwrap() - a.intersection(&b).volume().unwrap();
+        assert!((total - expected).abs() < f64::EPSILON);
+        for (index, first) in difference.iter().enumerate() {
+            for second in &difference[index + 1..] {
+                assert!(!first.intersects(second));
+            }
+        }
+    }
+
+    #[test]
+    pub fn disjoint_or_covering() {
+        let a = RangeBox::new([
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(0, 10),
+        ]);
+        let b = RangeBox::new([
+            ContinuousRange::Inclusive(20, 30),
+            ContinuousRange::Inclusive(0, 10),
+        ]);
+        assert_eq!(a.difference(&b), vec![a.clone()]);
+        assert_eq!(a.difference(&RangeBox::full()), vec![]);
+        assert_eq!(RangeBox::empty().difference(&a), vec![]);
+        assert_eq!(a.difference(&RangeBox::empty()), vec![a.clone()]);
+    }
+
+    #[test]
+    pub fn three_dimensions() {
+        let a = RangeBox::new([
+            ContinuousRange::EndExclusive(0, 4),
+            ContinuousRange::EndExclusive(0, 4),
+            ContinuousRange::EndExclusive(0, 4),
+        ]);
+        let b = RangeBox::new([
+            ContinuousRange::EndExclusive(1, 3),
+            ContinuousRange::EndExclusive(1, 3),
+            ContinuousRange::EndExclusive(1, 3),
+        ]);
+        let difference = a.difference(&b);
+        assert_eq!(difference.len(), 6);
+        let total: u32 = difference
+            .iter()
+            .map(|range_box| range_box.volume().unwrap())
+            .sum();
+        assert_eq!(total, 64 - 8);
+    }
+}
+
+mod test_volume {
+    use crate::{ContinuousRange, RangeBox};
+
+    #[test]
+    pub fn bounded() {
+        let range_box = RangeBox::new([
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::EndExclusive(5, 8),
+        ]);
+        assert_eq!(range_box.volume(), Some(30u32));
+    }
+
+    #[test]
+    pub fn empty_or_unbounded() {
+        assert_eq!(
+            RangeBox::new([ContinuousRange::From(0), ContinuousRange::Empty]).volume(),
+            Some(0u32)
+        );
+        assert_eq!(
+            RangeBox::new([ContinuousRange::From(0), ContinuousRange::Inclusive(0, 1)]).volume(),
+            None
+        );
+        assert_eq!(RangeBox::<0, i32>::new([]).volume(), None);
+    }
+}
+
+mod test_hull {
+    use crate::{ContinuousRange, RangeBox};
+
+    #[test]
+    pub fn keeps_outer_bounds() {
+        let a = RangeBox::new([
+            ContinuousRange::Exclusive(0, 5),
+            ContinuousRange::Inclusive(0, 5),
+        ]);
+        let b = RangeBox::new([ContinuousRange::Inclusive(0, 5), ContinuousRange::From(3)]);
+        assert_eq!(
+            a.hull(&b),
+            RangeBox::new([ContinuousRange::Inclusive(0, 5), ContinuousRange::From(0)])
+        );
+        assert_eq!(a.hull(&b), b.hull(&a));
+    }
+
+    #[test]
+    pub fn empty() {
+        let a = RangeBox::new([
+            ContinuousRange::Exclusive(0, 5),
+            ContinuousRange::Inclusive(0, 5),
+        ]);
+        let empty = RangeBox::new([ContinuousRange::Inclusive(10, 20), ContinuousRange::Empty]);
+        assert_eq!(a.hull(&empty), a);
+        assert_eq!(empty.hull(&a), a);
+    }
+}
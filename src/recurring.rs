@@ -0,0 +1,287 @@
+use std::{convert::TryFrom, fmt, ops::Bound};
+
+use crate::{continuous::bound_value, ContinuousRange, Magnitude, Measure, Range};
+
+/// The value `count` periods after `value`, before it if `count` is negative, or [`None`] if it can't be
+/// represented
+fn offset<Idx: Measure>(value: &Idx, period: &Idx::Length, count: i64) -> Option<Idx> {
+    let distance = period.times(count.unsigned_abs())?;
+    if count < 0 {
+        value.retreat(&distance)
+    } else {
+        value.advance(&distance)
+    }
+}
+
+/// The number of whole periods from `start` to `end` rounded toward negative infinity, negative if `end` is
+/// before `start`, or [`None`] if it doesn't fit in an [`i64`]
+fn periods<Idx: Measure>(start: &Idx, end: &Idx, period: &Idx::Length) -> Option<i64> {
+    if start <= end {
+        return i64::try_from(Idx::measure(start, end).quotient(period)?).ok();
+    }
+
+    let distance = Idx::measure(end, start);
+    let mut periods = distance.quotient(period)?;
+    if period.times(periods)? < distance {
+        periods = periods.checked_add(1)?;
+    }
+    i64::try_from(periods).ok().map(|periods| -periods)
+}
+
+/// A range repeated indefinitely every period, like business hours or maintenance windows
+///
+/// Each occurrence is the base range moved by a whole number of periods, in both directions. Values of the
+/// exceptions are removed from all the occurrences.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, Range, RecurringRange};
+///
+/// // Every day from 9 to 17, with hours as the index
+/// let hours = RecurringRange::new(ContinuousRange::EndExclusive(9, 17), 24u32).unwrap();
+/// assert!(hours.contains(&(24 * 3 + 10)));
+/// assert!(!hours.contains(&(24 * 3 + 17)));
+///
+/// let hours = hours.except(Range::Continuous(ContinuousRange::EndExclusive(24, 48)));
+/// assert_eq!(
+///     hours.occurrences_within(&ContinuousRange::Inclusive(0, 72)),
+///     Some(Range::Composite(vec![
+///         ContinuousRange::EndExclusive(9, 17),
+///         ContinuousRange::EndExclusive(57, 65),
+///     ]))
+/// );
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct RecurringRange<Idx: Measure> {
+    base: ContinuousRange<Idx>,
+    period: Idx::Length,
+    exceptions: Range<Idx>,
+}
+
+impl<Idx: Measure> RecurringRange<Idx> {
+    /// A range repeating `base` every `period`, without exceptions
+    ///
+    /// Returns [`None`] if `base` is empty or unbounded, if `period` isn't positive or if `base` is longer than
+    /// `period`, as occurrences would overlap. A base including both of its bounds must also be shorter than
+    /// `period`, otherwise the end of each occurrence would be the start of the next one.
+    #[must_use]
+    pub fn new(base: ContinuousRange<Idx>, period: Idx::Length) -> Option<Self> {
+        if base.is_empty() || period <= Idx::Length::zero() {
+            return None;
+        }
+
+        let length = base.length()?;
+        let closed = matches!(
+            base.range_bounds()?,
+            (Bound::Included(_), Bound::Included(_))
+        );
+        if length > period || (closed && length >= period) {
+            return None;
+        }
+
+        Some(RecurringRange {
+            base,
+            period,
+            exceptions: Range::empty(),
+        })
+    }
+
+    /// Remove the values of `exceptions` from all the occurrences, in addition to the existing exceptions
+    #[must_use]
+    pub fn except(self, exceptions: Range<Idx>) -> Self {
+        RecurringRange {
+            exceptions: Range::composite(vec![self.exceptions, exceptions]).simplify(),
+            ..self
+        }
+    }
+
+    /// The occurrence that doesn't need to be moved
+    #[must_use]
+    pub fn base(&self) -> &ContinuousRange<Idx> {
+        &self.base
+    }
+
+    /// The distance between the starts of two consecutive occurrences
+    #[must_use]
+    pub fn period(&self) -> &Idx::Length {
+        &self.period
+    }
+
+    /// The values removed from all the occurrences
+    #[must_use]
+    pub fn exceptions(&self) -> &Range<Idx> {
+        &self.exceptions
+    }
+
+    /// Check if `value` is part of one of the occurrences
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn contains(&self, value: &Idx) -> bool {
+        if self.exceptions.contains(value) {
+            return false;
+        }
+
+        // Only the occurrence starting just before the value or the one before it can contain it
+        let index = match self.index_before(value) {
+            Some(index) => index,
+            None => return false,
+        };
+        [index.checked_sub(1), Some(index)]
+            .iter()
+            .filter_map(|index| self.occurrence((*index)?))
+            .any(|occurrence| occurrence.contains(value))
+    }
+
+    /// The parts of the occurrences contained in `window`
+    ///
+    /// Returns [`None`] if `window` is unbounded, as it would contain an infinite number of occurrences, or if
+    /// the occurrences can't be represented by the index type.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn occurrences_within(&self, window: &ContinuousRange<Idx>) -> Option<Range<Idx>> {
+        if window.is_empty() {
+            return Some(Range::empty());
+        }
+
+        let first = self
+            .index_before(window.start().and_then(bound_value)?)?
+            .checked_sub(1)?;
+        let last = self.index_before(window.end().and_then(bound_value)?)?;
+        let mut occurrences = Vec::new();
+        for index in first..=last {
+            occurrences.push(Range::Continuous(self.occurrence(index)?));
+        }
+
+        Some(
+            Range::composite(occurrences)
+                .intersection(&Range::Continuous(window.clone()))
+                .difference(&self.exceptions),
+        )
+    }
+
+    /// The parts of the occurrences contained in `range`
+    ///
+    /// Returns [`None`] if `range` is unbounded, see [`RecurringRange::occurrences_within`].
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn intersection(&self, range: &Range<Idx>) -> Option<Range<Idx>> {
+        let mut parts = Vec::new();
+        for piece in range.pieces() {
+            parts.push(self.occurrences_within(piece)?);
+        }
+
+        Some(Range::composite(parts).simplify())
+    }
+
+    /// The first occurrence starting at or after `value`, without its exceptions
+    ///
+    /// Occurrences entirely removed by the exceptions are skipped. The start considered is the one of the
+    /// occurrence before removing the exceptions. Returns [`None`] if there is no such occurrence.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn next_occurrence(&self, value: &Idx) -> Option<Range<Idx>> {
+        let mut index = self.index_before(value)?;
+        loop {
+            let occurrence = self.occurrence(index)?;
+            let start = occurrence.start().and_then(bound_value)?;
+            let mut next = index;
+            if start >= value {
+                let remaining = Range::Continuous(occurrence.clone()).difference(&self.exceptions);
+                if !remaining.is_empty() {
+                    return Some(remaining);
+                }
+
+                // Skip the occurrences starting before the end of the exception, none are left if it's unbounded
+                let exception = self.exception_intersecting(&occurrence)?;
+                let exception_end = exception.end().and_then(bound_value)?;
+                next = self.index_before(exception_end)?;
+            }
+            index = next.max(index.checked_add(1)?);
+        }
+    }
+
+    /// The last occurrence ending at or before `value`, without its exceptions
+    ///
+    /// Occurrences entirely removed by the exceptions are skipped. The end considered is the one of the
+    /// occurrence before removing the exceptions. Returns [`None`] if there is no such occurrence.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn previous_occurrence(&self, value: &Idx) -> Option<Range<Idx>> {
+        let mut index = self.index_before(value)?;
+        loop {
+            let occurrence = self.occurrence(index)?;
+            let end = occurrence.end().and_then(bound_value)?;
+            let mut previous = index;
+            if end <= value {
+                let remaining = Range::Continuous(occurrence.clone()).difference(&self.exceptions);
+                if !remaining.is_empty() {
+                    return Some(remaining);
+                }
+
+                // Skip the occurrences starting after the start of the exception, none are left if it's unbounded
+                let exception = self.exception_intersecting(&occurrence)?;
+                let exception_start = exception.start().and_then(bound_value)?;
+                previous = self.index_before(exception_start)?;
+            }
+            index = previous.min(index.checked_sub(1)?);
+        }
+    }
+
+    /// The first part of the exceptions intersecting `occurrence`
+    fn exception_intersecting(
+        &self,
+        occurrence: &ContinuousRange<Idx>,
+    ) -> Option<&ContinuousRange<Idx>> {
+        self.exceptions.pieces().iter().find(|exception| {
+            exception
+                .try_compare(occurrence)
+                .expect("PartialOrd contract isn't correctly implemented")
+                .map_or(false, |relation| relation.intersects())
+        })
+    }
+
+    /// The base range moved by `index` periods
+    fn occurrence(&self, index: i64) -> Option<ContinuousRange<Idx>> {
+        self.base
+            .clone()
+            .try_map_bounds(|value| offset(&value, &self.period, index).ok_or(()))
+            .ok()
+    }
+
+    /// The index of the last occurrence starting at or before `value`
+    fn index_before(&self, value: &Idx) -> Option<i64> {
+        periods(
+            self.base.start().and_then(bound_value)?,
+            value,
+            &self.period,
+        )
+    }
+}
+
+impl<Idx> fmt::Debug for RecurringRange<Idx>
+where
+    Idx: Measure + fmt::Debug,
+    Idx::Length: fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("RecurringRange")
+            .field("base", &self.base)
+            .field("period", &self.period)
+            .field("exceptions", &self.exceptions)
+            .finish()
+    }
+}
@@ -0,0 +1,269 @@
+mod test_new {
+    use crate::{ContinuousRange, RecurringRange};
+
+    #[test]
+    pub fn valid() {
+        let recurring = RecurringRange::new(ContinuousRange::EndExclusive(0, 10), 10u32).unwrap();
+        assert_eq!(recurring.base(), &ContinuousRange::EndExclusive(0, 10));
+        assert_eq!(recurring.period(), &10u32);
+        assert!(recurring.exceptions().is_empty());
+    }
+
+    #[test]
+    pub fn as_long_as_period() {
+        for base in [
+            ContinuousRange::EndExclusive(0, 10),
+            ContinuousRange::StartExclusive(0, 10),
+            ContinuousRange::Exclusive(0, 10),
+        ] {
+            assert!(RecurringRange::new(base, 10u32).is_some());
+        }
+        assert!(RecurringRange::new(ContinuousRange::Inclusive(0, 9), 10u32).is_some());
+        assert!(RecurringRange::new(ContinuousRange::Single(0), 1u32).is_some());
+        assert_eq!(
+            RecurringRange::new(ContinuousRange::Inclusive(0.0, 1.0), 1.0),
+            None
+        );
+    }
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(
+            RecurringRange::new(ContinuousRange::Inclusive(0, 10), 0u32),
+            None
+        );
+        assert_eq!(
+            RecurringRange::new(ContinuousRange::Inclusive(0.0, 10.0), -5.0),
+            None
+        );
+        assert_eq!(
+            RecurringRange::new(ContinuousRange::Inclusive(0, 11), 10u32),
+            None
+        );
+        assert_eq!(
+            RecurringRange::new(ContinuousRange::Inclusive(0, 10), 10u32),
+            None
+        );
+        assert_eq!(RecurringRange::new(ContinuousRange::From(0), 10u32), None);
+        assert_eq!(RecurringRange::new(ContinuousRange::<i32>::Empty, 10), None);
+    }
+}
+
+mod test_contains {
+    use crate::{ContinuousRange, Range, RecurringRange};
+
+    #[test]
+    pub fn periodic() {
+        let recurring = RecurringRange::new(ContinuousRange::EndExclusive(2, 4), 10u32).unwrap();
+        assert!(recurring.contains(&2));
+        assert!(recurring.contains(&13));
+        assert!(recurring.contains(&-8));
+        assert!(!recurring.contains(&4));
+        assert!(!recurring.contains(&-10));
+        assert!(!recurring.contains(&1_000_000));
+    }
+
+    #[test]
+    pub fn touching_occurrences() {
+        let recurring = RecurringRange::new(ContinuousRange::EndExclusive(0.0, 1.0), 1.0).unwrap();
+        assert!(recurring.contains(&-3.5));
+        assert!(recurring.contains(&7.0));
+    }
+
+    #[test]
+    pub fn exceptions() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32)
+            .unwrap()
+            .except(Range::Continuous(ContinuousRange::Inclusive(10, 12)));
+        assert!(recurring.contains(&13));
+        assert!(!recurring.contains(&12));
+        assert!(recurring.contains(&2));
+    }
+}
+
+mod test_occurrences {
+    use crate::{ContinuousRange, Range, RecurringRange};
+
+    #[test]
+    pub fn within() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32).unwrap();
+        assert_eq!(
+            recurring.occurrences_within(&ContinuousRange::Inclusive(3, 22)),
+            Some(Range::Composite(vec![
+                ContinuousRange::Inclusive(3, 5),
+                ContinuousRange::Inclusive(10, 15),
+                ContinuousRange::Inclusive(20, 22),
+            ]))
+        );
+        assert_eq!(
+            recurring.occurrences_within(&ContinuousRange::Exclusive(6, 9)),
+            Some(Range::empty())
+        );
+        assert_eq!(
+            recurring.occurrences_within(&ContinuousRange::From(0)),
+            None
+        );
+    }
+
+    #[test]
+    pub fn intersection() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32)
+            .unwrap()
+            .except(Range::Continuous(ContinuousRange::Single(12)));
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(-12, -7),
+            ContinuousRange::Inclusive(11, 14),
+        ]);
+        assert_eq!(
+            recurring.intersection(&range),
+            Some(Range::Composite(vec![
+                ContinuousRange::Inclusive(-10, -7),
+                ContinuousRange::EndExclusive(11, 12),
+                ContinuousRange::StartExclusive(12, 14),
+            ]))
+        );
+    }
+}
+
+mod test_next_previous {
+    use crate::{ContinuousRange, Range, RecurringRange};
+
+    #[test]
+    pub fn next() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32).unwrap();
+        assert_eq!(
+            recurring.next_occurrence(&0),
+            Some(Range::Continuous(ContinuousRange::Inclusive(0, 5)))
+        );
+        assert_eq!(
+            recurring.next_occurrence(&1),
+            Some(Range::Continuous(ContinuousRange::Inclusive(10, 15)))
+        );
+        assert_eq!(
+            recurring.next_occurrence(&-25),
+            Some(Range::Continuous(ContinuousRange::Inclusive(-20, -15)))
+        );
+    }
+
+    #[test]
+    pub fn previous() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32).unwrap();
+        assert_eq!(
+            recurring.previous_occurrence(&5),
+            Some(Range::Continuous(ContinuousRange::Inclusive(0, 5)))
+        );
+        assert_eq!(
+            recurring.previous_occurrence(&4),
+            Some(Range::Continuous(ContinuousRange::Inclusive(-10, -5)))
+        );
+    }
+
+    #[test]
+    pub fn skip_exceptions() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32)
+            .unwrap()
+            .except(Range::Composite(vec![
+                ContinuousRange::Inclusive(10, 25),
+                ContinuousRange::Inclusive(30, 31),
+            ]));
+        assert_eq!(
+            recurring.next_occurrence(&1),
+            Some(Range::Continuous(ContinuousRange::StartExclusive(31, 35)))
+        );
+        assert_eq!(
+            recurring.previous_occurrence(&30),
+            Some(Range::Continuous(ContinuousRange::Inclusive(0, 5)))
+        );
+    }
+
+    #[test]
+    pub fn many_exceptions() {
+        // Every occurrence is removed except one out of 100_000
+        let exceptions = (0..1_000)
+            .map(|index| {
+                let start = index * 1_000_000;
+                Range::Continuous(ContinuousRange::EndExclusive(start + 10, start + 1_000_000))
+            })
+            .collect::<Vec<_>>();
+        let recurring = RecurringRange::new(ContinuousRange::EndExclusive(0i64, 5), 10u64)
+            .unwrap()
+            .except(Range::composite(exceptions));
+        assert_eq!(
+            recurring.next_occurrence(&1),
+            Some(Range::Continuous(ContinuousRange::EndExclusive(
+                1_000_000, 1_000_005
+            )))
+        );
+        assert_eq!(
+            recurring.previous_occurrence(&999_999_999),
+            Some(Range::Continuous(ContinuousRange::EndExclusive(
+                999_000_000,
+                999_000_005
+            )))
+        );
+        assert_eq!(
+            recurring.previous_occurrence(&1_999_999),
+            Some(Range::Continuous(ContinuousRange::EndExclusive(
+                1_000_000, 1_000_005
+            )))
+        );
+    }
+
+    #[test]
+    pub fn partially_covered() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32)
+            .unwrap()
+            .except(Range::Continuous(ContinuousRange::Inclusive(3, 25)));
+        assert_eq!(
+            recurring.previous_occurrence(&26),
+            Some(Range::Continuous(ContinuousRange::EndExclusive(0, 3)))
+        );
+        assert_eq!(
+            recurring.next_occurrence(&1),
+            Some(Range::Continuous(ContinuousRange::Inclusive(30, 35)))
+        );
+    }
+
+    #[test]
+    pub fn exhausted() {
+        let recurring = RecurringRange::new(ContinuousRange::Inclusive(0, 5), 10u32)
+            .unwrap()
+            .except(Range::Continuous(ContinuousRange::From(8)));
+        assert_eq!(recurring.next_occurrence(&1), None);
+        assert_eq!(
+            recurring.previous_occurrence(&100),
+            Some(Range::Continuous(ContinuousRange::Inclusive(0, 5)))
+        );
+
+        let bounded = RecurringRange::new(ContinuousRange::Inclusive(0u8, 5), 10).unwrap();
+        assert_eq!(bounded.previous_occurrence(&4), None);
+        assert_eq!(bounded.next_occurrence(&251), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn dates() {
+        use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+        let time = |day, hour| -> NaiveDateTime {
+            NaiveDate::from_ymd_opt(2022, 1, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        let hours = RecurringRange::new(
+            ContinuousRange::EndExclusive(time(3, 9), time(3, 17)),
+            Duration::days(1),
+        )
+        .unwrap();
+        assert!(hours.contains(&time(20, 12)));
+        assert!(!hours.contains(&time(20, 18)));
+        assert_eq!(
+            hours.next_occurrence(&time(1, 10)),
+            Some(Range::Continuous(ContinuousRange::EndExclusive(
+                time(2, 9),
+                time(2, 17)
+            )))
+        );
+    }
+}
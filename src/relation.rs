@@ -1,3 +1,5 @@
+use crate::RelationSet;
+
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub enum RangesRelation {
     /// The first range is strictly before the second one with no overlap
@@ -166,3 +168,169 @@ impl RangesRelation {
         }
     }
 }
+
+impl RangesRelation {
+    /// All the relations, in declaration order
+    pub const ALL: [RangesRelation; 13] = [
+        RangesRelation::StrictlyBefore,
+        RangesRelation::StrictlyAfter,
+        RangesRelation::Meets,
+        RangesRelation::IsMet,
+        RangesRelation::Overlaps,
+        RangesRelation::IsOverlapped,
+        RangesRelation::Starts,
+        RangesRelation::IsStarted,
+        RangesRelation::StrictlyContains,
+        RangesRelation::IsStrictlyContained,
+        RangesRelation::Finishes,
+        RangesRelation::IsFinished,
+        RangesRelation::Equal,
+    ];
+
+    /// The relation of the second range to the first one
+    ///
+    /// If `a.compare(b)` is `r` then `b.compare(a)` is `r.inverse()`.
+    #[must_use]
+    pub fn inverse(&self) -> RangesRelation {
+        match self {
+            RangesRelation::StrictlyBefore => RangesRelation::StrictlyAfter,
+            RangesRelation::StrictlyAfter => RangesRelation::StrictlyBefore,
+            RangesRelation::Meets => RangesRelation::IsMet,
+            RangesRelation::IsMet => RangesRelation::Meets,
+            RangesRelation::Overlaps => RangesRelation::IsOverlapped,
+            RangesRelation::IsOverlapped => RangesRelation::Overlaps,
+            RangesRelation::Starts => RangesRelation::IsStarted,
+            RangesRelation::IsStarted => RangesRelation::Starts,
+            RangesRelation::StrictlyContains => RangesRelation::IsStrictlyContained,
+            RangesRelation::IsStrictlyContained => RangesRelation::StrictlyContains,
+            RangesRelation::Finishes => RangesRelation::IsFinished,
+            RangesRelation::IsFinished => RangesRelation::Finishes,
+            RangesRelation::Equal => RangesRelation::Equal,
+        }
+    }
+
+    /// Infer the possible relations between `A` and `C` knowing that `A` is in relation `self` with `B` and `B`
+    /// is in relation `other` with `C`
+    ///
+    /// This is the composition table of Allen's interval algebra, it's only valid for ranges where the start is
+    /// strictly before the end.
+    ///
+    /// ```
+    /// use range_ranger::RangesRelation;
+    ///
+    /// let relations = RangesRelation::Meets.compose(RangesRelation::Meets);
+    /// assert!(relations.contains(RangesRelation::StrictlyBefore));
+    /// assert_eq!(relations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn compose(&self, other: RangesRelation) -> RelationSet {
+        RelationSet::from_bits(COMPOSITION[*self as usize][other as usize])
+    }
+}
+
+// Short names following Allen's notation
+const P: u16 = 1 << RangesRelation::StrictlyBefore as u16;
+const PI: u16 = 1 << RangesRelation::StrictlyAfter as u16;
+const M: u16 = 1 << RangesRelation::Meets as u16;
+const MI: u16 = 1 << RangesRelation::IsMet as u16;
+const O: u16 = 1 << RangesRelation::Overlaps as u16;
+const OI: u16 = 1 << RangesRelation::IsOverlapped as u16;
+const S: u16 = 1 << RangesRelation::Starts as u16;
+const SI: u16 = 1 << RangesRelation::IsStarted as u16;
+const DI: u16 = 1 << RangesRelation::StrictlyContains as u16;
+const D: u16 = 1 << RangesRelation::IsStrictlyContained as u16;
+const F: u16 = 1 << RangesRelation::Finishes as u16;
+const FI: u16 = 1 << RangesRelation::IsFinished as u16;
+const E: u16 = 1 << RangesRelation::Equal as u16;
+const ALL: u16 = RelationSet::ALL.bits();
+
+/// `COMPOSITION[r1][r2]` contains the relations possible between `A` and `C` when `A r1 B` and `B r2 C`
+#[rustfmt::skip]
+const COMPOSITION: [[u16; 13]; 13] = [
+    // StrictlyBefore
+    [P, ALL, P, P | M | O | S | D, P, P | M | O | S | D, P, P, P, P | M | O | S | D, P | M | O | S | D, P, P],
+    // StrictlyAfter
+    [ALL, PI, PI | MI | OI | D | F, PI, PI | MI | OI | D | F, PI, PI | MI | OI | D | F, PI, PI, PI | MI | OI | D | F, PI, PI, PI],
+    // Meets
+    [P, PI | MI | OI | SI | DI, P, F | FI | E, P, O | S | D, M, M, P, O | S | D, O | S | D, P, M],
+    // IsMet
+    [P | M | O | DI | FI, PI, S | SI | E, PI, OI | D | F, PI, OI | D | F, PI, PI, OI | D | F, MI, MI, MI],
+    // Overlaps
+    [P, PI | MI | OI | SI | DI, P, OI | SI | DI, P | M | O, O | OI | S | SI | DI | D | F | FI | E, O, O | DI | FI, P | M | O | DI | FI, O | S | D, O | S | D, P | M | O, O],
+    // IsOverlapped
+    [P | M | O | DI | FI, PI, O | DI | FI, PI, O | OI | S | SI | DI | D | F | FI | E, PI | MI | OI, OI | D | F, PI | MI | OI, PI | MI | OI | SI | DI, OI | D | F, OI, OI | SI | DI, OI],
+    // Starts
+    [P, PI, P, MI, P | M | O, OI | D | F, S, S | SI | E, P | M | O | DI | FI, D, D, P | M | O, S],
+    // IsStarted
+    [P | M | O | DI | FI, PI, O | DI | FI, MI, O | DI | FI, OI, S | SI | E, SI, DI, OI | D | F, OI, DI, SI],
+    // StrictlyContains
+    [P | M | O | DI | FI, PI | MI | OI | SI | DI, O | DI | FI, OI | SI | DI, O | DI | FI, OI | SI | DI, O | DI | FI, DI, DI, O | OI | S | SI | DI | D | F | FI | E, OI | SI | DI, DI, DI],
+    // IsStrictlyContained
+    [P, PI, P, PI, P | M | O | S | D, PI | MI | OI | D | F, D, PI | MI | OI | D | F, ALL, D, D, P | M | O | S | D, D],
+    // Finishes
+    [P, PI, M, PI, O | S | D, PI | MI | OI, D, PI | MI | OI, PI | MI | OI | SI | DI, D, F, F | FI | E, F],
+    // IsFinished
+    [P, PI | MI | OI | SI | DI, M, OI | SI | DI, O, OI | SI | DI, O, DI, DI, O | S | D, F | FI | E, FI, FI],
+    // Equal
+    [P, PI, M, MI, O, OI, S, SI, DI, D, F, FI, E],
+];
+
+/// The position of a point relative to a range
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum PointRelation {
+    /// The point is before the range, including when it's equal to an excluded start
+    ///
+    /// ```text
+    /// P
+    ///   [ A ]
+    /// ```
+    Before,
+
+    /// The point is the included start of the range
+    ///
+    /// ```text
+    /// P
+    /// [ A ]
+    /// ```
+    Starts,
+
+    /// The point is inside the range but isn't one of its bounds
+    ///
+    /// ```text
+    ///   P
+    /// [ A ]
+    /// ```
+    During,
+
+    /// The point is the included end of the range
+    ///
+    /// ```text
+    ///     P
+    /// [ A ]
+    /// ```
+    Finishes,
+
+    /// The point is after the range, including when it's equal to an excluded end
+    ///
+    /// ```text
+    ///       P
+    /// [ A ]
+    /// ```
+    After,
+}
+
+impl PointRelation {
+    /// Returns true if the point is contained in the range
+    ///
+    /// This is equivalent to the relations:
+    /// - [`PointRelation::Starts`]
+    /// - [`PointRelation::During`]
+    /// - [`PointRelation::Finishes`]
+    #[must_use]
+    pub fn contained(&self) -> bool {
+        match self {
+            PointRelation::Starts | PointRelation::During | PointRelation::Finishes => true,
+            PointRelation::Before | PointRelation::After => false,
+        }
+    }
+}
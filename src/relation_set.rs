@@ -0,0 +1,149 @@
+use std::{
+    fmt,
+    iter::FromIterator,
+    ops::{BitAnd, BitOr, Not},
+};
+
+use crate::RangesRelation;
+
+/// A set of [`RangesRelation`], representing a disjunction of possible relations between two ranges
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct RelationSet(u16);
+
+impl RelationSet {
+    /// The set without any relation
+    pub const EMPTY: RelationSet = RelationSet(0);
+
+    /// The set containing all the relations
+    pub const ALL: RelationSet = RelationSet((1 << RangesRelation::ALL.len()) - 1);
+
+    pub(crate) const fn from_bits(bits: u16) -> Self {
+        RelationSet(bits & Self::ALL.0)
+    }
+
+    pub(crate) const fn bits(self) -> u16 {
+        self.0
+    }
+
+    fn bit(relation: RangesRelation) -> u16 {
+        1 << relation as u16
+    }
+
+    /// Check if the set doesn't contain any relation
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The number of relations in the set
+    #[must_use]
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Check if `relation` is part of the set
+    #[must_use]
+    pub fn contains(self, relation: RangesRelation) -> bool {
+        self.0 & Self::bit(relation) != 0
+    }
+
+    /// Add `relation` to the set
+    pub fn insert(&mut self, relation: RangesRelation) {
+        self.0 |= Self::bit(relation);
+    }
+
+    /// Remove `relation` from the set
+    pub fn remove(&mut self, relation: RangesRelation) {
+        self.0 &= !Self::bit(relation);
+    }
+
+    /// The relations that are in either set
+    #[must_use]
+    pub fn union(self, other: RelationSet) -> RelationSet {
+        RelationSet(self.0 | other.0)
+    }
+
+    /// The relations that are in both sets
+    #[must_use]
+    pub fn intersection(self, other: RelationSet) -> RelationSet {
+        RelationSet(self.0 & other.0)
+    }
+
+    /// Iterate over the relations of the set, in declaration order
+    pub fn iter(self) -> impl Iterator<Item = RangesRelation> {
+        RangesRelation::ALL
+            .iter()
+            .copied()
+            .filter(move |relation| self.contains(*relation))
+    }
+
+    /// The set of the inverse of each relation
+    #[must_use]
+    pub fn inverse(self) -> RelationSet {
+        self.iter().map(|relation| relation.inverse()).collect()
+    }
+
+    /// Infer the possible relations between `A` and `C` knowing that the relation between `A` and `B` is in
+    /// `self` and the relation between `B` and `C` is in `other`
+    ///
+    /// See [`RangesRelation::compose`].
+    #[must_use]
+    pub fn compose(self, other: RelationSet) -> RelationSet {
+        let mut result = RelationSet::EMPTY;
+        for first in self.iter() {
+            for second in other.iter() {
+                result = result | first.compose(second);
+                if result == RelationSet::ALL {
+                    return result;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl From<RangesRelation> for RelationSet {
+    fn from(relation: RangesRelation) -> Self {
+        RelationSet(RelationSet::bit(relation))
+    }
+}
+
+impl FromIterator<RangesRelation> for RelationSet {
+    fn from_iter<T: IntoIterator<Item = RangesRelation>>(iter: T) -> Self {
+        let mut set = RelationSet::EMPTY;
+        for relation in iter {
+            set.insert(relation);
+        }
+        set
+    }
+}
+
+impl BitOr for RelationSet {
+    type Output = RelationSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for RelationSet {
+    type Output = RelationSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Not for RelationSet {
+    type Output = RelationSet;
+
+    fn not(self) -> Self::Output {
+        RelationSet::from_bits(!self.0)
+    }
+}
+
+impl fmt::Debug for RelationSet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_set().entries(self.iter()).finish()
+    }
+}
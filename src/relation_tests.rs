@@ -0,0 +1,168 @@
+/// All the ranges with a start strictly before the end using values from 0 to 6, enough to produce every
+/// possible ordering of the bounds of three ranges
+fn proper_ranges() -> Vec<crate::ContinuousRange<i32>> {
+    let mut ranges = Vec::new();
+    for start in 0..7 {
+        for end in (start + 1)..7 {
+            ranges.push(crate::ContinuousRange::Inclusive(start, end));
+        }
+    }
+    ranges
+}
+
+mod test_inverse {
+    use crate::RangesRelation;
+
+    use super::proper_ranges;
+
+    #[test]
+    pub fn involution() {
+        for relation in RangesRelation::ALL {
+            assert_eq!(relation.inverse().inverse(), relation);
+        }
+        assert_eq!(RangesRelation::Equal.inverse(), RangesRelation::Equal);
+        assert_eq!(
+            RangesRelation::Overlaps.inverse(),
+            RangesRelation::IsOverlapped
+        );
+    }
+
+    #[test]
+    pub fn matches_compare() {
+        let ranges = proper_ranges();
+        for a in &ranges {
+            for b in &ranges {
+                let relation = a.compare(b).unwrap();
+                assert_eq!(b.compare(a), Some(relation.inverse()), "{a:?} {b:?}");
+            }
+        }
+    }
+}
+
+mod test_compose {
+    use crate::{RangesRelation, RelationSet};
+
+    use super::proper_ranges;
+
+    #[test]
+    pub fn matches_compare() {
+        let ranges = proper_ranges();
+        let mut expected = [[RelationSet::EMPTY; 13]; 13];
+        for a in &ranges {
+            for b in &ranges {
+                let ab = a.compare(b).unwrap();
+                for c in &ranges {
+                    let bc = b.compare(c).unwrap();
+                    expected[ab as usize][bc as usize].insert(a.compare(c).unwrap());
+                }
+            }
+        }
+
+        for first in RangesRelation::ALL {
+            for second in RangesRelation::ALL {
+                assert_eq!(
+                    first.compose(second),
+                    expected[first as usize][second as usize],
+                    "{first:?} {second:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn known_compositions() {
+        assert_eq!(
+            RangesRelation::StrictlyBefore.compose(RangesRelation::StrictlyAfter),
+            RelationSet::ALL
+        );
+        assert_eq!(
+            RangesRelation::Starts.compose(RangesRelation::IsStarted),
+            [
+                RangesRelation::Starts,
+                RangesRelation::IsStarted,
+                RangesRelation::Equal
+            ]
+            .iter()
+            .copied()
+            .collect()
+        );
+        for relation in RangesRelation::ALL {
+            assert_eq!(
+                RangesRelation::Equal.compose(relation),
+                RelationSet::from(relation)
+            );
+        }
+    }
+
+    #[test]
+    pub fn sets() {
+        let during_or_meets =
+            RelationSet::from(RangesRelation::IsStrictlyContained) | RangesRelation::Meets.into();
+        assert_eq!(
+            during_or_meets.compose(RangesRelation::StrictlyBefore.into()),
+            RangesRelation::StrictlyBefore.into()
+        );
+        assert_eq!(
+            RelationSet::EMPTY.compose(RelationSet::ALL),
+            RelationSet::EMPTY
+        );
+    }
+}
+
+mod test_relation_set {
+    use crate::{RangesRelation, RelationSet};
+
+    #[test]
+    pub fn insert_remove() {
+        let mut set = RelationSet::EMPTY;
+        assert!(set.is_empty());
+        set.insert(RangesRelation::Meets);
+        set.insert(RangesRelation::Equal);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(RangesRelation::Meets));
+        assert!(!set.contains(RangesRelation::IsMet));
+        set.remove(RangesRelation::Meets);
+        assert_eq!(set, RangesRelation::Equal.into());
+    }
+
+    #[test]
+    pub fn operators() {
+        let a: RelationSet = [RangesRelation::Meets, RangesRelation::Overlaps]
+            .iter()
+            .copied()
+            .collect();
+        let b: RelationSet = [RangesRelation::Overlaps, RangesRelation::Starts]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(a & b, RangesRelation::Overlaps.into());
+        assert_eq!((a | b).len(), 3);
+        assert_eq!((!a).len(), 11);
+        assert_eq!(!RelationSet::ALL, RelationSet::EMPTY);
+        assert_eq!(RelationSet::ALL.len(), 13);
+    }
+
+    #[test]
+    pub fn inverse() {
+        let set: RelationSet = [RangesRelation::Meets, RangesRelation::Equal]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(
+            set.inverse(),
+            [RangesRelation::IsMet, RangesRelation::Equal]
+                .iter()
+                .copied()
+                .collect()
+        );
+    }
+
+    #[test]
+    pub fn fmt_debug() {
+        let set: RelationSet = [RangesRelation::Equal, RangesRelation::Meets]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(format!("{set:?}"), "{Meets, Equal}");
+    }
+}
@@ -0,0 +1,1064 @@
+use std::{
+    cmp::Ordering, collections::VecDeque, convert::TryFrom, error::Error, fmt, iter::Peekable,
+    str::FromStr, vec,
+};
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use crate::{continuous::bound_value, ContinuousRange, Range};
+
+/// An error raised when an iCalendar recurrence can't be parsed
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum RecurrenceError {
+    /// The recurrence rule doesn't have a `FREQ` part
+    MissingFrequency,
+
+    /// The event doesn't have a `DTSTART` property
+    MissingStart,
+
+    /// A rule part or a property is malformed, with its content
+    Invalid(String),
+
+    /// A rule part or a property is valid but isn't supported, with its content
+    Unsupported(String),
+}
+
+impl fmt::Display for RecurrenceError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurrenceError::MissingFrequency => write!(fmt, "recurrence rule has no FREQ part"),
+            RecurrenceError::MissingStart => write!(fmt, "event has no DTSTART property"),
+            RecurrenceError::Invalid(content) => {
+                write!(fmt, "invalid recurrence content: {content}")
+            }
+            RecurrenceError::Unsupported(content) => {
+                write!(fmt, "unsupported recurrence content: {content}")
+            }
+        }
+    }
+}
+
+impl Error for RecurrenceError {}
+
+fn invalid(content: &str) -> RecurrenceError {
+    RecurrenceError::Invalid(content.to_owned())
+}
+
+/// Store the value of a property that can only appear once, `line` being reported if it was already set
+fn set_once<T>(slot: &mut Option<T>, value: T, line: &str) -> Result<(), RecurrenceError> {
+    if slot.is_some() {
+        return Err(invalid(line));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// The base unit of time of a [`RecurrenceRule`]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Frequency {
+    /// Every second
+    Secondly,
+
+    /// Every minute
+    Minutely,
+
+    /// Every hour
+    Hourly,
+
+    /// Every day
+    Daily,
+
+    /// Every week, starting on the `WKST` day
+    Weekly,
+
+    /// Every month
+    Monthly,
+
+    /// Every year
+    Yearly,
+}
+
+/// A recurrence rule as defined by the `RRULE` property of RFC 5545
+///
+/// All the rule parts are supported except `BYWEEKNO` and `BYYEARDAY`. Times are floating, the `Z` suffix of
+/// `UNTIL` is ignored and a date-only `UNTIL` includes the whole day.
+///
+/// ```
+/// use range_ranger::{Frequency, RecurrenceRule};
+///
+/// let rule: RecurrenceRule = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=10".parse().unwrap();
+/// assert_eq!(rule.frequency(), Frequency::Weekly);
+/// assert_eq!(rule.interval(), 2);
+/// assert_eq!(rule.count(), Some(10));
+/// ```
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<NaiveDateTime>,
+    by_second: Vec<u32>,
+    by_minute: Vec<u32>,
+    by_hour: Vec<u32>,
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+    by_set_pos: Vec<i32>,
+    week_start: Weekday,
+}
+
+impl RecurrenceRule {
+    /// The base unit of time of the rule
+    #[must_use]
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// The number of units of time between two periods of the rule
+    #[must_use]
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// The maximum number of occurrences produced by the rule
+    #[must_use]
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// The last value an occurrence can start at
+    #[must_use]
+    pub fn until(&self) -> Option<NaiveDateTime> {
+        self.until
+    }
+}
+
+fn parse_number<T: FromStr>(text: &str) -> Option<T> {
+    let digits = text.strip_prefix(&['+', '-'][..]).unwrap_or(text);
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+fn parse_list<T: FromStr + Ord>(
+    part: &str,
+    value: &str,
+    valid: impl Fn(&T) -> bool,
+) -> Result<Vec<T>, RecurrenceError> {
+    let mut values = value
+        .split(',')
+        .map(|item| match parse_number(item) {
+            Some(value) if valid(&value) => Ok(value),
+            _ => Err(invalid(part)),
+        })
+        .collect::<Result<Vec<T>, _>>()?;
+    values.sort();
+    values.dedup();
+    Ok(values)
+}
+
+fn parse_weekday(text: &str) -> Option<Weekday> {
+    Some(match text.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_week_day(text: &str) -> Option<(Option<i32>, Weekday)> {
+    let split = text.len().checked_sub(2)?;
+    let weekday = parse_weekday(text.get(split..)?)?;
+    let ordinal = match text.get(..split)? {
+        "" => None,
+        ordinal => match parse_number::<i32>(ordinal) {
+            Some(ordinal) if ordinal != 0 && ordinal.unsigned_abs() <= 53 => Some(ordinal),
+            _ => return None,
+        },
+    };
+    Some((ordinal, weekday))
+}
+
+/// Parse a `DATE` or `DATE-TIME` value, returning if it's a date
+fn parse_date_time(text: &str) -> Result<(NaiveDateTime, bool), RecurrenceError> {
+    let value = text.trim();
+    let value = value.strip_suffix(&['Z', 'z'][..]).unwrap_or(value);
+    let number = |range: std::ops::Range<usize>| -> Option<u32> {
+        let digits = value.get(range)?;
+        if digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            digits.parse().ok()
+        } else {
+            None
+        }
+    };
+
+    let date = || {
+        NaiveDate::from_ymd_opt(
+            i32::try_from(number(0..4)?).ok()?,
+            number(4..6)?,
+            number(6..8)?,
+        )
+    };
+    let parsed = match value.len() {
+        8 => date()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date| (date, true)),
+        15 if value
+            .get(8..9)
+            .map_or(false, |separator| separator.eq_ignore_ascii_case("T")) =>
+        {
+            date()
+                .and_then(|date| date.and_hms_opt(number(9..11)?, number(11..13)?, number(13..15)?))
+                .map(|date| (date, false))
+        }
+        _ => None,
+    };
+    parsed.ok_or_else(|| invalid(text))
+}
+
+/// Parse a `DURATION` value, only positive durations are supported
+fn parse_duration(text: &str) -> Result<Duration, RecurrenceError> {
+    let value = text.trim();
+    let value = value.strip_prefix('+').unwrap_or(value);
+    if value.starts_with('-') {
+        return Err(RecurrenceError::Unsupported(text.to_owned()));
+    }
+    let value = value
+        .strip_prefix(&['P', 'p'][..])
+        .ok_or_else(|| invalid(text))?;
+
+    let mut seconds: i64 = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+    let mut any = false;
+    for character in value.chars() {
+        if character.is_ascii_digit() {
+            number.push(character);
+            continue;
+        }
+
+        let unit = match (character.to_ascii_uppercase(), in_time) {
+            ('T', false) if number.is_empty() => {
+                in_time = true;
+                continue;
+            }
+            ('W', false) => 7 * 24 * 3600,
+            ('D', false) => 24 * 3600,
+            ('H', true) => 3600,
+            ('M', true) => 60,
+            ('S', true) => 1,
+            _ => return Err(invalid(text)),
+        };
+        let amount: i64 = number.parse().map_err(|_| invalid(text))?;
+        seconds = amount
+            .checked_mul(unit)
+            .and_then(|amount| seconds.checked_add(amount))
+            .filter(|seconds| *seconds <= i64::MAX / 1000)
+            .ok_or_else(|| invalid(text))?;
+        number.clear();
+        any = true;
+    }
+
+    if !any || !number.is_empty() {
+        return Err(invalid(text));
+    }
+    Ok(Duration::seconds(seconds))
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = RecurrenceError;
+
+    /// Parse the value of an `RRULE` property, with or without the `RRULE:` prefix
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let text = match text.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &text[6..],
+            _ => text,
+        };
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+
+        let mut seen = Vec::new();
+        for part in text.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part.split_once('=').ok_or_else(|| invalid(part))?;
+            let name = name.to_ascii_uppercase();
+            if seen.contains(&name) || value.is_empty() {
+                return Err(invalid(part));
+            }
+
+            match name.as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "SECONDLY" => Frequency::Secondly,
+                        "MINUTELY" => Frequency::Minutely,
+                        "HOURLY" => Frequency::Hourly,
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(invalid(part)),
+                    });
+                }
+                "INTERVAL" => {
+                    rule.interval = parse_number(value)
+                        .filter(|interval| *interval > 0)
+                        .ok_or_else(|| invalid(part))?;
+                }
+                "COUNT" => rule.count = Some(parse_number(value).ok_or_else(|| invalid(part))?),
+                "UNTIL" => {
+                    let (until, is_date) = parse_date_time(value)?;
+                    rule.until = Some(if is_date {
+                        until.date().and_hms_opt(23, 59, 59).unwrap_or(until)
+                    } else {
+                        until
+                    });
+                }
+                "BYSECOND" => rule.by_second = parse_list(part, value, |second| *second <= 60)?,
+                "BYMINUTE" => rule.by_minute = parse_list(part, value, |minute| *minute < 60)?,
+                "BYHOUR" => rule.by_hour = parse_list(part, value, |hour| *hour < 24)?,
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(|day| parse_week_day(day).ok_or_else(|| invalid(part)))
+                        .collect::<Result<_, _>>()?;
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = parse_list(part, value, |day: &i32| {
+                        *day != 0 && day.unsigned_abs() <= 31
+                    })?;
+                }
+                "BYMONTH" => {
+                    rule.by_month = parse_list(part, value, |month| (1..=12).contains(month))?;
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos = parse_list(part, value, |pos: &i32| {
+                        *pos != 0 && pos.unsigned_abs() <= 366
+                    })?;
+                }
+                "WKST" => rule.week_start = parse_weekday(value).ok_or_else(|| invalid(part))?,
+                "BYWEEKNO" | "BYYEARDAY" => {
+                    return Err(RecurrenceError::Unsupported(part.to_owned()))
+                }
+                _ => return Err(invalid(part)),
+            }
+            seen.push(name);
+        }
+
+        rule.frequency = frequency.ok_or(RecurrenceError::MissingFrequency)?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(invalid(text));
+        }
+        if rule.frequency == Frequency::Weekly && !rule.by_month_day.is_empty() {
+            return Err(invalid(text));
+        }
+        let has_ordinal = rule.by_day.iter().any(|(ordinal, _)| ordinal.is_some());
+        if has_ordinal
+            && rule.frequency != Frequency::Monthly
+            && rule.frequency != Frequency::Yearly
+        {
+            return Err(invalid(text));
+        }
+
+        Ok(rule)
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map_or(31, |last| last.day())
+}
+
+fn days_in_year(date: NaiveDate) -> u32 {
+    NaiveDate::from_ymd_opt(date.year(), 12, 31).map_or(365, |last| last.ordinal())
+}
+
+/// A duration of `count * unit` seconds or [`None`] if it can't be represented
+fn seconds(count: i64, unit: i64) -> Option<Duration> {
+    count
+        .checked_mul(unit)
+        .filter(|seconds| seconds.abs() <= i64::MAX / 1000)
+        .map(Duration::seconds)
+}
+
+/// The occurrences of a single rule, in increasing order
+struct Expansion {
+    rule: RecurrenceRule,
+    start: NaiveDateTime,
+    limit: NaiveDateTime,
+    period: i64,
+    pending: VecDeque<NaiveDateTime>,
+    emitted: u32,
+    done: bool,
+}
+
+impl Expansion {
+    /// Expand `rule` starting at `start` up to the periods starting after `limit`, skipping the periods that
+    /// end before `from` if the occurrences don't need to be counted
+    fn new(
+        rule: &RecurrenceRule,
+        start: NaiveDateTime,
+        from: NaiveDateTime,
+        limit: NaiveDateTime,
+    ) -> Self {
+        let mut rule = rule.clone();
+        if rule.by_month_day.is_empty() && rule.by_day.is_empty() {
+            match rule.frequency {
+                Frequency::Yearly => {
+                    if rule.by_month.is_empty() {
+                        rule.by_month = vec![start.month()];
+                    }
+                    rule.by_month_day = vec![i32::try_from(start.day()).unwrap_or(1)];
+                }
+                Frequency::Monthly => {
+                    rule.by_month_day = vec![i32::try_from(start.day()).unwrap_or(1)];
+                }
+                Frequency::Weekly => rule.by_day = vec![(None, start.weekday())],
+                _ => {}
+            }
+        }
+
+        let mut expansion = Expansion {
+            rule,
+            start,
+            limit,
+            period: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+            done: false,
+        };
+        if expansion.rule.count.is_none() && from > start {
+            expansion.period = expansion.periods_until(from);
+        }
+        expansion
+    }
+
+    /// A number of periods that is safe to skip to reach `from`
+    fn periods_until(&self, from: NaiveDateTime) -> i64 {
+        let start = self.start;
+        let units = match self.rule.frequency {
+            Frequency::Yearly => i64::from(from.year() - start.year()),
+            Frequency::Monthly => {
+                i64::from(from.year() - start.year()) * 12 + i64::from(from.month0())
+                    - i64::from(start.month0())
+            }
+            Frequency::Weekly => (from - start).num_weeks(),
+            Frequency::Daily => (from - start).num_days(),
+            Frequency::Hourly => (from - start).num_hours(),
+            Frequency::Minutely => (from - start).num_minutes(),
+            Frequency::Secondly => (from - start).num_seconds(),
+        };
+        (units / i64::from(self.rule.interval) - 1).max(0)
+    }
+
+    /// The first value of the period `index`
+    fn period_start(&self, index: i64) -> Option<NaiveDateTime> {
+        let start = self.start;
+        let step = i64::from(self.rule.interval).checked_mul(index)?;
+        let midnight = |date: NaiveDate| date.and_hms_opt(0, 0, 0);
+        match self.rule.frequency {
+            Frequency::Yearly => {
+                let year = i32::try_from(i64::from(start.year()).checked_add(step)?).ok()?;
+                midnight(NaiveDate::from_ymd_opt(year, 1, 1)?)
+            }
+            Frequency::Monthly => {
+                let months =
+                    (i64::from(start.year()) * 12 + i64::from(start.month0())).checked_add(step)?;
+                let year = i32::try_from(months.div_euclid(12)).ok()?;
+                let month = u32::try_from(months.rem_euclid(12)).ok()? + 1;
+                midnight(NaiveDate::from_ymd_opt(year, month, 1)?)
+            }
+            Frequency::Weekly => {
+                let offset = (7 + start.weekday().num_days_from_monday()
+                    - self.rule.week_start.num_days_from_monday())
+                    % 7;
+                let week = start
+                    .date()
+                    .checked_sub_signed(Duration::days(i64::from(offset)))?;
+                midnight(week.checked_add_signed(seconds(step, 7 * 24 * 3600)?)?)
+            }
+            Frequency::Daily => {
+                midnight(start.date().checked_add_signed(seconds(step, 24 * 3600)?)?)
+            }
+            Frequency::Hourly => start
+                .date()
+                .and_hms_opt(start.hour(), 0, 0)?
+                .checked_add_signed(seconds(step, 3600)?),
+            Frequency::Minutely => start
+                .date()
+                .and_hms_opt(start.hour(), start.minute(), 0)?
+                .checked_add_signed(seconds(step, 60)?),
+            Frequency::Secondly => start
+                .date()
+                .and_hms_opt(start.hour(), start.minute(), start.second())?
+                .checked_add_signed(seconds(step, 1)?),
+        }
+    }
+
+    /// The days of the period starting at `period`
+    fn period_days(&self, period: NaiveDateTime) -> Vec<NaiveDate> {
+        let first = period.date();
+        let count = match self.rule.frequency {
+            Frequency::Yearly => days_in_year(first),
+            Frequency::Monthly => days_in_month(first),
+            Frequency::Weekly => 7,
+            _ => 1,
+        };
+        first.iter_days().take(count as usize).collect()
+    }
+
+    /// The times of the day of the occurrences in the period starting at `period`
+    fn times(&self, period: NaiveDateTime) -> Vec<NaiveTime> {
+        let rule = &self.rule;
+        let expand = |values: &[u32], default: u32| {
+            if values.is_empty() {
+                vec![default]
+            } else {
+                values.to_vec()
+            }
+        };
+        let limit = |values: &[u32], value: u32| {
+            if values.is_empty() || values.contains(&value) {
+                vec![value]
+            } else {
+                Vec::new()
+            }
+        };
+
+        let frequency = rule.frequency;
+        let hours = if frequency <= Frequency::Hourly {
+            limit(&rule.by_hour, period.hour())
+        } else {
+            expand(&rule.by_hour, self.start.hour())
+        };
+        let minutes = if frequency <= Frequency::Minutely {
+            limit(&rule.by_minute, period.minute())
+        } else {
+            expand(&rule.by_minute, self.start.minute())
+        };
+        let seconds = if frequency == Frequency::Secondly {
+            limit(&rule.by_second, period.second())
+        } else {
+            expand(&rule.by_second, self.start.second())
+        };
+
+        let mut times = Vec::new();
+        for hour in &hours {
+            for minute in &minutes {
+                for second in &seconds {
+                    times.extend(NaiveTime::from_hms_opt(*hour, *minute, *second));
+                }
+            }
+        }
+        times
+    }
+
+    fn matches(&self, date: NaiveDate) -> bool {
+        let rule = &self.rule;
+        if !rule.by_month.is_empty() && !rule.by_month.contains(&date.month()) {
+            return false;
+        }
+
+        let day = i64::from(date.day());
+        let month_length = i64::from(days_in_month(date));
+        if !rule.by_month_day.is_empty()
+            && !rule.by_month_day.iter().any(|expected| {
+                i64::from(*expected) == day || i64::from(*expected) == day - month_length - 1
+            })
+        {
+            return false;
+        }
+
+        if rule.by_day.is_empty() {
+            return true;
+        }
+
+        // Ordinals count the weekdays of the year for a yearly rule without months, of the month otherwise
+        let (index, length) = if rule.frequency == Frequency::Yearly && rule.by_month.is_empty() {
+            (date.ordinal0(), days_in_year(date))
+        } else {
+            (date.day0(), days_in_month(date))
+        };
+        rule.by_day.iter().any(|(ordinal, weekday)| {
+            *weekday == date.weekday()
+                && match ordinal {
+                    None => true,
+                    Some(ordinal) if *ordinal > 0 => {
+                        i64::from(index / 7 + 1) == i64::from(*ordinal)
+                    }
+                    Some(ordinal) => {
+                        i64::from((length - 1 - index) / 7 + 1) == -i64::from(*ordinal)
+                    }
+                }
+        })
+    }
+
+    /// The next period after the period `index` that can contain occurrences
+    ///
+    /// Sub-daily periods in a day, hour or minute excluded by the rule are skipped together, so that a rule like
+    /// `FREQ=SECONDLY;BYMONTH=12` doesn't go through every second of the other months.
+    fn next_period(&self, index: i64) -> Option<i64> {
+        let next = index.checked_add(1)?;
+        let rule = &self.rule;
+        let unit = match rule.frequency {
+            Frequency::Hourly => 3600,
+            Frequency::Minutely => 60,
+            Frequency::Secondly => 1,
+            _ => return Some(next),
+        };
+
+        let period = self.period_start(index)?;
+        let excluded = |values: &[u32], value: u32| !values.is_empty() && !values.contains(&value);
+        let skip_to = if !self.matches(period.date()) {
+            period.date().succ_opt()?.and_hms_opt(0, 0, 0)?
+        } else if rule.frequency <= Frequency::Minutely && excluded(&rule.by_hour, period.hour()) {
+            period
+                .date()
+                .and_hms_opt(period.hour(), 0, 0)?
+                .checked_add_signed(Duration::hours(1))?
+        } else if rule.frequency == Frequency::Secondly
+            && excluded(&rule.by_minute, period.minute())
+        {
+            period
+                .date()
+                .and_hms_opt(period.hour(), period.minute(), 0)?
+                .checked_add_signed(Duration::minutes(1))?
+        } else {
+            return Some(next);
+        };
+
+        // The first period starting at or after `skip_to`
+        let length = unit * i64::from(rule.interval);
+        let offset = (skip_to - self.period_start(0)?).num_seconds();
+        let skipped = offset.div_euclid(length) + i64::from(offset.rem_euclid(length) != 0);
+        Some(next.max(skipped))
+    }
+
+    /// The occurrences of the period `index` or [`None`] if the period is past the limit
+    fn candidates(&self, index: i64) -> Option<Vec<NaiveDateTime>> {
+        let period = self.period_start(index)?;
+        if period > self.limit {
+            return None;
+        }
+
+        let times = self.times(period);
+        let mut candidates = Vec::new();
+        for day in self.period_days(period) {
+            if self.matches(day) {
+                candidates.extend(times.iter().map(|time| day.and_time(*time)));
+            }
+        }
+
+        if !self.rule.by_set_pos.is_empty() {
+            let length = i64::try_from(candidates.len()).ok()?;
+            let mut selected: Vec<NaiveDateTime> = self
+                .rule
+                .by_set_pos
+                .iter()
+                .filter_map(|position| {
+                    let position = i64::from(*position);
+                    let index = if position > 0 {
+                        position - 1
+                    } else {
+                        length + position
+                    };
+                    candidates.get(usize::try_from(index).ok()?).copied()
+                })
+                .collect();
+            selected.sort();
+            selected.dedup();
+            candidates = selected;
+        }
+
+        candidates.retain(|candidate| *candidate >= self.start);
+        Some(candidates)
+    }
+}
+
+impl Iterator for Expansion {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done {
+                return None;
+            }
+
+            if let Some(candidate) = self.pending.pop_front() {
+                let past_until = self.rule.until.map_or(false, |until| candidate > until);
+                let past_count = self.rule.count.map_or(false, |count| self.emitted >= count);
+                if past_until || past_count {
+                    self.done = true;
+                    return None;
+                }
+
+                self.emitted += 1;
+                return Some(candidate);
+            }
+
+            match self.candidates(self.period) {
+                Some(candidates) => self.pending.extend(candidates),
+                None => self.done = true,
+            }
+            match self.next_period(self.period) {
+                Some(period) => self.period = period,
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+/// An event repeating according to iCalendar recurrence rules and dates, as described by a `VEVENT`
+///
+/// The occurrences start at `DTSTART`, at each value produced by the `RRULE` properties and at each `RDATE`,
+/// except the ones listed by `EXDATE`. Times are floating, time zone parameters are ignored.
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use range_ranger::{ContinuousRange, RecurringEvent};
+///
+/// let event: RecurringEvent = "BEGIN:VEVENT\n\
+///     DTSTART:20230102T090000\n\
+///     DURATION:PT1H\n\
+///     RRULE:FREQ=DAILY;COUNT=3\n\
+///     EXDATE:20230103T090000\n\
+///     END:VEVENT"
+///     .parse()
+///     .unwrap();
+///
+/// let time = |day, hour| NaiveDate::from_ymd_opt(2023, 1, day).unwrap().and_hms_opt(hour, 0, 0).unwrap();
+/// let window = ContinuousRange::Inclusive(time(1, 0), time(31, 0));
+/// let occurrences: Vec<_> = event.occurrences(&window).unwrap().collect();
+/// assert_eq!(
+///     occurrences,
+///     vec![
+///         ContinuousRange::EndExclusive(time(2, 9), time(2, 10)),
+///         ContinuousRange::EndExclusive(time(4, 9), time(4, 10)),
+///     ]
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RecurringEvent {
+    start: NaiveDateTime,
+    duration: Duration,
+    rules: Vec<RecurrenceRule>,
+    dates: Vec<NaiveDateTime>,
+    exceptions: Vec<NaiveDateTime>,
+}
+
+impl RecurringEvent {
+    /// An event with a single occurrence starting at `start` and lasting `duration`
+    ///
+    /// `duration` is expected to be positive or zero, occurrences without duration are single values.
+    #[must_use]
+    pub fn new(start: NaiveDateTime, duration: Duration) -> Self {
+        RecurringEvent {
+            start,
+            duration,
+            rules: Vec::new(),
+            dates: Vec::new(),
+            exceptions: Vec::new(),
+        }
+    }
+
+    /// Add the occurrences produced by `rule`, like an `RRULE` property
+    #[must_use]
+    pub fn with_rule(mut self, rule: RecurrenceRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Add an occurrence starting at `date`, like an `RDATE` property
+    #[must_use]
+    pub fn with_date(mut self, date: NaiveDateTime) -> Self {
+        self.dates.push(date);
+        self
+    }
+
+    /// Remove the occurrence starting at `date`, like an `EXDATE` property
+    #[must_use]
+    pub fn with_exception(mut self, date: NaiveDateTime) -> Self {
+        self.exceptions.push(date);
+        self
+    }
+
+    /// The start of the first occurrence
+    #[must_use]
+    pub fn start(&self) -> NaiveDateTime {
+        self.start
+    }
+
+    /// The duration of each occurrence
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Iterate lazily over the occurrences that intersect `window`, in increasing order
+    ///
+    /// Returns [`None`] if `window` is unbounded, as it could contain an infinite number of occurrences.
+    #[must_use]
+    pub fn occurrences(&self, window: &ContinuousRange<NaiveDateTime>) -> Option<Occurrences<'_>> {
+        self.iterate(window, true)
+    }
+
+    /// The values of `window` that are part of an occurrence
+    ///
+    /// The time covered by the occurrences removed by `EXDATE` is subtracted from the union of all the
+    /// occurrences, so it's also removed from the occurrences overlapping them. Returns [`None`] if `window` is
+    /// unbounded.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected.
+    #[must_use]
+    pub fn expand(&self, window: &ContinuousRange<NaiveDateTime>) -> Option<Range<NaiveDateTime>> {
+        let mut iterator = self.iterate(window, false)?;
+        let mut occurrences = Vec::new();
+        let mut excluded = Vec::new();
+        while let Some(start) = iterator.next_start() {
+            let occurrence = Range::Continuous(self.occurrence(start));
+            if self.exceptions.contains(&start) {
+                excluded.push(occurrence);
+            } else {
+                occurrences.push(occurrence);
+            }
+        }
+
+        Some(
+            Range::composite(occurrences)
+                .difference(&Range::composite(excluded))
+                .intersection(&Range::Continuous(window.clone())),
+        )
+    }
+
+    fn occurrence(&self, start: NaiveDateTime) -> ContinuousRange<NaiveDateTime> {
+        if self.duration <= Duration::zero() {
+            return ContinuousRange::Single(start);
+        }
+
+        match start.checked_add_signed(self.duration) {
+            Some(end) => ContinuousRange::EndExclusive(start, end),
+            None => ContinuousRange::From(start),
+        }
+    }
+
+    fn iterate(
+        &self,
+        window: &ContinuousRange<NaiveDateTime>,
+        skip_exceptions: bool,
+    ) -> Option<Occurrences<'_>> {
+        if window.is_empty() {
+            return Some(Occurrences {
+                event: self,
+                window: window.clone(),
+                limit: self.start,
+                rules: Vec::new(),
+                dates: Vec::new().into_iter().peekable(),
+                skip_exceptions,
+            });
+        }
+
+        let (start, end) = window.range_bounds()?;
+        let (start, limit) = (*bound_value(start)?, *bound_value(end)?);
+
+        let from = start.checked_sub_signed(self.duration).unwrap_or(start);
+        let mut dates = self.dates.clone();
+        dates.push(self.start);
+        dates.sort();
+        dates.dedup();
+
+        Some(Occurrences {
+            event: self,
+            window: window.clone(),
+            limit,
+            rules: self
+                .rules
+                .iter()
+                .map(|rule| Expansion::new(rule, self.start, from, limit).peekable())
+                .collect(),
+            dates: dates.into_iter().peekable(),
+            skip_exceptions,
+        })
+    }
+}
+
+impl FromStr for RecurringEvent {
+    type Err = RecurrenceError;
+
+    /// Parse the content lines of a `VEVENT`
+    ///
+    /// The `DTSTART`, `DTEND`, `DURATION`, `RRULE`, `RDATE` and `EXDATE` properties are used, other properties
+    /// are ignored. Without `DTEND` or `DURATION` the occurrences last one day if `DTSTART` is a date and have no
+    /// duration otherwise. The lines can be wrapped in `BEGIN` and `END` lines, only the properties of the
+    /// `VEVENT` itself are used and not the ones of the calendar or of nested components like `VALARM`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // Long lines are folded by inserting a line break followed by a space
+        let mut lines: Vec<String> = Vec::new();
+        for line in text.lines() {
+            match (line.strip_prefix(&[' ', '\t'][..]), lines.last_mut()) {
+                (Some(continuation), Some(last)) => last.push_str(continuation),
+                _ => lines.push(line.trim().to_owned()),
+            }
+        }
+
+        let mut start = None;
+        let mut end = None;
+        let mut duration = None;
+        let mut rules = Vec::new();
+        let mut dates = Vec::new();
+        let mut exceptions = Vec::new();
+        let mut components: Vec<String> = Vec::new();
+        for line in lines.iter().filter(|line| !line.is_empty()) {
+            let (head, value) = line.split_once(':').ok_or_else(|| invalid(line))?;
+            let name = head.split(';').next().unwrap_or(head).to_ascii_uppercase();
+            match name.as_str() {
+                "BEGIN" => {
+                    components.push(value.trim().to_ascii_uppercase());
+                    continue;
+                }
+                "END" => match components.pop() {
+                    Some(component) if component.eq_ignore_ascii_case(value.trim()) => continue,
+                    _ => return Err(invalid(line)),
+                },
+                _ if components
+                    .last()
+                    .map_or(false, |component| component != "VEVENT") =>
+                {
+                    continue
+                }
+                _ => {}
+            }
+
+            let parse_dates = |dates: &mut Vec<NaiveDateTime>| {
+                for date in value.split(',') {
+                    if date.contains('/') {
+                        return Err(RecurrenceError::Unsupported(line.clone()));
+                    }
+                    dates.push(parse_date_time(date)?.0);
+                }
+                Ok(())
+            };
+
+            match name.as_str() {
+                "DTSTART" => set_once(&mut start, parse_date_time(value)?, line)?,
+                "DTEND" => set_once(&mut end, parse_date_time(value)?.0, line)?,
+                "DURATION" => set_once(&mut duration, parse_duration(value)?, line)?,
+                "RRULE" => rules.push(value.parse()?),
+                "RDATE" => parse_dates(&mut dates)?,
+                "EXDATE" => parse_dates(&mut exceptions)?,
+                "EXRULE" => return Err(RecurrenceError::Unsupported(line.clone())),
+                _ => {}
+            }
+        }
+
+        if let Some(component) = components.pop() {
+            return Err(RecurrenceError::Invalid(format!("BEGIN:{component}")));
+        }
+
+        let (start, is_date) = start.ok_or(RecurrenceError::MissingStart)?;
+        let duration = match (end, duration) {
+            (Some(_), Some(_)) => return Err(invalid("DTEND and DURATION")),
+            (Some(end), None) if end >= start => end - start,
+            (Some(_), None) => return Err(invalid("DTEND")),
+            (None, Some(duration)) => duration,
+            (None, None) if is_date => Duration::days(1),
+            (None, None) => Duration::zero(),
+        };
+
+        Ok(RecurringEvent {
+            start,
+            duration,
+            rules,
+            dates,
+            exceptions,
+        })
+    }
+}
+
+/// The occurrences of a [`RecurringEvent`] intersecting a window, see [`RecurringEvent::occurrences`]
+pub struct Occurrences<'a> {
+    event: &'a RecurringEvent,
+    window: ContinuousRange<NaiveDateTime>,
+    limit: NaiveDateTime,
+    rules: Vec<Peekable<Expansion>>,
+    dates: Peekable<vec::IntoIter<NaiveDateTime>>,
+    skip_exceptions: bool,
+}
+
+impl Occurrences<'_> {
+    /// The start of the next occurrence that may intersect the window
+    fn next_start(&mut self) -> Option<NaiveDateTime> {
+        loop {
+            let mut next: Option<NaiveDateTime> = self.dates.peek().copied();
+            for rule in &mut self.rules {
+                if let Some(candidate) = rule.peek() {
+                    if next.map_or(true, |next| candidate.cmp(&next) == Ordering::Less) {
+                        next = Some(*candidate);
+                    }
+                }
+            }
+
+            let start = next?;
+            if start > self.limit {
+                return None;
+            }
+
+            // Occurrences produced by multiple sources are only returned once
+            if self.dates.peek() == Some(&start) {
+                self.dates.next();
+            }
+            for rule in &mut self.rules {
+                if rule.peek() == Some(&start) {
+                    rule.next();
+                }
+            }
+
+            if self.skip_exceptions && self.event.exceptions.contains(&start) {
+                continue;
+            }
+            if !self
+                .event
+                .occurrence(start)
+                .intersection(&self.window)
+                .is_empty()
+            {
+                return Some(start);
+            }
+        }
+    }
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = ContinuousRange<NaiveDateTime>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next_start()?;
+        Some(self.event.occurrence(start))
+    }
+}
@@ -0,0 +1,464 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::{ContinuousRange, RecurringEvent};
+
+fn time(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+/// The starts of the occurrences of an event with `rule`, starting at `start`, during the years 1997 to 2000
+fn starts(start: NaiveDateTime, rule: &str) -> Vec<NaiveDateTime> {
+    let event =
+        RecurringEvent::new(start, chrono::Duration::zero()).with_rule(rule.parse().unwrap());
+    let window = ContinuousRange::EndExclusive(time(1997, 1, 1, 0), time(2001, 1, 1, 0));
+    event
+        .occurrences(&window)
+        .unwrap()
+        .map(|occurrence| match occurrence {
+            // Occurrences without duration are single values
+            ContinuousRange::Single(start) => start,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+mod test_rule_parsing {
+    use crate::{Frequency, RecurrenceError, RecurrenceRule};
+
+    use super::time;
+
+    #[test]
+    pub fn parts() {
+        let rule: RecurrenceRule = "RRULE:FREQ=DAILY;INTERVAL=3;UNTIL=19971224T000000Z"
+            .parse()
+            .unwrap();
+        assert_eq!(rule.frequency(), Frequency::Daily);
+        assert_eq!(rule.interval(), 3);
+        assert_eq!(rule.count(), None);
+        assert_eq!(rule.until(), Some(time(1997, 12, 24, 0)));
+
+        let rule: RecurrenceRule = "freq=yearly;until=19971224".parse().unwrap();
+        assert_eq!(rule.frequency(), Frequency::Yearly);
+        assert_eq!(
+            rule.until(),
+            Some(time(1997, 12, 24, 23) + chrono::Duration::seconds(59 * 60 + 59))
+        );
+    }
+
+    #[test]
+    pub fn errors() {
+        let parse = |text: &str| text.parse::<RecurrenceRule>();
+        assert_eq!(parse("COUNT=3"), Err(RecurrenceError::MissingFrequency));
+        assert_eq!(
+            parse("FREQ=DAILY;COUNT=3;UNTIL=19971224T000000Z"),
+            Err(RecurrenceError::Invalid(
+                "FREQ=DAILY;COUNT=3;UNTIL=19971224T000000Z".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse("FREQ=YEARLY;BYWEEKNO=20"),
+            Err(RecurrenceError::Unsupported("BYWEEKNO=20".to_owned()))
+        );
+        assert_eq!(
+            parse("FREQ=DAILY;BYHOUR=24"),
+            Err(RecurrenceError::Invalid("BYHOUR=24".to_owned()))
+        );
+        assert_eq!(
+            parse("FREQ=DAILY;INTERVAL=0"),
+            Err(RecurrenceError::Invalid("INTERVAL=0".to_owned()))
+        );
+        assert_eq!(
+            parse("FREQ=DAILY;FREQ=WEEKLY"),
+            Err(RecurrenceError::Invalid("FREQ=WEEKLY".to_owned()))
+        );
+        assert_eq!(
+            parse("FREQ=FORTNIGHTLY"),
+            Err(RecurrenceError::Invalid("FREQ=FORTNIGHTLY".to_owned()))
+        );
+        assert!(parse("FREQ=WEEKLY;BYDAY=1MO").is_err());
+        assert!(parse("FREQ=MONTHLY;BYDAY=+1MO,-2FR").is_ok());
+    }
+}
+
+mod test_expansion {
+    use super::{starts, time};
+
+    #[test]
+    pub fn daily_count() {
+        let expected: Vec<_> = (2..12).map(|day| time(1997, 9, day, 9)).collect();
+        assert_eq!(starts(time(1997, 9, 2, 9), "FREQ=DAILY;COUNT=10"), expected);
+    }
+
+    #[test]
+    pub fn every_other_day_until() {
+        let result = starts(
+            time(1997, 9, 2, 9),
+            "FREQ=DAILY;INTERVAL=2;UNTIL=19970910T090000",
+        );
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 2, 9),
+                time(1997, 9, 4, 9),
+                time(1997, 9, 6, 9),
+                time(1997, 9, 8, 9),
+                time(1997, 9, 10, 9),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn weekly_days() {
+        let result = starts(
+            time(1997, 9, 2, 9),
+            "FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH",
+        );
+        let expected: Vec<_> = [
+            (9, 2),
+            (9, 4),
+            (9, 9),
+            (9, 11),
+            (9, 16),
+            (9, 18),
+            (9, 23),
+            (9, 25),
+            (9, 30),
+            (10, 2),
+        ]
+        .iter()
+        .map(|(month, day)| time(1997, *month, *day, 9))
+        .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    pub fn monthly_first_friday() {
+        let result = starts(time(1997, 9, 5, 9), "FREQ=MONTHLY;COUNT=4;BYDAY=1FR");
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 5, 9),
+                time(1997, 10, 3, 9),
+                time(1997, 11, 7, 9),
+                time(1997, 12, 5, 9),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn monthly_last_weekday() {
+        let result = starts(
+            time(1997, 9, 30, 9),
+            "FREQ=MONTHLY;COUNT=5;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+        );
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 30, 9),
+                time(1997, 10, 31, 9),
+                time(1997, 11, 28, 9),
+                time(1997, 12, 31, 9),
+                time(1998, 1, 30, 9),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn monthly_skips_short_months() {
+        let result = starts(time(1997, 1, 31, 9), "FREQ=MONTHLY;COUNT=4");
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 1, 31, 9),
+                time(1997, 3, 31, 9),
+                time(1997, 5, 31, 9),
+                time(1997, 7, 31, 9),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn friday_the_thirteenth() {
+        let result = starts(time(1997, 9, 2, 9), "FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13");
+        // The start is always an occurrence, even when it doesn't match the rule
+        assert_eq!(
+            result[..4],
+            [
+                time(1997, 9, 2, 9),
+                time(1998, 2, 13, 9),
+                time(1998, 3, 13, 9),
+                time(1998, 11, 13, 9)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn yearly_months() {
+        let result = starts(time(1997, 6, 10, 9), "FREQ=YEARLY;COUNT=4;BYMONTH=6,7");
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 6, 10, 9),
+                time(1997, 7, 10, 9),
+                time(1998, 6, 10, 9),
+                time(1998, 7, 10, 9),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn yearly_last_sunday() {
+        let result = starts(time(1997, 12, 28, 9), "FREQ=YEARLY;BYDAY=-1SU");
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 12, 28, 9),
+                time(1998, 12, 27, 9),
+                time(1999, 12, 26, 9),
+                time(2000, 12, 31, 9),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn hourly_interval() {
+        let result = starts(
+            time(1997, 9, 2, 9),
+            "FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z",
+        );
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 2, 9),
+                time(1997, 9, 2, 12),
+                time(1997, 9, 2, 15)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn daily_hours() {
+        let result = starts(time(1997, 9, 2, 9), "FREQ=DAILY;BYHOUR=9,17;COUNT=3");
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 2, 9),
+                time(1997, 9, 2, 17),
+                time(1997, 9, 3, 9)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn sub_daily_skips_excluded_days() {
+        let result = starts(time(1997, 9, 2, 9), "FREQ=SECONDLY;BYMONTH=12;COUNT=2");
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 2, 9),
+                time(1997, 12, 1, 0),
+                time(1997, 12, 1, 0) + chrono::Duration::seconds(1)
+            ]
+        );
+
+        let result = starts(
+            time(1997, 9, 2, 9),
+            "FREQ=MINUTELY;INTERVAL=7;BYHOUR=3;BYDAY=SA;COUNT=3",
+        );
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 2, 9),
+                time(1997, 9, 6, 3) + chrono::Duration::minutes(4),
+                time(1997, 9, 6, 3) + chrono::Duration::minutes(11),
+                time(1997, 9, 6, 3) + chrono::Duration::minutes(18)
+            ]
+        );
+
+        let result = starts(
+            time(1997, 9, 2, 9),
+            "FREQ=SECONDLY;INTERVAL=40;BYHOUR=10;BYMINUTE=30;COUNT=2",
+        );
+        assert_eq!(
+            result,
+            vec![
+                time(1997, 9, 2, 9),
+                time(1997, 9, 2, 10) + chrono::Duration::minutes(30),
+                time(1997, 9, 2, 10) + chrono::Duration::seconds(30 * 60 + 40)
+            ]
+        );
+    }
+
+    #[test]
+    pub fn far_window() {
+        use crate::{ContinuousRange, RecurringEvent};
+
+        // Periods before the window are skipped without changing the result
+        let event = RecurringEvent::new(time(1900, 1, 1, 9), chrono::Duration::hours(1))
+            .with_rule("FREQ=WEEKLY;BYDAY=SA".parse().unwrap());
+        let window = ContinuousRange::Inclusive(time(2000, 1, 1, 0), time(2000, 1, 10, 0));
+        let occurrences: Vec<_> = event.occurrences(&window).unwrap().collect();
+        assert_eq!(
+            occurrences,
+            vec![
+                ContinuousRange::EndExclusive(time(2000, 1, 1, 9), time(2000, 1, 1, 10)),
+                ContinuousRange::EndExclusive(time(2000, 1, 8, 9), time(2000, 1, 8, 10)),
+            ]
+        );
+    }
+}
+
+mod test_event {
+    use chrono::Duration;
+
+    use crate::{ContinuousRange, Range, RecurrenceError, RecurringEvent};
+
+    use super::time;
+
+    #[test]
+    pub fn parse() {
+        let event: RecurringEvent = "BEGIN:VEVENT\r\n\
+            SUMMARY:Stand-up\r\n\
+            DTSTART;TZID=Europe/Paris:19970902T090000\r\n\
+            DTEND;TZID=Europe/Paris:19970902T093000\r\n\
+            RRULE:FREQ=WEEKLY;BYDAY=MO,\r\n TU\r\n\
+            RDATE:19970906T090000,19970907T090000\r\n\
+            EXDATE:19970908T090000\r\n\
+            END:VEVENT\r\n"
+            .parse()
+            .unwrap();
+        assert_eq!(event.start(), time(1997, 9, 2, 9));
+        assert_eq!(event.duration(), Duration::minutes(30));
+
+        let window = ContinuousRange::EndExclusive(time(1997, 9, 1, 0), time(1997, 9, 10, 0));
+        assert_eq!(event.occurrences(&window).unwrap().count(), 4);
+        assert_eq!(
+            event.occurrences(&window).unwrap().last(),
+            Some(ContinuousRange::EndExclusive(
+                time(1997, 9, 9, 9),
+                time(1997, 9, 9, 9) + Duration::minutes(30)
+            ))
+        );
+    }
+
+    #[test]
+    pub fn nested_components() {
+        let event: RecurringEvent = "BEGIN:VCALENDAR\n\
+            VERSION:2.0\n\
+            BEGIN:VEVENT\n\
+            DTSTART:19970902T090000\n\
+            DURATION:PT1H\n\
+            BEGIN:VALARM\n\
+            TRIGGER:-PT15M\n\
+            DURATION:PT5M\n\
+            REPEAT:2\n\
+            END:VALARM\n\
+            END:VEVENT\n\
+            END:VCALENDAR"
+            .parse()
+            .unwrap();
+        assert_eq!(event.duration(), Duration::hours(1));
+
+        let event: RecurringEvent = "BEGIN:VEVENT\n\
+            DTSTART:19970902T090000\n\
+            BEGIN:VALARM\n\
+            DURATION:PT5M\n\
+            END:VALARM\n\
+            DTEND:19970902T093000\n\
+            END:VEVENT"
+            .parse()
+            .unwrap();
+        assert_eq!(event.duration(), Duration::minutes(30));
+    }
+
+    #[test]
+    pub fn date_start() {
+        let event: RecurringEvent = "DTSTART;VALUE=DATE:19970902\nRRULE:FREQ=YEARLY;COUNT=2"
+            .parse()
+            .unwrap();
+        assert_eq!(event.duration(), Duration::days(1));
+        let window = ContinuousRange::From(time(1997, 1, 1, 0));
+        assert!(event.occurrences(&window).is_none());
+
+        let window = ContinuousRange::Inclusive(time(1997, 1, 1, 0), time(2000, 1, 1, 0));
+        assert_eq!(
+            event.expand(&window),
+            Some(Range::Composite(vec![
+                ContinuousRange::EndExclusive(time(1997, 9, 2, 0), time(1997, 9, 3, 0)),
+                ContinuousRange::EndExclusive(time(1998, 9, 2, 0), time(1998, 9, 3, 0)),
+            ]))
+        );
+    }
+
+    #[test]
+    pub fn errors() {
+        let parse = |text: &str| text.parse::<RecurringEvent>();
+        assert_eq!(
+            parse("RRULE:FREQ=DAILY"),
+            Err(RecurrenceError::MissingStart)
+        );
+        assert_eq!(
+            parse("DTSTART:1997090"),
+            Err(RecurrenceError::Invalid("1997090".to_owned()))
+        );
+        assert_eq!(
+            parse("DTSTART:19970902T090000\nDURATION:-PT1H"),
+            Err(RecurrenceError::Unsupported("-PT1H".to_owned()))
+        );
+        assert_eq!(
+            parse("DTSTART:19970902T090000\nDURATION:PT1X"),
+            Err(RecurrenceError::Invalid("PT1X".to_owned()))
+        );
+        assert!(matches!(
+            parse("DTSTART:19970902T090000\nRDATE;VALUE=PERIOD:19970902T090000Z/PT1H"),
+            Err(RecurrenceError::Unsupported(_))
+        ));
+        assert_eq!(
+            parse("DTSTART:19970902T090000\nDTSTART:19970903T090000"),
+            Err(RecurrenceError::Invalid(
+                "DTSTART:19970903T090000".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse("DTSTART:19970902T090000\nDURATION:PT1H\nDURATION:PT2H"),
+            Err(RecurrenceError::Invalid("DURATION:PT2H".to_owned()))
+        );
+        assert_eq!(
+            parse("BEGIN:VEVENT\nDTSTART:19970902T090000\nEND:VALARM"),
+            Err(RecurrenceError::Invalid("END:VALARM".to_owned()))
+        );
+        assert_eq!(
+            parse("BEGIN:VEVENT\nDTSTART:19970902T090000"),
+            Err(RecurrenceError::Invalid("BEGIN:VEVENT".to_owned()))
+        );
+    }
+
+    #[test]
+    pub fn expand_clips_and_subtracts() {
+        let event = RecurringEvent::new(time(2023, 1, 2, 9), Duration::hours(2))
+            .with_rule("FREQ=DAILY;COUNT=4".parse().unwrap())
+            .with_date(time(2023, 1, 3, 10))
+            .with_exception(time(2023, 1, 3, 9));
+        let window = ContinuousRange::Exclusive(time(2023, 1, 2, 10), time(2023, 1, 5, 10));
+        assert_eq!(
+            event.expand(&window),
+            Some(Range::Composite(vec![
+                ContinuousRange::Exclusive(time(2023, 1, 2, 10), time(2023, 1, 2, 11)),
+                // The excluded occurrence from 9 to 11 is also removed from the added one from 10 to 12
+                ContinuousRange::EndExclusive(time(2023, 1, 3, 11), time(2023, 1, 3, 12)),
+                ContinuousRange::EndExclusive(time(2023, 1, 4, 9), time(2023, 1, 4, 11)),
+                ContinuousRange::EndExclusive(time(2023, 1, 5, 9), time(2023, 1, 5, 10)),
+            ]))
+        );
+
+        let occurrences: Vec<_> = event.occurrences(&window).unwrap().collect();
+        assert_eq!(occurrences.len(), 4);
+        assert_eq!(
+            occurrences[1],
+            ContinuousRange::EndExclusive(time(2023, 1, 3, 10), time(2023, 1, 3, 12))
+        );
+    }
+}
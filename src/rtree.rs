@@ -0,0 +1,385 @@
+use std::{cmp::Ordering, convert::TryFrom, fmt, iter::FromIterator};
+
+use crate::{
+    continuous::{partial_cmp_bounds, BoundSide},
+    ContinuousRange, RangeBox,
+};
+
+/// The maximum number of entries of a node, a node that grows past it is split in two
+const MAX_ENTRIES: usize = 8;
+
+/// Boxes with the value or the node they bound
+type Entries<const N: usize, Idx, T> = Vec<(RangeBox<N, Idx>, T)>;
+
+#[derive(Clone, Debug)]
+enum Node<const N: usize, Idx, V> {
+    Leaf(Entries<N, Idx, V>),
+    Branch(Entries<N, Idx, Node<N, Idx, V>>),
+}
+
+impl<const N: usize, Idx: PartialOrd + Clone, V> Node<N, Idx, V> {
+    fn is_empty(&self) -> bool {
+        match self {
+            Node::Leaf(entries) => entries.is_empty(),
+            Node::Branch(children) => children.is_empty(),
+        }
+    }
+
+    fn bounds(&self) -> RangeBox<N, Idx> {
+        match self {
+            Node::Leaf(entries) => bounds_of(entries),
+            Node::Branch(children) => bounds_of(children),
+        }
+    }
+}
+
+/// The smallest box containing all the boxes of `entries`
+fn bounds_of<const N: usize, Idx: PartialOrd + Clone, T>(
+    entries: &[(RangeBox<N, Idx>, T)],
+) -> RangeBox<N, Idx> {
+    entries
+        .iter()
+        .fold(RangeBox::empty(), |bounds, (range_box, _)| {
+            bounds.hull(range_box)
+        })
+}
+
+/// Order ranges by their start, empty ranges last
+fn cmp_starts<Idx: PartialOrd + Clone>(
+    a: &ContinuousRange<Idx>,
+    b: &ContinuousRange<Idx>,
+) -> Ordering {
+    match (a.start(), b.start()) {
+        (Some(a), Some(b)) => partial_cmp_bounds(&a, BoundSide::Start, &b, BoundSide::Start)
+            .unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn sort_by_axis<const N: usize, Idx: PartialOrd + Clone, T>(
+    entries: &mut [(RangeBox<N, Idx>, T)],
+    axis: usize,
+) {
+    if axis < N {
+        entries.sort_by(|(a, _), (b, _)| cmp_starts(a.axis(axis), b.axis(axis)));
+    }
+}
+
+/// Split `items` in consecutive groups of `size` items, the last one being smaller if needed
+fn chunks<T>(mut items: Vec<T>, size: usize) -> Vec<Vec<T>> {
+    let mut chunks = Vec::new();
+    while items.len() > size {
+        let rest = items.split_off(size);
+        chunks.push(items);
+        items = rest;
+    }
+    if !items.is_empty() {
+        chunks.push(items);
+    }
+    chunks
+}
+
+/// Group `entries` in nodes of at most [`MAX_ENTRIES`] with the Sort-Tile-Recursive algorithm, from `axis`
+fn tile<const N: usize, Idx: PartialOrd + Clone, T>(
+    mut entries: Entries<N, Idx, T>,
+    axis: usize,
+) -> Vec<Entries<N, Idx, T>> {
+    if entries.len() <= MAX_ENTRIES {
+        return chunks(entries, MAX_ENTRIES);
+    }
+
+    sort_by_axis(&mut entries, axis);
+    if axis + 1 >= N {
+        return chunks(entries, MAX_ENTRIES);
+    }
+
+    // Cut the entries in slabs along this axis so that each remaining axis gets the same number of cuts
+    let nodes = (entries.len() + MAX_ENTRIES - 1) / MAX_ENTRIES;
+    let dimensions = u32::try_from(N - axis).unwrap_or(u32::MAX);
+    let mut slabs: usize = 1;
+    while slabs
+        .checked_pow(dimensions)
+        .map_or(false, |total| total < nodes)
+    {
+        slabs += 1;
+    }
+    let slab_size = MAX_ENTRIES * ((nodes + slabs - 1) / slabs);
+
+    chunks(entries, slab_size)
+        .into_iter()
+        .flat_map(|slab| tile(slab, axis + 1))
+        .collect()
+}
+
+/// Split an overflowing node in two halves, along the first axis where their bounds don't intersect
+fn split<const N: usize, Idx: PartialOrd + Clone + fmt::Debug, T>(
+    mut entries: Entries<N, Idx, T>,
+) -> (Entries<N, Idx, T>, Entries<N, Idx, T>) {
+    let half = entries.len() / 2;
+    let mut best = 0;
+    for axis in 0..N {
+        sort_by_axis(&mut entries, axis);
+        if !bounds_of(&entries[..half]).intersects(&bounds_of(&entries[half..])) {
+            best = axis;
+            break;
+        }
+    }
+
+    sort_by_axis(&mut entries, best);
+    let second = entries.split_off(half);
+    (entries, second)
+}
+
+/// The child that should receive `range_box`, one already containing it or the one growing on the fewest axes
+fn choose_child<const N: usize, Idx: PartialOrd + Clone + fmt::Debug, T>(
+    children: &[(RangeBox<N, Idx>, T)],
+    range_box: &RangeBox<N, Idx>,
+) -> usize {
+    let growth = |bounds: &RangeBox<N, Idx>| {
+        let hull = bounds.hull(range_box);
+        hull.axes()
+            .iter()
+            .zip(bounds.axes().iter())
+            .filter(|(grown, axis)| grown != axis)
+            .count()
+    };
+
+    children
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, (bounds, _))| growth(bounds))
+        .map_or(0, |(index, _)| index)
+}
+
+/// Insert the entry below `node`, returning the two halves of the node if it had to be split
+fn insert_into<const N: usize, Idx: PartialOrd + Clone + fmt::Debug, V>(
+    node: &mut Node<N, Idx, V>,
+    range_box: RangeBox<N, Idx>,
+    value: V,
+) -> Option<(Node<N, Idx, V>, Node<N, Idx, V>)> {
+    match node {
+        Node::Leaf(entries) => {
+            entries.push((range_box, value));
+            if entries.len() <= MAX_ENTRIES {
+                return None;
+            }
+
+            let (first, second) = split(std::mem::take(entries));
+            Some((Node::Leaf(first), Node::Leaf(second)))
+        }
+        Node::Branch(children) => {
+            let index = choose_child(children, &range_box);
+            let halves = {
+                let (bounds, child) = &mut children[index];
+                *bounds = bounds.hull(&range_box);
+                insert_into(child, range_box, value)
+            };
+
+            let (first, second) = halves?;
+            children[index] = (first.bounds(), first);
+            children.push((second.bounds(), second));
+            if children.len() <= MAX_ENTRIES {
+                return None;
+            }
+
+            let (first, second) = split(std::mem::take(children));
+            Some((Node::Branch(first), Node::Branch(second)))
+        }
+    }
+}
+
+/// Remove an entry with exactly `range_box` below `node`, removing the children left empty
+fn remove_from<const N: usize, Idx: PartialOrd + Clone + fmt::Debug, V>(
+    node: &mut Node<N, Idx, V>,
+    range_box: &RangeBox<N, Idx>,
+) -> Option<V> {
+    match node {
+        Node::Leaf(entries) => {
+            let index = entries.iter().position(|(entry, _)| entry == range_box)?;
+            Some(entries.remove(index).1)
+        }
+        Node::Branch(children) => {
+            for index in 0..children.len() {
+                let (bounds, child) = &mut children[index];
+                if !bounds.contains_box(range_box) {
+                    continue;
+                }
+
+                if let Some(value) = remove_from(child, range_box) {
+                    if child.is_empty() {
+                        children.remove(index);
+                    } else {
+                        *bounds = child.bounds();
+                    }
+                    return Some(value);
+                }
+            }
+            None
+        }
+    }
+}
+
+/// A spatial index of [`RangeBox`] entries with values, answering point and box queries
+///
+/// The boxes are grouped in a tree of nodes with their bounding box, queries only visit the nodes whose bounding
+/// box matches. The bounding boxes keep the exclusive and unbounded bounds of the boxes they contain, so
+/// queries on the edges of the boxes are exact.
+///
+/// ```
+/// use range_ranger::{ContinuousRange, RTree, RangeBox};
+///
+/// // Tariffs by hour of the day and consumption
+/// let tariffs = RTree::bulk_load(vec![
+///     (RangeBox::new([ContinuousRange::EndExclusive(0, 8), ContinuousRange::From(0)]), "night"),
+///     (RangeBox::new([ContinuousRange::EndExclusive(8, 24), ContinuousRange::EndExclusive(0, 100)]), "day"),
+///     (RangeBox::new([ContinuousRange::EndExclusive(8, 24), ContinuousRange::From(100)]), "peak"),
+/// ]);
+///
+/// let found: Vec<_> = tariffs.locate(&[12, 150]).into_iter().map(|(_, tariff)| *tariff).collect();
+/// assert_eq!(found, vec!["peak"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RTree<const N: usize, Idx, V> {
+    root: Node<N, Idx, V>,
+    len: usize,
+}
+
+impl<const N: usize, Idx, V> Default for RTree<N, Idx, V> {
+    fn default() -> Self {
+        RTree {
+            root: Node::Leaf(Vec::new()),
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize, Idx: PartialOrd + Clone + fmt::Debug, V> FromIterator<(RangeBox<N, Idx>, V)>
+    for RTree<N, Idx, V>
+{
+    fn from_iter<T: IntoIterator<Item = (RangeBox<N, Idx>, V)>>(iter: T) -> Self {
+        Self::bulk_load(iter.into_iter().collect())
+    }
+}
+
+impl<const N: usize, Idx: PartialOrd + Clone + fmt::Debug, V> RTree<N, Idx, V> {
+    /// An index without any entry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An index of all the `entries`, packed with the Sort-Tile-Recursive algorithm
+    ///
+    /// Building the index at once produces fuller nodes that don't overlap as much as inserting the entries one
+    /// by one.
+    #[must_use]
+    pub fn bulk_load(entries: Vec<(RangeBox<N, Idx>, V)>) -> Self {
+        let len = entries.len();
+        let mut nodes: Vec<_> = tile(entries, 0)
+            .into_iter()
+            .map(|group| {
+                let node = Node::Leaf(group);
+                (node.bounds(), node)
+            })
+            .collect();
+
+        while nodes.len() > 1 {
+            nodes = tile(nodes, 0)
+                .into_iter()
+                .map(|group| {
+                    let node = Node::Branch(group);
+                    (node.bounds(), node)
+                })
+                .collect();
+        }
+
+        match nodes.pop() {
+            Some((_, root)) => RTree { root, len },
+            None => Self::new(),
+        }
+    }
+
+    /// The number of entries
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the index doesn't contain any entry
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add an entry, boxes don't need to be unique
+    pub fn insert(&mut self, range_box: RangeBox<N, Idx>, value: V) {
+        if let Some((first, second)) = insert_into(&mut self.root, range_box, value) {
+            self.root = Node::Branch(vec![(first.bounds(), first), (second.bounds(), second)]);
+        }
+        self.len += 1;
+    }
+
+    /// Remove one of the entries with a box equal to `range_box` and return its value
+    pub fn remove(&mut self, range_box: &RangeBox<N, Idx>) -> Option<V> {
+        let value = remove_from(&mut self.root, range_box)?;
+        self.len -= 1;
+
+        // Keep the root as low as possible, all the leaves stay at the same depth
+        loop {
+            match &mut self.root {
+                Node::Branch(children) if children.len() <= 1 => {
+                    self.root = match children.pop() {
+                        Some((_, child)) => child,
+                        None => Node::Leaf(Vec::new()),
+                    };
+                }
+                _ => break,
+            }
+        }
+
+        Some(value)
+    }
+
+    /// The entries with a box containing `point`
+    #[must_use]
+    pub fn locate(&self, point: &[Idx; N]) -> Vec<(&RangeBox<N, Idx>, &V)> {
+        self.search(|range_box| range_box.contains(point))
+    }
+
+    /// The entries with a box intersecting `range_box`
+    #[must_use]
+    pub fn intersecting(&self, range_box: &RangeBox<N, Idx>) -> Vec<(&RangeBox<N, Idx>, &V)> {
+        self.search(|other| other.intersects(range_box))
+    }
+
+    /// All the entries, in no particular order
+    #[must_use]
+    pub fn entries(&self) -> Vec<(&RangeBox<N, Idx>, &V)> {
+        self.search(|_| true)
+    }
+
+    /// The entries with a box matching `filter`, that must also match all the boxes containing them
+    fn search(&self, filter: impl Fn(&RangeBox<N, Idx>) -> bool) -> Vec<(&RangeBox<N, Idx>, &V)> {
+        let mut found = Vec::new();
+        let mut nodes = vec![&self.root];
+        while let Some(node) = nodes.pop() {
+            match node {
+                Node::Leaf(entries) => found.extend(
+                    entries
+                        .iter()
+                        .filter(|(range_box, _)| filter(range_box))
+                        .map(|(range_box, value)| (range_box, value)),
+                ),
+                Node::Branch(children) => nodes.extend(
+                    children
+                        .iter()
+                        .filter(|(bounds, _)| filter(bounds))
+                        .map(|(_, child)| child),
+                ),
+            }
+        }
+        found
+    }
+}
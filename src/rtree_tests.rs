@@ -0,0 +1,175 @@
+mod test_rtree {
+    use std::convert::TryFrom;
+
+    use crate::{ContinuousRange, RTree, RangeBox};
+
+    /// Deterministic pseudo-random boxes of all the kinds of ranges
+    fn random_boxes(count: usize) -> Vec<(RangeBox<2, i32>, usize)> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move |modulo: u64| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            i32::try_from((state >> 33) % modulo).unwrap()
+        };
+
+        let mut range = move || {
+            let start = next(100);
+            let end = start + next(20);
+            match next(10) {
+                0 => ContinuousRange::From(start),
+                1 => ContinuousRange::ToExclusive(end),
+                2 => ContinuousRange::Single(start),
+                3 => ContinuousRange::Exclusive(start, end),
+                4 => ContinuousRange::StartExclusive(start, end),
+                5 | 6 => ContinuousRange::EndExclusive(start, end),
+                _ => ContinuousRange::Inclusive(start, end),
+            }
+        };
+
+        (0..count)
+            .map(|index| (RangeBox::new([range(), range()]), index))
+            .collect()
+    }
+
+    fn sorted(found: Vec<(&RangeBox<2, i32>, &usize)>) -> Vec<usize> {
+        let mut values: Vec<_> = found.into_iter().map(|(_, value)| *value).collect();
+        values.sort_unstable();
+        values
+    }
+
+    fn brute_force(
+        entries: &[(RangeBox<2, i32>, usize)],
+        filter: impl Fn(&RangeBox<2, i32>) -> bool,
+    ) -> Vec<usize> {
+        entries
+            .iter()
+            .filter(|(range_box, _)| filter(range_box))
+            .map(|(_, value)| *value)
+            .collect()
+    }
+
+    fn check_queries(tree: &RTree<2, i32, usize>, entries: &[(RangeBox<2, i32>, usize)]) {
+        assert_eq!(tree.len(), entries.len());
+        assert_eq!(sorted(tree.entries()), brute_force(entries, |_| true));
+
+        for x in (-5..130).step_by(7) {
+            for y in (-5..130).step_by(5) {
+                let point = [x, y];
+                assert_eq!(
+                    sorted(tree.locate(&point)),
+                    brute_force(entries, |range_box| range_box.contains(&point))
+                );
+            }
+        }
+
+        for (query, _) in random_boxes(50) {
+            assert_eq!(
+                sorted(tree.intersecting(&query)),
+                brute_force(entries, |range_box| range_box.intersects(&query))
+            );
+        }
+    }
+
+    #[test]
+    pub fn bulk_load() {
+        let entries = random_boxes(500);
+        let tree = RTree::bulk_load(entries.clone());
+        check_queries(&tree, &entries);
+
+        let collected: RTree<2, i32, usize> = entries.iter().cloned().collect();
+        check_queries(&collected, &entries);
+    }
+
+    #[test]
+    pub fn insert() {
+        let entries = random_boxes(500);
+        let mut tree = RTree::new();
+        for (range_box, value) in entries.iter().cloned() {
+            tree.insert(range_box, value);
+        }
+        check_queries(&tree, &entries);
+    }
+
+    #[test]
+    pub fn remove() {
+        let mut entries = random_boxes(300);
+        let mut tree = RTree::bulk_load(entries.clone());
+
+        let removed: Vec<_> = entries.drain(100..250).collect();
+        for (range_box, _) in &removed {
+            assert!(tree.remove(range_box).is_some());
+        }
+        // The removed values can differ from the removed entries when boxes are duplicated
+        let mut values: Vec<_> = entries
+            .iter()
+            .map(|(range_box, _)| range_box.clone())
+            .collect();
+        let mut remaining: Vec<_> = tree
+            .entries()
+            .into_iter()
+            .map(|(range_box, _)| range_box.clone())
+            .collect();
+        let key = |range_box: &RangeBox<2, i32>| format!("{range_box:?}");
+        values.sort_by_key(key);
+        remaining.sort_by_key(key);
+        assert_eq!(values, remaining);
+        assert_eq!(tree.len(), 150);
+
+        for (range_box, _) in &entries {
+            assert!(tree.remove(range_box).is_some());
+        }
+        assert!(tree.is_empty());
+        assert!(tree.entries().is_empty());
+        assert_eq!(tree.remove(&removed[0].0), None);
+
+        tree.insert(RangeBox::full(), 1);
+        assert_eq!(sorted(tree.locate(&[3, 4])), vec![1]);
+    }
+
+    #[test]
+    pub fn exclusive_and_unbounded_bounds() {
+        let tree = RTree::bulk_load(vec![
+            (
+                RangeBox::new([ContinuousRange::EndExclusive(0, 10), ContinuousRange::Full]),
+                "left",
+            ),
+            (
+                RangeBox::new([ContinuousRange::Inclusive(10, 20), ContinuousRange::To(0)]),
+                "bottom right",
+            ),
+            (
+                RangeBox::new([
+                    ContinuousRange::Inclusive(10, 20),
+                    ContinuousRange::FromExclusive(0),
+                ]),
+                "top right",
+            ),
+        ]);
+
+        let values = |point| {
+            tree.locate(&point)
+                .into_iter()
+                .map(|(_, value)| *value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values([10, 0]), vec!["bottom right"]);
+        assert_eq!(values([10, 1]), vec!["top right"]);
+        assert_eq!(values([9, i32::MIN]), vec!["left"]);
+        assert!(values([21, 0]).is_empty());
+
+        let query = RangeBox::new([
+            ContinuousRange::Exclusive(5, 10),
+            ContinuousRange::Single(0),
+        ]);
+        assert_eq!(tree.intersecting(&query).len(), 1);
+    }
+
+    #[test]
+    pub fn empty() {
+        let tree = RTree::<2, i32, ()>::bulk_load(Vec::new());
+        assert!(tree.is_empty());
+        assert!(tree.locate(&[0, 0]).is_empty());
+        assert!(tree.intersecting(&RangeBox::full()).is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul, Sub},
+};
+
+use crate::{continuous::map_bound, ContinuousRange, Magnitude, Range, RangeBuilder};
+
+impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
+    /// Apply `start` to the start bound and `end` to the end bound, keeping their kind
+    fn map_each_bound(
+        &self,
+        start: impl FnOnce(Idx) -> Idx,
+        end: impl FnOnce(Idx) -> Idx,
+    ) -> ContinuousRange<Idx> {
+        if self.is_empty() {
+            return ContinuousRange::Empty;
+        }
+
+        match self.range_bounds() {
+            Some((start_bound, end_bound)) => {
+                // Bounds crossing each other produce an empty range
+                RangeBuilder::new()
+                    .start_bound(map_bound(start_bound, start))
+                    .end_bound(map_bound(end_bound, end))
+                    .build()
+                    .unwrap_or(ContinuousRange::Empty)
+            }
+            None => ContinuousRange::Empty,
+        }
+    }
+
+    /// Move the range by adding `delta` to both of its bounds
+    ///
+    /// The kind of each bound is preserved and unbounded ends stay unbounded.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::EndExclusive(1, 5);
+    /// assert_eq!(range.shift(10), ContinuousRange::EndExclusive(11, 15));
+    /// assert_eq!(range - 1, ContinuousRange::EndExclusive(0, 4));
+    /// ```
+    #[must_use]
+    pub fn shift<D>(&self, delta: D) -> ContinuousRange<Idx>
+    where
+        Idx: Add<D, Output = Idx>,
+        D: Clone,
+    {
+        let end_delta = delta.clone();
+        self.map_each_bound(|start| start + delta, |end| end + end_delta)
+    }
+
+    /// Multiply both bounds of the range by `factor`
+    ///
+    /// A negative factor mirrors the range, swapping its bounds. The factor is expected to be non-zero.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// let range = ContinuousRange::StartExclusive(1.0, 5.0);
+    /// assert_eq!(range.scale(2.0), ContinuousRange::StartExclusive(2.0, 10.0));
+    /// assert_eq!(range.scale(-1.0), ContinuousRange::EndExclusive(-5.0, -1.0));
+    /// ```
+    #[must_use]
+    pub fn scale<F>(&self, factor: F) -> ContinuousRange<Idx>
+    where
+        Idx: Mul<F, Output = Idx>,
+        F: Magnitude,
+    {
+        if self.is_empty() {
+            return ContinuousRange::Empty;
+        }
+
+        let (start, end) = match self.range_bounds() {
+            Some(bounds) => bounds,
+            None => return ContinuousRange::Empty,
+        };
+
+        let negative = factor.partial_cmp(&F::zero()) == Some(Ordering::Less);
+        let end_factor = factor.clone();
+        let start = map_bound(start, |start| start * factor);
+        let end = map_bound(end, |end| end * end_factor);
+        let (start, end) = if negative { (end, start) } else { (start, end) };
+        RangeBuilder::new()
+            .start_bound(start)
+            .end_bound(end)
+            .build()
+            .unwrap_or(ContinuousRange::Empty)
+    }
+
+    /// Pad the range by moving its start `before` lower and its end `after` higher
+    ///
+    /// The kind of each bound is preserved and unbounded ends stay unbounded.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// assert_eq!(ContinuousRange::Single(5).expand(1, 2), ContinuousRange::Inclusive(4, 7));
+    /// ```
+    #[must_use]
+    pub fn expand<D>(&self, before: D, after: D) -> ContinuousRange<Idx>
+    where
+        Idx: Add<D, Output = Idx> + Sub<D, Output = Idx>,
+    {
+        self.map_each_bound(|start| start - before, |end| end + after)
+    }
+
+    /// Reduce the range by moving its start `before` higher and its end `after` lower
+    ///
+    /// The kind of each bound is preserved and unbounded ends stay unbounded. The result is empty if the bounds
+    /// cross.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// assert_eq!(ContinuousRange::Inclusive(0, 10).shrink(1, 2), ContinuousRange::Inclusive(1, 8));
+    /// assert_eq!(ContinuousRange::Inclusive(0, 10).shrink(6, 6), ContinuousRange::Empty);
+    /// ```
+    #[must_use]
+    pub fn shrink<D>(&self, before: D, after: D) -> ContinuousRange<Idx>
+    where
+        Idx: Add<D, Output = Idx> + Sub<D, Output = Idx>,
+    {
+        self.map_each_bound(|start| start + before, |end| end - after)
+    }
+}
+
+impl<Idx: PartialOrd + Clone> Range<Idx> {
+    fn map_pieces(&self, f: impl Fn(&ContinuousRange<Idx>) -> ContinuousRange<Idx>) -> Range<Idx> {
+        match self {
+            Range::Continuous(range) => Range::Continuous(f(range)),
+            Range::Composite(pieces) => {
+                Range::composite(pieces.iter().map(|piece| Range::Continuous(f(piece))))
+            }
+        }
+    }
+
+    /// Move each part of the range by `delta`, see [`ContinuousRange::shift`]
+    #[must_use]
+    pub fn shift<D>(&self, delta: D) -> Range<Idx>
+    where
+        Idx: Add<D, Output = Idx>,
+        D: Clone,
+    {
+        self.map_pieces(|piece| piece.shift(delta.clone()))
+    }
+
+    /// Multiply the bounds of each part of the range by `factor`, see [`ContinuousRange::scale`]
+    #[must_use]
+    pub fn scale<F>(&self, factor: F) -> Range<Idx>
+    where
+        Idx: Mul<F, Output = Idx>,
+        F: Magnitude,
+    {
+        self.map_pieces(move |piece| piece.scale(factor.clone()))
+    }
+
+    /// Pad each part of the range, see [`ContinuousRange::expand`]
+    ///
+    /// Parts may overlap after being expanded, use [`Range::simplify`] to merge them.
+    #[must_use]
+    pub fn expand<D>(&self, before: D, after: D) -> Range<Idx>
+    where
+        Idx: Add<D, Output = Idx> + Sub<D, Output = Idx>,
+        D: Clone,
+    {
+        self.map_pieces(|piece| piece.expand(before.clone(), after.clone()))
+    }
+
+    /// Reduce each part of the range, see [`ContinuousRange::shrink`]
+    #[must_use]
+    pub fn shrink<D>(&self, before: D, after: D) -> Range<Idx>
+    where
+        Idx: Add<D, Output = Idx> + Sub<D, Output = Idx>,
+        D: Clone,
+    {
+        self.map_pieces(|piece| piece.shrink(before.clone(), after.clone()))
+    }
+}
+
+impl<Idx, D> Add<D> for ContinuousRange<Idx>
+where
+    Idx: PartialOrd + Clone + Add<D, Output = Idx>,
+    D: Magnitude,
+{
+    type Output = ContinuousRange<Idx>;
+
+    fn add(self, rhs: D) -> Self::Output {
+        self.shift(rhs)
+    }
+}
+
+impl<Idx, D> Sub<D> for ContinuousRange<Idx>
+where
+    Idx: PartialOrd + Clone + Sub<D, Output = Idx>,
+    D: Magnitude,
+{
+    type Output = ContinuousRange<Idx>;
+
+    fn sub(self, rhs: D) -> Self::Output {
+        let end_rhs = rhs.clone();
+        self.map_each_bound(|start| start - rhs, |end| end - end_rhs)
+    }
+}
+
+impl<Idx, D> Add<D> for Range<Idx>
+where
+    Idx: PartialOrd + Clone + Add<D, Output = Idx>,
+    D: Magnitude,
+{
+    type Output = Range<Idx>;
+
+    fn add(self, rhs: D) -> Self::Output {
+        self.shift(rhs)
+    }
+}
+
+impl<Idx, D> Sub<D> for Range<Idx>
+where
+    Idx: PartialOrd + Clone + Sub<D, Output = Idx>,
+    D: Magnitude,
+{
+    type Output = Range<Idx>;
+
+    fn sub(self, rhs: D) -> Self::Output {
+        self.map_pieces(|piece| piece.clone() - rhs.clone())
+    }
+}
@@ -0,0 +1,207 @@
+mod test_shift {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn keeps_bound_kinds() {
+        assert_eq!(
+            ContinuousRange::Single(1).shift(2),
+            ContinuousRange::Single(3)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 5).shift(2),
+            ContinuousRange::Inclusive(3, 7)
+        );
+        assert_eq!(
+            ContinuousRange::Exclusive(1, 5).shift(-2),
+            ContinuousRange::Exclusive(-1, 3)
+        );
+        assert_eq!(
+            ContinuousRange::StartExclusive(1, 5).shift(2),
+            ContinuousRange::StartExclusive(3, 7)
+        );
+        assert_eq!(
+            ContinuousRange::FromExclusive(1).shift(2),
+            ContinuousRange::FromExclusive(3)
+        );
+        assert_eq!(ContinuousRange::To(1).shift(2), ContinuousRange::To(3));
+        assert_eq!(ContinuousRange::<i32>::Full.shift(2), ContinuousRange::Full);
+        assert_eq!(
+            ContinuousRange::<i32>::Empty.shift(2),
+            ContinuousRange::Empty
+        );
+    }
+
+    #[test]
+    pub fn operators() {
+        assert_eq!(
+            ContinuousRange::Inclusive(1.0, 5.0) + 0.5,
+            ContinuousRange::Inclusive(1.5, 5.5)
+        );
+        assert_eq!(
+            ContinuousRange::ToExclusive(10u8) - 3,
+            ContinuousRange::ToExclusive(7)
+        );
+    }
+
+    #[test]
+    pub fn range() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 2),
+            ContinuousRange::From(10),
+        ]);
+        assert_eq!(
+            range.shift(5),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(6, 7),
+                ContinuousRange::From(15)
+            ])
+        );
+        assert_eq!(
+            range.clone() + 5,
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(6, 7),
+                ContinuousRange::From(15)
+            ])
+        );
+        assert_eq!(
+            range.clone() - 1,
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(0, 1),
+                ContinuousRange::From(9)
+            ])
+        );
+
+        // Operators between two ranges are still available
+        assert_eq!(
+            range.clone() - Range::Continuous(ContinuousRange::From(10)),
+            Range::Continuous(ContinuousRange::Inclusive(1, 2))
+        );
+        assert_eq!(
+            Range::continuous(0.0, 1.0) + 0.5,
+            Range::continuous(0.5, 1.5)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn dates() {
+        use chrono::{Duration, NaiveDate};
+
+        let date = |day| NaiveDate::from_ymd_opt(2022, 1, day).unwrap();
+        assert_eq!(
+            ContinuousRange::EndExclusive(date(1), date(8)) + Duration::days(1),
+            ContinuousRange::EndExclusive(date(2), date(9))
+        );
+    }
+}
+
+mod test_scale {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn positive() {
+        assert_eq!(
+            ContinuousRange::EndExclusive(1, 5).scale(3),
+            ContinuousRange::EndExclusive(3, 15)
+        );
+        assert_eq!(
+            ContinuousRange::From(1.5).scale(2.0),
+            ContinuousRange::From(3.0)
+        );
+    }
+
+    #[test]
+    pub fn negative() {
+        assert_eq!(
+            ContinuousRange::EndExclusive(1, 5).scale(-1),
+            ContinuousRange::StartExclusive(-5, -1)
+        );
+        assert_eq!(
+            ContinuousRange::FromExclusive(2).scale(-2),
+            ContinuousRange::ToExclusive(-4)
+        );
+        assert_eq!(
+            ContinuousRange::Single(2).scale(-2),
+            ContinuousRange::Single(-4)
+        );
+        assert_eq!(
+            ContinuousRange::<i32>::Full.scale(-2),
+            ContinuousRange::Full
+        );
+    }
+
+    #[test]
+    pub fn range() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 2),
+            ContinuousRange::Inclusive(5, 6),
+        ]);
+        assert_eq!(
+            range.scale(10),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(10, 20),
+                ContinuousRange::Inclusive(50, 60)
+            ])
+        );
+    }
+}
+
+mod test_expand_shrink {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn expand() {
+        assert_eq!(
+            ContinuousRange::Exclusive(1, 5).expand(1, 2),
+            ContinuousRange::Exclusive(0, 7)
+        );
+        assert_eq!(
+            ContinuousRange::From(1).expand(1, 2),
+            ContinuousRange::From(0)
+        );
+        assert_eq!(
+            ContinuousRange::Exclusive(1, 1).expand(1, 1),
+            ContinuousRange::Empty
+        );
+    }
+
+    #[test]
+    pub fn shrink() {
+        assert_eq!(
+            ContinuousRange::Exclusive(1, 5).shrink(1, 1),
+            ContinuousRange::Exclusive(2, 4)
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(1, 5).shrink(2, 2),
+            ContinuousRange::Single(3)
+        );
+        assert_eq!(
+            ContinuousRange::Exclusive(1, 5).shrink(2, 2),
+            ContinuousRange::Empty
+        );
+        assert_eq!(ContinuousRange::To(5).shrink(2, 2), ContinuousRange::To(3));
+    }
+
+    #[test]
+    pub fn range() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 2),
+            ContinuousRange::Inclusive(4, 10),
+        ]);
+        assert_eq!(
+            range.shrink(1, 1),
+            Range::Composite(vec![
+                ContinuousRange::Single(1),
+                ContinuousRange::Inclusive(5, 9)
+            ])
+        );
+        assert_eq!(
+            range.expand(1, 1).simplify(),
+            Range::Continuous(ContinuousRange::Inclusive(-1, 11))
+        );
+        assert_eq!(
+            range.shrink(2, 2),
+            Range::Continuous(ContinuousRange::Inclusive(6, 8))
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use std::{fmt, ops::Bound};
+
+use crate::{ContinuousRange, Magnitude, Measure};
+
+#[cfg(feature = "chrono")]
+pub use chrono_impls::CalendarUnit;
+
+/// The side of a split receiving the value the range is split at
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum SplitSide {
+    /// The split value ends the left part
+    Left,
+
+    /// The split value starts the right part
+    Right,
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+
+    use crate::{ContinuousRange, Range};
+
+    /// A period of the calendar starting at local midnight
+    #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+    pub enum CalendarUnit {
+        /// A day
+        Day,
+
+        /// A week starting on monday
+        Week,
+
+        /// A month starting on its first day
+        Month,
+
+        /// A year starting on the first of january
+        Year,
+    }
+
+    impl CalendarUnit {
+        /// The first day of the unit following the one containing `date`
+        fn next_start(self, date: NaiveDate) -> Option<NaiveDate> {
+            match self {
+                CalendarUnit::Day => date.succ_opt(),
+                CalendarUnit::Week => date.checked_add_signed(Duration::days(
+                    7 - i64::from(date.weekday().num_days_from_monday()),
+                )),
+                CalendarUnit::Month if date.month() == 12 => {
+                    NaiveDate::from_ymd_opt(date.year().checked_add(1)?, 1, 1)
+                }
+                CalendarUnit::Month => NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1),
+                CalendarUnit::Year => NaiveDate::from_ymd_opt(date.year().checked_add(1)?, 1, 1),
+            }
+        }
+    }
+
+    /// The first instant after `instant` where the local date is `date` or later, `date` being after the local
+    /// date of `instant`
+    fn local_start_of<Tz: TimeZone>(
+        instant: &DateTime<Tz>,
+        date: NaiveDate,
+    ) -> Option<DateTime<Tz>> {
+        let zone = instant.timezone();
+        let candidates = match zone.from_local_datetime(&date.and_time(NaiveTime::MIN)) {
+            LocalResult::Single(start) => vec![start],
+            LocalResult::Ambiguous(earliest, latest) => vec![earliest, latest],
+            LocalResult::None => Vec::new(),
+        };
+        if let Some(start) = candidates.into_iter().find(|start| start > instant) {
+            return Some(start);
+        }
+
+        // Midnight was skipped by a transition, the day starts at the first second after it. Offsets are less
+        // than a day so two days after midnight in UTC is always on a later local date.
+        let mut before = instant.timestamp();
+        let mut after = Utc
+            .from_utc_datetime(
+                &date
+                    .checked_add_signed(Duration::days(2))?
+                    .and_time(NaiveTime::MIN),
+            )
+            .timestamp();
+        while after - before > 1 {
+            let middle = before + (after - before) / 2;
+            if zone.timestamp_opt(middle, 0).single()?.date_naive() >= date {
+                after = middle;
+            } else {
+                before = middle;
+            }
+        }
+        zone.timestamp_opt(after, 0).single()
+    }
+
+    /// The start of the unit following the one containing `instant`, in the time zone of `instant`
+    fn next_boundary<Tz: TimeZone>(
+        instant: &DateTime<Tz>,
+        unit: CalendarUnit,
+    ) -> Option<DateTime<Tz>> {
+        local_start_of(instant, unit.next_start(instant.date_naive())?)
+    }
+
+    impl<Tz: TimeZone> ContinuousRange<DateTime<Tz>> {
+        /// Split the range at each start of a calendar `unit` in the time zone of its values
+        ///
+        /// Units start at local midnight so their length follows the transitions of the time zone, like days of
+        /// 23 or 25 hours when daylight saving time starts or ends. When midnight doesn't exist locally the unit
+        /// starts at the first instant of its first day. The pieces are [`ContinuousRange::EndExclusive`] except
+        /// for the first and last ones, that keep the kind of the start and end bound of the range, so that
+        /// together they cover it exactly, an included end at the start of a unit being alone in the last piece.
+        /// Returns [`None`] if the range is unbounded.
+        ///
+        /// ```
+        /// use chrono::{TimeZone, Utc};
+        /// use range_ranger::{CalendarUnit, ContinuousRange};
+        ///
+        /// let range = ContinuousRange::Inclusive(
+        ///     Utc.with_ymd_and_hms(2023, 1, 30, 12, 0, 0).unwrap(),
+        ///     Utc.with_ymd_and_hms(2023, 2, 2, 0, 0, 0).unwrap(),
+        /// );
+        /// assert_eq!(
+        ///     range.split_by_calendar(CalendarUnit::Month),
+        ///     Some(vec![
+        ///         ContinuousRange::EndExclusive(
+        ///             Utc.with_ymd_and_hms(2023, 1, 30, 12, 0, 0).unwrap(),
+        ///             Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap(),
+        ///         ),
+        ///         ContinuousRange::Inclusive(
+        ///             Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap(),
+        ///             Utc.with_ymd_and_hms(2023, 2, 2, 0, 0, 0).unwrap(),
+        ///         ),
+        ///     ])
+        /// );
+        /// ```
+        #[must_use]
+        pub fn split_by_calendar(
+            &self,
+            unit: CalendarUnit,
+        ) -> Option<Vec<ContinuousRange<DateTime<Tz>>>> {
+            if self.is_unbounded() {
+                return None;
+            }
+
+            let (start, end) = match self.bounded_values() {
+                Some(values) => values,
+                None => return Some(Vec::new()),
+            };
+
+            let end = end.clone();
+            let points = std::iter::successors(next_boundary(start, unit), |point| {
+                next_boundary(point, unit)
+            })
+            .take_while(|point| *point <= end);
+            Some(self.cut(points))
+        }
+    }
+
+    impl<Tz: TimeZone> Range<DateTime<Tz>> {
+        /// Split the range at each start of a calendar `unit` in the time zone of its values, see
+        /// [`ContinuousRange::split_by_calendar`]
+        ///
+        /// The pieces are ordered and don't overlap. Returns [`None`] if the range is unbounded.
+        ///
+        /// # Panics
+        ///
+        /// This function may panic if the [`PartialOrd`] contract isn't respected.
+        #[must_use]
+        pub fn split_by_calendar(
+            &self,
+            unit: CalendarUnit,
+        ) -> Option<Vec<ContinuousRange<DateTime<Tz>>>> {
+            let mut pieces = Vec::new();
+            for piece in self.simplify().pieces() {
+                pieces.extend(piece.split_by_calendar(unit)?);
+            }
+            Some(pieces)
+        }
+    }
+}
+
+impl<Idx: PartialOrd + Clone> ContinuousRange<Idx> {
+    /// Split the range in the part before `point` and the part after it, `side` selecting the part that
+    /// contains `point` itself
+    ///
+    /// Parts that don't contain any value are [`ContinuousRange::Empty`].
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the [`PartialOrd`] contract isn't respected, see
+    /// [`intersection`][ContinuousRange::intersection].
+    ///
+    /// ```
+    /// use range_ranger::{ContinuousRange, SplitSide};
+    ///
+    /// let range = ContinuousRange::Inclusive(0, 10);
+    /// assert_eq!(
+    ///     range.split_at(&4, SplitSide::Left),
+    ///     (ContinuousRange::Inclusive(0, 4), ContinuousRange::StartExclusive(4, 10))
+    /// );
+    /// assert_eq!(
+    ///     range.split_at(&4, SplitSide::Right),
+    ///     (ContinuousRange::EndExclusive(0, 4), ContinuousRange::Inclusive(4, 10))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn split_at(
+        &self,
+        point: &Idx,
+        side: SplitSide,
+    ) -> (ContinuousRange<Idx>, ContinuousRange<Idx>)
+    where
+        Idx: fmt::Debug,
+    {
+        let (before, after) = match side {
+            SplitSide::Left => (
+                ContinuousRange::To(point.clone()),
+                ContinuousRange::FromExclusive(point.clone()),
+            ),
+            SplitSide::Right => (
+                ContinuousRange::ToExclusive(point.clone()),
+                ContinuousRange::From(point.clone()),
+            ),
+        };
+
+        (self.intersection(&before), self.intersection(&after))
+    }
+
+    /// Split the range at its midpoint, the right part starting with it
+    ///
+    /// Both parts are never empty: when the midpoint is the first value of the range, like for discrete types
+    /// rounding down to the start, it ends the left part instead. Returns [`None`] if the range is empty,
+    /// unbounded or can't be split in two, like a single value.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// assert_eq!(
+    ///     ContinuousRange::Inclusive(0.0, 1.0).bisect(),
+    ///     Some((ContinuousRange::EndExclusive(0.0, 0.5), ContinuousRange::Inclusive(0.5, 1.0)))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn bisect(&self) -> Option<(ContinuousRange<Idx>, ContinuousRange<Idx>)>
+    where
+        Idx: Measure + fmt::Debug,
+    {
+        let (start, end) = self.bounded_values()?;
+        let middle = Idx::interpolate(start, end, 1, 2)?;
+        let (left, right) = self.split_at(&middle, SplitSide::Right);
+        if !left.is_empty() {
+            return Some((left, right));
+        }
+
+        let (left, right) = self.split_at(&middle, SplitSide::Left);
+        if left.is_empty() || right.is_empty() {
+            return None;
+        }
+        Some((left, right))
+    }
+
+    /// Split the range into `parts` consecutive pieces of equal length
+    ///
+    /// The pieces are [`ContinuousRange::EndExclusive`] except for the first and last ones, that keep the kind of
+    /// the start and end bound of the range, so that together they cover it exactly. Discrete types can produce
+    /// fewer pieces when the range doesn't contain enough values. Returns [`None`] if the range is unbounded or
+    /// `parts` is zero.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// assert_eq!(
+    ///     ContinuousRange::Inclusive(0, 9).subdivide(3),
+    ///     Some(vec![
+    ///         ContinuousRange::EndExclusive(0, 3),
+    ///         ContinuousRange::EndExclusive(3, 6),
+    ///         ContinuousRange::Inclusive(6, 9),
+    ///     ])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn subdivide(&self, parts: u64) -> Option<Vec<ContinuousRange<Idx>>>
+    where
+        Idx: Measure + fmt::Debug,
+    {
+        if parts == 0 || self.is_unbounded() {
+            return None;
+        }
+
+        let (start, end) = match self.bounded_values() {
+            Some(values) => values,
+            None => return Some(Vec::new()),
+        };
+
+        let points = (1..parts)
+            .map(|part| Idx::interpolate(start, end, part, parts))
+            .collect::<Option<Vec<_>>>()?;
+        Some(self.cut(points.into_iter()))
+    }
+
+    /// Split the range into consecutive pieces of length `step`, the last one being shorter if needed
+    ///
+    /// The pieces are [`ContinuousRange::EndExclusive`] except for the first and last ones, that keep the kind of
+    /// the start and end bound of the range, so that together they cover it exactly. Returns [`None`] if the
+    /// range is unbounded, if `step` isn't positive or if adding it doesn't move a value, like a float step too
+    /// small for the magnitude of the bounds.
+    ///
+    /// ```
+    /// use range_ranger::ContinuousRange;
+    ///
+    /// assert_eq!(
+    ///     ContinuousRange::EndExclusive(0, 25).chunks(&10u32),
+    ///     Some(vec![
+    ///         ContinuousRange::EndExclusive(0, 10),
+    ///         ContinuousRange::EndExclusive(10, 20),
+    ///         ContinuousRange::EndExclusive(20, 25),
+    ///     ])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn chunks(&self, step: &Idx::Length) -> Option<Vec<ContinuousRange<Idx>>>
+    where
+        Idx: Measure + fmt::Debug,
+    {
+        if *step <= Idx::Length::zero() || self.is_unbounded() {
+            return None;
+        }
+
+        let (start, end) = match self.bounded_values() {
+            Some(values) => values,
+            None => return Some(Vec::new()),
+        };
+
+        let mut points = Vec::new();
+        let mut previous = start.clone();
+        while let Some(point) = previous.advance(step) {
+            if point <= previous {
+                return None;
+            }
+            if point >= *end {
+                break;
+            }
+            points.push(point.clone());
+            previous = point;
+        }
+        Some(self.cut(points.into_iter()))
+    }
+
+    /// The start and end values of a non-empty range with both bounds
+    fn bounded_values(&self) -> Option<(&Idx, &Idx)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        match self.range_bounds()? {
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) => Some((start, end)),
+            _ => None,
+        }
+    }
+
+    fn is_unbounded(&self) -> bool {
+        matches!(
+            self.range_bounds(),
+            Some((Bound::Unbounded, _) | (_, Bound::Unbounded))
+        )
+    }
+
+    /// Split the range at each of the increasing `points`, the piece after a point starting with it
+    fn cut(&self, points: impl Iterator<Item = Idx>) -> Vec<ContinuousRange<Idx>>
+    where
+        Idx: fmt::Debug,
+    {
+        let mut pieces = Vec::new();
+        let mut rest = self.clone();
+        for point in points {
+            let (piece, after) = rest.split_at(&point, SplitSide::Right);
+            if !piece.is_empty() {
+                pieces.push(piece);
+            }
+            rest = after;
+        }
+
+        if !rest.is_empty() {
+            pieces.push(rest);
+        }
+        pieces
+    }
+}
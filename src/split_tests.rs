@@ -0,0 +1,474 @@
+mod test_split_at {
+    use crate::{ContinuousRange, SplitSide};
+
+    #[test]
+    pub fn inside() {
+        let range = ContinuousRange::Exclusive(0, 10);
+        assert_eq!(
+            range.split_at(&5, SplitSide::Left),
+            (
+                ContinuousRange::StartExclusive(0, 5),
+                ContinuousRange::Exclusive(5, 10)
+            )
+        );
+        assert_eq!(
+            range.split_at(&5, SplitSide::Right),
+            (
+                ContinuousRange::Exclusive(0, 5),
+                ContinuousRange::EndExclusive(5, 10)
+            )
+        );
+    }
+
+    #[test]
+    pub fn on_bounds() {
+        let range = ContinuousRange::Inclusive(0, 10);
+        assert_eq!(
+            range.split_at(&0, SplitSide::Right),
+            (ContinuousRange::Empty, ContinuousRange::Inclusive(0, 10))
+        );
+        assert_eq!(
+            range.split_at(&0, SplitSide::Left),
+            (
+                ContinuousRange::Single(0),
+                ContinuousRange::StartExclusive(0, 10)
+            )
+        );
+        assert_eq!(
+            range.split_at(&20, SplitSide::Left),
+            (ContinuousRange::Inclusive(0, 10), ContinuousRange::Empty)
+        );
+    }
+
+    #[test]
+    pub fn unbounded() {
+        assert_eq!(
+            ContinuousRange::Full.split_at(&1.5, SplitSide::Right),
+            (
+                ContinuousRange::ToExclusive(1.5),
+                ContinuousRange::From(1.5)
+            )
+        );
+    }
+}
+
+mod test_bisect {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn integers() {
+        assert_eq!(
+            ContinuousRange::Inclusive(0, 9).bisect(),
+            Some((
+                ContinuousRange::EndExclusive(0, 4),
+                ContinuousRange::Inclusive(4, 9)
+            ))
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(i64::MIN, i64::MAX).bisect(),
+            Some((
+                ContinuousRange::EndExclusive(i64::MIN, -1),
+                ContinuousRange::Inclusive(-1, i64::MAX)
+            ))
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(0u8, 255).bisect(),
+            Some((
+                ContinuousRange::EndExclusive(0, 127),
+                ContinuousRange::Inclusive(127, 255)
+            ))
+        );
+    }
+
+    #[test]
+    pub fn degenerate() {
+        assert_eq!(ContinuousRange::<i32>::Empty.bisect(), None);
+        assert_eq!(ContinuousRange::From(1).bisect(), None);
+        assert_eq!(ContinuousRange::Single(1).bisect(), None);
+        assert_eq!(ContinuousRange::Inclusive(1, 1).bisect(), None);
+    }
+
+    #[test]
+    pub fn recursive() {
+        // Bisecting again always ends, with pieces of at most one value
+        fn leaves(range: &ContinuousRange<i32>, counts: &mut Vec<u128>) {
+            match range.bisect() {
+                Some((left, right)) => {
+                    leaves(&left, counts);
+                    leaves(&right, counts);
+                }
+                None => counts.push(range.count().unwrap()),
+            }
+        }
+
+        let mut counts = Vec::new();
+        leaves(&ContinuousRange::Inclusive(0, 9), &mut counts);
+        assert!(counts.iter().all(|count| *count <= 1));
+        assert_eq!(counts.iter().sum::<u128>(), 10);
+    }
+
+    #[test]
+    pub fn two_values() {
+        // The midpoint rounds down to the start, so it ends the left part
+        assert_eq!(
+            ContinuousRange::Inclusive(0, 1).bisect(),
+            Some((
+                ContinuousRange::Single(0),
+                ContinuousRange::StartExclusive(0, 1)
+            ))
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(0u8, 1).bisect(),
+            Some((
+                ContinuousRange::Single(0),
+                ContinuousRange::StartExclusive(0, 1)
+            ))
+        );
+    }
+}
+
+mod test_subdivide {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn exact() {
+        assert_eq!(
+            ContinuousRange::StartExclusive(0.0, 3.0).subdivide(3),
+            Some(vec![
+                ContinuousRange::Exclusive(0.0, 1.0),
+                ContinuousRange::EndExclusive(1.0, 2.0),
+                ContinuousRange::Inclusive(2.0, 3.0),
+            ])
+        );
+        assert_eq!(
+            ContinuousRange::EndExclusive(0, 10).subdivide(1),
+            Some(vec![ContinuousRange::EndExclusive(0, 10)])
+        );
+    }
+
+    #[test]
+    pub fn rounding() {
+        assert_eq!(
+            ContinuousRange::EndExclusive(0, 10).subdivide(3),
+            Some(vec![
+                ContinuousRange::EndExclusive(0, 3),
+                ContinuousRange::EndExclusive(3, 6),
+                ContinuousRange::EndExclusive(6, 10),
+            ])
+        );
+        // Not enough values for each part
+        assert_eq!(
+            ContinuousRange::EndExclusive(0, 2).subdivide(4),
+            Some(vec![
+                ContinuousRange::EndExclusive(0, 1),
+                ContinuousRange::EndExclusive(1, 2),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(ContinuousRange::Inclusive(0, 10).subdivide(0), None);
+        assert_eq!(ContinuousRange::To(10).subdivide(2), None);
+        assert_eq!(ContinuousRange::<i32>::Empty.subdivide(2), Some(vec![]));
+    }
+}
+
+mod test_chunks {
+    use crate::ContinuousRange;
+
+    #[test]
+    pub fn integers() {
+        assert_eq!(
+            ContinuousRange::Inclusive(0, 20).chunks(&10u32),
+            Some(vec![
+                ContinuousRange::EndExclusive(0, 10),
+                ContinuousRange::Inclusive(10, 20),
+            ])
+        );
+        assert_eq!(
+            ContinuousRange::Inclusive(250u8, 255).chunks(&4),
+            Some(vec![
+                ContinuousRange::EndExclusive(250, 254),
+                ContinuousRange::Inclusive(254, 255),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn invalid() {
+        assert_eq!(ContinuousRange::Inclusive(0, 10).chunks(&0u32), None);
+        assert_eq!(ContinuousRange::Inclusive(0.0, 10.0).chunks(&-1.0), None);
+        assert_eq!(ContinuousRange::From(0).chunks(&1u32), None);
+        assert_eq!(
+            ContinuousRange::Inclusive(1e16f64, 1e16 + 10.0).chunks(&0.5),
+            None
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn dates() {
+        use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+        let date = |day| NaiveDate::from_ymd_opt(2022, 1, day).unwrap();
+        assert_eq!(
+            ContinuousRange::EndExclusive(date(1), date(15)).chunks(&Duration::days(7)),
+            Some(vec![
+                ContinuousRange::EndExclusive(date(1), date(8)),
+                ContinuousRange::EndExclusive(date(8), date(15)),
+            ])
+        );
+
+        let time = |hour| -> NaiveDateTime { date(1).and_hms_opt(hour, 0, 0).unwrap() };
+        assert_eq!(
+            ContinuousRange::EndExclusive(time(0), time(12)).subdivide(4),
+            Some(vec![
+                ContinuousRange::EndExclusive(time(0), time(3)),
+                ContinuousRange::EndExclusive(time(3), time(6)),
+                ContinuousRange::EndExclusive(time(6), time(9)),
+                ContinuousRange::EndExclusive(time(9), time(12)),
+            ])
+        );
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod test_split_by_calendar {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use chrono_tz::{
+        America::{Havana, Sao_Paulo},
+        Europe::Paris,
+        Pacific::Apia,
+        Tz,
+    };
+
+    use crate::{CalendarUnit, ContinuousRange, Range};
+
+    fn local(zone: Tz, year: i32, month: u32, day: u32, hour: u32) -> DateTime<Tz> {
+        zone.with_ymd_and_hms(year, month, day, hour, 0, 0)
+            .earliest()
+            .unwrap()
+    }
+
+    fn lengths(pieces: &[ContinuousRange<DateTime<Tz>>]) -> Vec<Duration> {
+        pieces.iter().map(|piece| piece.length().unwrap()).collect()
+    }
+
+    #[test]
+    pub fn days_across_daylight_saving_time() {
+        let range = ContinuousRange::EndExclusive(
+            local(Paris, 2023, 3, 25, 12),
+            local(Paris, 2023, 3, 27, 12),
+        );
+        let pieces = range.split_by_calendar(CalendarUnit::Day).unwrap();
+        assert_eq!(
+            pieces,
+            vec![
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 3, 25, 12),
+                    local(Paris, 2023, 3, 26, 0)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 3, 26, 0),
+                    local(Paris, 2023, 3, 27, 0)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 3, 27, 0),
+                    local(Paris, 2023, 3, 27, 12)
+                ),
+            ]
+        );
+        assert_eq!(
+            lengths(&pieces),
+            vec![
+                Duration::hours(12),
+                Duration::hours(23),
+                Duration::hours(12)
+            ]
+        );
+
+        let range = ContinuousRange::EndExclusive(
+            local(Paris, 2023, 10, 29, 0),
+            local(Paris, 2023, 10, 30, 0),
+        );
+        let pieces = range.split_by_calendar(CalendarUnit::Day).unwrap();
+        assert_eq!(lengths(&pieces), vec![Duration::hours(25)]);
+    }
+
+    #[test]
+    pub fn preserves_bounds() {
+        let range =
+            ContinuousRange::Exclusive(local(Paris, 2023, 1, 1, 12), local(Paris, 2023, 1, 2, 0));
+        assert_eq!(
+            range.split_by_calendar(CalendarUnit::Day),
+            Some(vec![range])
+        );
+
+        let range =
+            ContinuousRange::Inclusive(local(Paris, 2023, 1, 1, 0), local(Paris, 2023, 1, 2, 0));
+        assert_eq!(
+            range.split_by_calendar(CalendarUnit::Day),
+            Some(vec![
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 1, 1, 0),
+                    local(Paris, 2023, 1, 2, 0)
+                ),
+                ContinuousRange::Single(local(Paris, 2023, 1, 2, 0)),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn months_in_another_zone() {
+        // Local midnight in Paris isn't midnight in UTC
+        let range = ContinuousRange::Inclusive(
+            Utc.with_ymd_and_hms(2023, 1, 15, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2023, 4, 15, 0, 0, 0).unwrap(),
+        )
+        .map_bounds(|value| value.with_timezone(&Paris));
+        let pieces = range.split_by_calendar(CalendarUnit::Month).unwrap();
+        assert_eq!(
+            pieces,
+            vec![
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 1, 15, 1),
+                    local(Paris, 2023, 2, 1, 0)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 2, 1, 0),
+                    local(Paris, 2023, 3, 1, 0)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 3, 1, 0),
+                    local(Paris, 2023, 4, 1, 0)
+                ),
+                ContinuousRange::Inclusive(
+                    local(Paris, 2023, 4, 1, 0),
+                    local(Paris, 2023, 4, 15, 2)
+                ),
+            ]
+        );
+        assert_eq!(pieces[2].length(), Some(Duration::hours(31 * 24 - 1)));
+    }
+
+    #[test]
+    pub fn weeks_and_years() {
+        let range = ContinuousRange::EndExclusive(
+            local(Paris, 2022, 12, 28, 0),
+            local(Paris, 2023, 1, 4, 0),
+        );
+        assert_eq!(
+            range.split_by_calendar(CalendarUnit::Week),
+            Some(vec![
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2022, 12, 28, 0),
+                    local(Paris, 2023, 1, 2, 0)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 1, 2, 0),
+                    local(Paris, 2023, 1, 4, 0)
+                ),
+            ])
+        );
+        assert_eq!(
+            range.split_by_calendar(CalendarUnit::Year),
+            Some(vec![
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2022, 12, 28, 0),
+                    local(Paris, 2023, 1, 1, 0)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Paris, 2023, 1, 1, 0),
+                    local(Paris, 2023, 1, 4, 0)
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn skipped_midnight() {
+        // Daylight saving time started at midnight in Sao Paulo on 2018-11-04
+        let range = ContinuousRange::EndExclusive(
+            local(Sao_Paulo, 2018, 11, 3, 12),
+            local(Sao_Paulo, 2018, 11, 4, 12),
+        );
+        let pieces = range.split_by_calendar(CalendarUnit::Day).unwrap();
+        assert_eq!(
+            pieces,
+            vec![
+                ContinuousRange::EndExclusive(
+                    local(Sao_Paulo, 2018, 11, 3, 12),
+                    local(Sao_Paulo, 2018, 11, 4, 1)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Sao_Paulo, 2018, 11, 4, 1),
+                    local(Sao_Paulo, 2018, 11, 4, 12)
+                ),
+            ]
+        );
+        assert_eq!(
+            lengths(&pieces),
+            vec![Duration::hours(12), Duration::hours(11)]
+        );
+
+        // Samoa skipped 2011-12-30 entirely
+        let range =
+            ContinuousRange::EndExclusive(local(Apia, 2011, 12, 29, 0), local(Apia, 2012, 1, 1, 0));
+        assert_eq!(
+            range.split_by_calendar(CalendarUnit::Day),
+            Some(vec![
+                ContinuousRange::EndExclusive(
+                    local(Apia, 2011, 12, 29, 0),
+                    local(Apia, 2011, 12, 31, 0)
+                ),
+                ContinuousRange::EndExclusive(
+                    local(Apia, 2011, 12, 31, 0),
+                    local(Apia, 2012, 1, 1, 0)
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn repeated_midnight() {
+        // Daylight saving time ended at 1:00 in Havana on 2023-11-05, repeating the hour after midnight
+        let range = ContinuousRange::EndExclusive(
+            local(Havana, 2023, 11, 4, 12),
+            local(Havana, 2023, 11, 6, 0),
+        );
+        let pieces = range.split_by_calendar(CalendarUnit::Day).unwrap();
+        assert_eq!(
+            lengths(&pieces),
+            vec![Duration::hours(12), Duration::hours(25)]
+        );
+    }
+
+    #[test]
+    pub fn composite_and_unbounded() {
+        let range = Range::Composite(vec![
+            ContinuousRange::EndExclusive(
+                local(Paris, 2023, 1, 3, 12),
+                local(Paris, 2023, 1, 4, 12),
+            ),
+            ContinuousRange::EndExclusive(
+                local(Paris, 2023, 1, 1, 12),
+                local(Paris, 2023, 1, 2, 12),
+            ),
+        ]);
+        assert_eq!(
+            range
+                .split_by_calendar(CalendarUnit::Day)
+                .map(|pieces| pieces.len()),
+            Some(4)
+        );
+        assert_eq!(
+            Range::<DateTime<Tz>>::empty().split_by_calendar(CalendarUnit::Day),
+            Some(vec![])
+        );
+        assert_eq!(
+            ContinuousRange::From(local(Paris, 2023, 1, 1, 0)).split_by_calendar(CalendarUnit::Day),
+            None
+        );
+    }
+}
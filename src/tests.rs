@@ -483,4 +483,419 @@ mod test_composite_simplification {
         let r: Range<i32> = Range::composite(vec![(1..=5).into()]);
         assert_matches!(r, Range::Continuous(ContinuousRange::Inclusive(1, 5)));
     }
+
+    #[test]
+    pub fn merge_overlapping() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(5, 8),
+            ContinuousRange::Inclusive(1, 6),
+            ContinuousRange::Empty,
+        ]);
+        assert_eq!(
+            r.simplify(),
+            Range::Continuous(ContinuousRange::Inclusive(1, 8))
+        );
+    }
+
+    #[test]
+    pub fn merge_adjacent() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(5, 8),
+            ContinuousRange::EndExclusive(1, 5),
+        ]);
+        assert_eq!(
+            r.simplify(),
+            Range::Continuous(ContinuousRange::Inclusive(1, 8))
+        );
+    }
+
+    #[test]
+    pub fn sort_disjoint() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(10, 20),
+            ContinuousRange::Inclusive(1, 5),
+        ]);
+        assert_eq!(
+            r.simplify(),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(1, 5),
+                ContinuousRange::Inclusive(10, 20)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn merge_full() {
+        let r = Range::Composite(vec![ContinuousRange::To(5), ContinuousRange::From(1)]);
+        assert_eq!(r.simplify(), Range::Continuous(ContinuousRange::Full));
+    }
+
+    #[test]
+    pub fn keep_separated_exclusive_bounds() {
+        let r = Range::Composite(vec![
+            ContinuousRange::StartExclusive(5, 8),
+            ContinuousRange::EndExclusive(1, 5),
+        ]);
+        assert_eq!(
+            r.simplify(),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(1, 5),
+                ContinuousRange::StartExclusive(5, 8)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn single_part_left() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Empty,
+            ContinuousRange::Single(3),
+            ContinuousRange::Inclusive(1, 5),
+        ]);
+        assert_eq!(
+            r.simplify(),
+            Range::Continuous(ContinuousRange::Inclusive(1, 5))
+        );
+
+        let r: Range<i32> = Range::Composite(vec![
+            ContinuousRange::Empty,
+            ContinuousRange::Inclusive(5, 1),
+        ]);
+        assert_eq!(r.simplify(), Range::Continuous(ContinuousRange::Empty));
+    }
+
+    #[test]
+    pub fn same_values() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(7, 9),
+            ContinuousRange::Single(12),
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Exclusive(3, 8),
+        ]);
+        let simplified = r.simplify();
+        assert_eq!(
+            simplified,
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(1, 9),
+                ContinuousRange::Single(12)
+            ])
+        );
+        assert_eq!(simplified.simplify(), simplified);
+        for value in 0..15 {
+            assert_eq!(simplified.contains(value), r.contains(value));
+        }
+    }
+
+    #[test]
+    pub fn merge_with_tolerance() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(0.0, 1.0),
+            ContinuousRange::Inclusive(2.0, 3.0),
+            ContinuousRange::Inclusive(1.05, 1.95),
+        ]);
+        assert_eq!(
+            r.simplify_with_tolerance(&0.1),
+            Range::Continuous(ContinuousRange::Inclusive(0.0, 3.0))
+        );
+        assert_eq!(
+            r.simplify_with_tolerance(&0.01),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(0.0, 1.0),
+                ContinuousRange::Inclusive(1.05, 1.95),
+                ContinuousRange::Inclusive(2.0, 3.0),
+            ])
+        );
+    }
+}
+
+mod test_difference {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn empty() {
+        let r: Range<i32> = (1..=5).into();
+        assert_eq!(r.clone().difference(&Range::empty()), r);
+        assert_eq!(Range::empty().difference(&r), Range::empty());
+    }
+
+    #[test]
+    pub fn full() {
+        let r: Range<i32> = (1..=5).into();
+        assert_eq!(r.clone().difference(&Range::full()), Range::empty());
+        assert_eq!(
+            Range::full().difference(&r),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(1),
+                ContinuousRange::FromExclusive(5)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn split() {
+        let r: Range<i32> = (1..=10).into();
+        assert_eq!(
+            r.difference(&(4..6).into()),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(1, 4),
+                ContinuousRange::Inclusive(6, 10)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn composite() {
+        let r = Range::Composite(vec![
+            ContinuousRange::Inclusive(1, 5),
+            ContinuousRange::Inclusive(3, 10),
+        ]);
+        let other = Range::Composite(vec![ContinuousRange::Single(2), ContinuousRange::From(8)]);
+        assert_eq!(
+            r.difference(&other),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(1, 2),
+                ContinuousRange::Exclusive(2, 8)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn sub_operator() {
+        let r: Range<i32> = (1..=10).into();
+        let other: Range<i32> = (1..=5).into();
+        assert_eq!(r - other, Range::continuous_start_exclusive(5, 10));
+    }
+}
+
+mod test_gaps_within {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn empty() {
+        let r: Range<i32> = Range::empty();
+        assert_eq!(
+            r.gaps_within(&ContinuousRange::Inclusive(1, 5)),
+            Range::continuous(1, 5)
+        );
+    }
+
+    #[test]
+    pub fn outside_window() {
+        let r: Range<i32> = (10..=20).into();
+        assert_eq!(
+            r.gaps_within(&ContinuousRange::Inclusive(1, 5)),
+            Range::continuous(1, 5)
+        );
+    }
+
+    #[test]
+    pub fn covering_window() {
+        let r: Range<i32> = (0..=20).into();
+        assert_eq!(
+            r.gaps_within(&ContinuousRange::Inclusive(1, 5)),
+            Range::empty()
+        );
+    }
+
+    #[test]
+    pub fn multiple_gaps() {
+        let r = Range::Composite(vec![
+            ContinuousRange::EndExclusive(14, 16),
+            ContinuousRange::EndExclusive(9, 10),
+            ContinuousRange::EndExclusive(11, 12),
+        ]);
+        assert_eq!(
+            r.gaps_within(&ContinuousRange::EndExclusive(8, 18)),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(8, 9),
+                ContinuousRange::EndExclusive(10, 11),
+                ContinuousRange::EndExclusive(12, 14),
+                ContinuousRange::EndExclusive(16, 18),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn unbounded_window() {
+        let r: Range<i32> = (1..=5).into();
+        assert_eq!(
+            r.gaps_within(&ContinuousRange::From(0)),
+            Range::Composite(vec![
+                ContinuousRange::EndExclusive(0, 1),
+                ContinuousRange::FromExclusive(5)
+            ])
+        );
+    }
+}
+
+mod test_nearest {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn composite() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(20, 30),
+        ]);
+        assert_eq!(range.nearest(&5), Some(5));
+        assert_eq!(range.nearest(&14), Some(10));
+        assert_eq!(range.nearest(&16), Some(20));
+        assert_eq!(range.nearest(&40), Some(30));
+        // Ties are resolved with the lowest value
+        assert_eq!(range.nearest(&15), Some(10));
+    }
+
+    #[test]
+    pub fn excluded_bounds() {
+        let range = Range::Composite(vec![
+            ContinuousRange::EndExclusive(0.0, 10.0),
+            ContinuousRange::Inclusive(20.0, 30.0),
+        ]);
+        assert_eq!(range.nearest(&11.0), None);
+        assert_eq!(range.nearest(&16.0), Some(20.0));
+        assert_eq!(range.nearest(&-1.0), Some(0.0));
+
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(0.0, 1.0),
+            ContinuousRange::Exclusive(5.0, 10.0),
+        ]);
+        assert_eq!(range.nearest(&4.9), None);
+        assert_eq!(range.nearest(&2.0), Some(1.0));
+        assert_eq!(range.nearest(&3.0), Some(1.0));
+        assert_eq!(range.nearest(&7.0), Some(7.0));
+        assert_eq!(range.nearest(&12.0), None);
+    }
+
+    #[test]
+    pub fn discrete() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 1),
+            ContinuousRange::Exclusive(5, 10),
+        ]);
+        assert_eq!(range.nearest(&5), None);
+        assert_eq!(range.nearest_discrete(&5), Some(6));
+        assert_eq!(range.nearest_discrete(&3), Some(1));
+        assert_eq!(range.nearest_discrete(&4), Some(6));
+        assert_eq!(range.nearest_discrete(&12), Some(9));
+
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 1),
+            ContinuousRange::Exclusive(5, 6),
+        ]);
+        assert_eq!(range.nearest_discrete(&5), Some(1));
+    }
+
+    #[test]
+    pub fn continuous() {
+        let range = Range::Continuous(ContinuousRange::From(5));
+        assert_eq!(range.nearest(&0), Some(5));
+        assert_eq!(Range::<i32>::empty().nearest(&0), None);
+    }
+}
+
+mod test_map_bounds {
+    use crate::{ContinuousRange, Range};
+    use std::convert::TryFrom;
+
+    #[test]
+    pub fn composite() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(1u32, 2),
+            ContinuousRange::FromExclusive(10),
+        ]);
+        assert_eq!(
+            range.clone().map_bounds(u64::from),
+            Range::Composite(vec![
+                ContinuousRange::Inclusive(1u64, 2),
+                ContinuousRange::FromExclusive(10)
+            ])
+        );
+        assert_eq!(
+            range.map_bounds_decreasing(|x| -i64::from(x)),
+            Range::Composite(vec![
+                ContinuousRange::ToExclusive(-10),
+                ContinuousRange::Inclusive(-2, -1)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn normalized() {
+        let range = Range::Composite(vec![
+            ContinuousRange::EndExclusive(1, 5),
+            ContinuousRange::Inclusive(10, 15),
+            ContinuousRange::Inclusive(20, 30),
+        ]);
+        assert_eq!(
+            range.clone().map_bounds(|x| x / 10),
+            Range::Composite(vec![
+                ContinuousRange::Single(1),
+                ContinuousRange::Inclusive(2, 3)
+            ])
+        );
+        assert_eq!(
+            range.map_bounds_decreasing(|x| -x / 20),
+            Range::Composite(vec![
+                ContinuousRange::Single(-1),
+                ContinuousRange::Single(0)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn fallible() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(1u64, 2),
+            ContinuousRange::Inclusive(300, 400),
+        ]);
+        assert!(range.clone().try_map_bounds(u8::try_from).is_err());
+        assert_eq!(
+            range.try_map_bounds(u16::try_from),
+            Ok(Range::Composite(vec![
+                ContinuousRange::Inclusive(1u16, 2),
+                ContinuousRange::Inclusive(300, 400)
+            ]))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn timestamps() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let start = Utc.timestamp_millis_opt(1_000).unwrap();
+        let end = Utc.timestamp_millis_opt(5_000).unwrap();
+        let range = Range::Continuous(ContinuousRange::EndExclusive(start, end));
+        assert_eq!(
+            range.map_bounds(|date: DateTime<Utc>| date.timestamp_millis()),
+            Range::Continuous(ContinuousRange::EndExclusive(1_000, 5_000))
+        );
+    }
+}
+
+mod test_intersection {
+    use crate::{ContinuousRange, Range};
+
+    #[test]
+    pub fn composite() {
+        let range = Range::Composite(vec![
+            ContinuousRange::Inclusive(0, 10),
+            ContinuousRange::Inclusive(20, 30),
+        ]);
+        assert_eq!(
+            range.intersection(&Range::Continuous(ContinuousRange::Exclusive(5, 25))),
+            Range::Composite(vec![
+                ContinuousRange::StartExclusive(5, 10),
+                ContinuousRange::EndExclusive(20, 25)
+            ])
+        );
+    }
+
+    #[test]
+    pub fn disjoint() {
+        let range = Range::Continuous(ContinuousRange::Inclusive(0, 10));
+        assert!(range
+            .intersection(&Range::Continuous(ContinuousRange::From(11)))
+            .is_empty());
+    }
 }